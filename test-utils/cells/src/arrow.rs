@@ -0,0 +1,505 @@
+//! Conversions between [Cells] and arrow [RecordBatch]es.
+//!
+//! Each flat `FieldData` variant maps to the arrow primitive array of the
+//! same physical type. `FieldData::VecUInt8` maps to [BinaryArray], and the
+//! other `Vec` variants map to a [ListArray] of the primitive type.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use arrow::array::{
+    Array, ArrayRef, AsArray, BinaryArray, Float32Array, Float64Array,
+    Int16Array, Int32Array, Int64Array, Int8Array, ListArray, RecordBatch,
+    RecordBatchOptions, UInt16Array, UInt32Array, UInt64Array, UInt8Array,
+};
+use arrow::datatypes::{
+    DataType, Field, Float32Type, Float64Type, Int16Type, Int32Type, Int64Type,
+    Int8Type, Schema, UInt16Type, UInt32Type, UInt64Type, UInt8Type,
+};
+
+use super::field::FieldData;
+use super::Cells;
+
+/// Returns an arrow array containing the same values as `field`.
+fn field_to_array(field: &FieldData) -> ArrayRef {
+    macro_rules! list {
+        ($T:ty, $data:expr) => {
+            Arc::new(ListArray::from_iter_primitive::<$T, _, _>(
+                $data
+                    .iter()
+                    .map(|cell| Some(cell.iter().copied().map(Some))),
+            ))
+        };
+    }
+
+    match field {
+        FieldData::UInt8(data) => Arc::new(UInt8Array::from(data.clone())),
+        FieldData::UInt16(data) => Arc::new(UInt16Array::from(data.clone())),
+        FieldData::UInt32(data) => Arc::new(UInt32Array::from(data.clone())),
+        FieldData::UInt64(data) => Arc::new(UInt64Array::from(data.clone())),
+        FieldData::Int8(data) => Arc::new(Int8Array::from(data.clone())),
+        FieldData::Int16(data) => Arc::new(Int16Array::from(data.clone())),
+        FieldData::Int32(data) => Arc::new(Int32Array::from(data.clone())),
+        FieldData::Int64(data) => Arc::new(Int64Array::from(data.clone())),
+        FieldData::Float32(data) => Arc::new(Float32Array::from(data.clone())),
+        FieldData::Float64(data) => Arc::new(Float64Array::from(data.clone())),
+        FieldData::VecUInt8(data) => {
+            Arc::new(BinaryArray::from_iter_values(data.iter()))
+        }
+        FieldData::VecUInt16(data) => list!(UInt16Type, data),
+        FieldData::VecUInt32(data) => list!(UInt32Type, data),
+        FieldData::VecUInt64(data) => list!(UInt64Type, data),
+        FieldData::VecInt8(data) => list!(Int8Type, data),
+        FieldData::VecInt16(data) => list!(Int16Type, data),
+        FieldData::VecInt32(data) => list!(Int32Type, data),
+        FieldData::VecInt64(data) => list!(Int64Type, data),
+        FieldData::VecFloat32(data) => list!(Float32Type, data),
+        FieldData::VecFloat64(data) => list!(Float64Type, data),
+    }
+}
+
+/// Returns a `FieldData` containing the same values as `array`.
+///
+/// # Panics
+///
+/// Panics if `array` contains nulls or has a data type which
+/// does not correspond to a `FieldData` variant.
+fn array_to_field(array: &dyn Array) -> FieldData {
+    assert_eq!(
+        0,
+        array.null_count(),
+        "Cannot convert arrow array with nulls into FieldData"
+    );
+
+    macro_rules! flat {
+        ($T:ty) => {
+            FieldData::from(array.as_primitive::<$T>().values().to_vec())
+        };
+    }
+    macro_rules! list {
+        ($T:ty) => {
+            FieldData::from(
+                array
+                    .as_list::<i32>()
+                    .iter()
+                    .map(|cell| {
+                        cell.unwrap().as_primitive::<$T>().values().to_vec()
+                    })
+                    .collect::<Vec<_>>(),
+            )
+        };
+    }
+
+    match array.data_type() {
+        DataType::UInt8 => flat!(UInt8Type),
+        DataType::UInt16 => flat!(UInt16Type),
+        DataType::UInt32 => flat!(UInt32Type),
+        DataType::UInt64 => flat!(UInt64Type),
+        DataType::Int8 => flat!(Int8Type),
+        DataType::Int16 => flat!(Int16Type),
+        DataType::Int32 => flat!(Int32Type),
+        DataType::Int64 => flat!(Int64Type),
+        DataType::Float32 => flat!(Float32Type),
+        DataType::Float64 => flat!(Float64Type),
+        DataType::Binary => FieldData::VecUInt8(
+            array
+                .as_binary::<i32>()
+                .iter()
+                .map(|cell| cell.unwrap().to_vec())
+                .collect(),
+        ),
+        DataType::List(item) => match item.data_type() {
+            DataType::UInt8 => list!(UInt8Type),
+            DataType::UInt16 => list!(UInt16Type),
+            DataType::UInt32 => list!(UInt32Type),
+            DataType::UInt64 => list!(UInt64Type),
+            DataType::Int8 => list!(Int8Type),
+            DataType::Int16 => list!(Int16Type),
+            DataType::Int32 => list!(Int32Type),
+            DataType::Int64 => list!(Int64Type),
+            DataType::Float32 => list!(Float32Type),
+            DataType::Float64 => list!(Float64Type),
+            dt => panic!("Cannot convert arrow list of {} into FieldData", dt),
+        },
+        dt => panic!("Cannot convert arrow type {} into FieldData", dt),
+    }
+}
+
+impl Cells {
+    /// Returns an arrow [RecordBatch] with one non-nullable column per field.
+    ///
+    /// Columns are ordered by field name.
+    /// If there are no fields then the batch has no columns and no rows.
+    pub fn to_record_batch(&self) -> RecordBatch {
+        let mut names = self.fields.keys().collect::<Vec<_>>();
+        names.sort();
+
+        let columns = names
+            .iter()
+            .map(|name| field_to_array(&self.fields[*name]))
+            .collect::<Vec<ArrayRef>>();
+        let schema = Schema::new(
+            names
+                .iter()
+                .zip(columns.iter())
+                .map(|(name, column)| {
+                    Field::new(*name, column.data_type().clone(), false)
+                })
+                .collect::<Vec<_>>(),
+        );
+
+        // the row count is required if there are no columns
+        let num_rows = columns.first().map(|c| c.len()).unwrap_or(0);
+        RecordBatch::try_new_with_options(
+            Arc::new(schema),
+            columns,
+            &RecordBatchOptions::new().with_row_count(Some(num_rows)),
+        )
+        .expect("Cells fields must all have the same length")
+    }
+
+    /// Returns the cells contained in an arrow [RecordBatch].
+    ///
+    /// This is the inverse of [Cells::to_record_batch].
+    ///
+    /// # Panics
+    ///
+    /// Panics if any column contains nulls or has a data type which
+    /// does not correspond to a [FieldData] variant.
+    pub fn from_record_batch(batch: &RecordBatch) -> Cells {
+        let fields = batch
+            .schema()
+            .fields()
+            .iter()
+            .zip(batch.columns().iter())
+            .map(|(field, column)| {
+                (field.name().to_owned(), array_to_field(column.as_ref()))
+            })
+            .collect::<HashMap<String, FieldData>>();
+        Cells::new(fields)
+    }
+}
+
+#[cfg(any(test, feature = "proptest-strategies"))]
+pub mod strategy {
+    use arrow::array::make_array;
+    use arrow::buffer::NullBuffer;
+    use proptest::prelude::*;
+    use proptest::strategy::{NewTree, ValueTree};
+    use proptest::test_runner::TestRunner;
+    use tiledb_common::array::CellValNum;
+    use tiledb_common::datatype::Datatype;
+
+    use super::*;
+    use crate::strategy::{CellsParameters, CellsStrategySchema};
+
+    #[derive(Clone, Debug)]
+    pub struct RecordBatchParameters {
+        /// Minimum number of columns. Must be at least 1.
+        pub min_columns: usize,
+        pub max_columns: usize,
+        /// Whether columns may be nullable.
+        pub allow_nullable: bool,
+        /// Parameters for the cells of the batch, which
+        /// control the number of rows and var-sized cell lengths.
+        /// The `schema` is ignored.
+        pub cells: CellsParameters,
+    }
+
+    impl Default for RecordBatchParameters {
+        fn default() -> Self {
+            RecordBatchParameters {
+                min_columns: 1,
+                max_columns: 8,
+                allow_nullable: true,
+                cells: Default::default(),
+            }
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    struct ColumnSpec {
+        name: String,
+        nullable: bool,
+    }
+
+    /// Key of the cells field holding the values of column `i`.
+    fn data_key(i: usize) -> String {
+        format!("data{}", i)
+    }
+
+    /// Key of the cells field holding the validity of column `i`.
+    fn validity_key(i: usize) -> String {
+        format!("validity{}", i)
+    }
+
+    /// Strategy which produces arrow record batches whose columns
+    /// can all be converted into tiledb attributes.
+    ///
+    /// Each column is a primitive array, a binary array, or a list of
+    /// primitives, as produced by [Cells::to_record_batch].
+    /// Nullable columns may contain nulls and other columns do not.
+    #[derive(Debug, Default)]
+    pub struct RecordBatchStrategy {
+        params: RecordBatchParameters,
+    }
+
+    impl RecordBatchStrategy {
+        pub fn new(params: RecordBatchParameters) -> Self {
+            RecordBatchStrategy { params }
+        }
+    }
+
+    impl Strategy for RecordBatchStrategy {
+        type Tree = RecordBatchValueTree;
+        type Value = (Arc<Schema>, RecordBatch);
+
+        fn new_tree(&self, runner: &mut TestRunner) -> NewTree<Self> {
+            let strat_nullable = if self.params.allow_nullable {
+                any::<bool>().boxed()
+            } else {
+                Just(false).boxed()
+            };
+            let mut columns = proptest::collection::hash_map(
+                tiledb_pod::array::attribute::strategy::prop_attribute_name(),
+                (any::<Datatype>(), any::<CellValNum>(), strat_nullable),
+                self.params.min_columns..=self.params.max_columns,
+            )
+            .new_tree(runner)?
+            .current()
+            .into_iter()
+            .collect::<Vec<_>>();
+            columns.sort_by(|l, r| l.0.cmp(&r.0));
+
+            /*
+             * The validity of each nullable column is generated as another
+             * field of the cells, so that shrinking the number of rows
+             * shrinks the validity along with the values.
+             */
+            let mut fields = HashMap::new();
+            for (i, (_, (datatype, cell_val_num, nullable))) in
+                columns.iter().enumerate()
+            {
+                fields.insert(data_key(i), (*datatype, *cell_val_num));
+                if *nullable {
+                    fields.insert(
+                        validity_key(i),
+                        (Datatype::UInt8, CellValNum::single()),
+                    );
+                }
+            }
+
+            let cells = any_with::<Cells>(CellsParameters {
+                schema: Some(CellsStrategySchema::Fields(fields)),
+                ..self.params.cells.clone()
+            })
+            .new_tree(runner)?;
+
+            let columns = columns
+                .into_iter()
+                .map(|(name, (_, _, nullable))| ColumnSpec { name, nullable })
+                .collect::<Vec<_>>();
+
+            Ok(RecordBatchValueTree {
+                ncolumns: columns.len(),
+                min_columns: self.params.min_columns,
+                columns,
+                cells,
+                last_shrink: None,
+            })
+        }
+    }
+
+    #[derive(Clone, Copy, Debug)]
+    enum Shrink {
+        Rows,
+        Columns,
+    }
+
+    /// Value tree which shrinks a record batch.
+    /// The number of rows is shrunk first, and then the number of columns.
+    pub struct RecordBatchValueTree {
+        columns: Vec<ColumnSpec>,
+        ncolumns: usize,
+        min_columns: usize,
+        cells: <BoxedStrategy<Cells> as Strategy>::Tree,
+        last_shrink: Option<Shrink>,
+    }
+
+    impl ValueTree for RecordBatchValueTree {
+        type Value = (Arc<Schema>, RecordBatch);
+
+        fn current(&self) -> Self::Value {
+            let cells = self.cells.current();
+
+            let (fields, columns): (Vec<Field>, Vec<ArrayRef>) = self.columns
+                [0..self.ncolumns]
+                .iter()
+                .enumerate()
+                .map(|(i, column)| {
+                    let data = field_to_array(&cells.fields()[&data_key(i)]);
+                    let data = if column.nullable {
+                        let FieldData::UInt8(ref validity) =
+                            cells.fields()[&validity_key(i)]
+                        else {
+                            unreachable!()
+                        };
+                        let nulls = NullBuffer::from(
+                            validity
+                                .iter()
+                                .map(|v| *v != 0)
+                                .collect::<Vec<_>>(),
+                        );
+                        make_array(
+                            data.to_data()
+                                .into_builder()
+                                .nulls(Some(nulls))
+                                .build()
+                                .expect("Error applying validity"),
+                        )
+                    } else {
+                        data
+                    };
+                    (
+                        Field::new(
+                            column.name.clone(),
+                            data.data_type().clone(),
+                            column.nullable,
+                        ),
+                        data,
+                    )
+                })
+                .unzip();
+
+            let schema = Arc::new(Schema::new(fields));
+            let batch = RecordBatch::try_new(Arc::clone(&schema), columns)
+                .expect("Error constructing record batch");
+            (schema, batch)
+        }
+
+        fn simplify(&mut self) -> bool {
+            if self.cells.simplify() {
+                self.last_shrink = Some(Shrink::Rows);
+                true
+            } else if self.ncolumns > self.min_columns {
+                self.ncolumns -= 1;
+                self.last_shrink = Some(Shrink::Columns);
+                true
+            } else {
+                self.last_shrink = None;
+                false
+            }
+        }
+
+        fn complicate(&mut self) -> bool {
+            match self.last_shrink.take() {
+                Some(Shrink::Rows) => {
+                    if self.cells.complicate() {
+                        self.last_shrink = Some(Shrink::Rows);
+                        true
+                    } else {
+                        false
+                    }
+                }
+                Some(Shrink::Columns) => {
+                    /* the removed column is needed, keep it */
+                    self.ncolumns += 1;
+                    self.min_columns = self.ncolumns;
+                    true
+                }
+                None => false,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+    use tiledb_common::datatype::physical::BitsEq;
+
+    use super::*;
+
+    #[test]
+    fn var_uint8_binary() {
+        let cells = Cells::new(HashMap::from([(
+            "s".to_owned(),
+            FieldData::from(vec!["foo".to_owned(), "".to_owned()]),
+        )]));
+        let batch = cells.to_record_batch();
+        assert_eq!(&DataType::Binary, batch.column(0).data_type());
+        assert_eq!(cells, Cells::from_record_batch(&batch));
+    }
+
+    #[test]
+    fn no_fields() {
+        let cells = Cells::new(HashMap::new());
+        let batch = cells.to_record_batch();
+        assert_eq!(0, batch.num_columns());
+        assert_eq!(0, batch.num_rows());
+        assert_eq!(cells, Cells::from_record_batch(&batch));
+    }
+
+    #[test]
+    fn record_batch_shrinking() {
+        use proptest::strategy::ValueTree;
+        use proptest::test_runner::TestRunner;
+
+        let mut runner = TestRunner::deterministic();
+        let strat = strategy::RecordBatchStrategy::default();
+
+        for _ in 0..16 {
+            let mut tree = strat.new_tree(&mut runner).unwrap();
+            let (_, init) = tree.current();
+
+            // the number of rows shrinks before the number of columns
+            let mut prev = init.clone();
+            while tree.simplify() {
+                let (_, batch) = tree.current();
+                if batch.num_columns() < init.num_columns() {
+                    assert_eq!(prev.num_rows(), batch.num_rows());
+                } else {
+                    assert!(batch.num_rows() <= prev.num_rows());
+                }
+                assert!(batch.num_columns() <= prev.num_columns());
+                prev = batch;
+            }
+            assert_eq!(1, prev.num_columns());
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn roundtrip(cells in any::<Cells>()) {
+            let batch = cells.to_record_batch();
+            assert_eq!(cells.len(), batch.num_rows());
+            assert_eq!(cells.fields().len(), batch.num_columns());
+
+            let roundtrip = Cells::from_record_batch(&batch);
+            assert!(cells.bits_eq(&roundtrip));
+        }
+
+        #[test]
+        fn record_batch_strategy((schema, batch) in strategy::RecordBatchStrategy::default()) {
+            assert_eq!(schema, batch.schema());
+            for (field, column) in schema.fields().iter().zip(batch.columns()) {
+                if !field.is_nullable() {
+                    assert_eq!(0, column.null_count());
+                }
+            }
+
+            // columns without nulls are convertible to cells
+            let indices = batch
+                .columns()
+                .iter()
+                .enumerate()
+                .filter(|(_, c)| c.null_count() == 0)
+                .map(|(i, _)| i)
+                .collect::<Vec<_>>();
+            if !indices.is_empty() {
+                let cells = Cells::from_record_batch(&batch.project(&indices).unwrap());
+                assert_eq!(batch.num_rows(), cells.len());
+            }
+        }
+    }
+}
@@ -386,6 +386,116 @@ impl FieldData {
             }
         )
     }
+
+    /// Returns the indices of the minimum and maximum records of `self`,
+    /// or `None` if `self` is empty.
+    ///
+    /// Records are compared using [BitsOrd], and var-length records compare
+    /// lexicographically. This scans the records once without copying them.
+    /// If there are several minimum records then the index of the first
+    /// one is returned; if there are several maximum records then the index
+    /// of the last one is returned, matching [Iterator::min_by]
+    /// and [Iterator::max_by].
+    pub fn min_max_index(&self) -> Option<(usize, usize)> {
+        typed_field_data_go!(self, ref data, {
+            let mut records = data.iter().enumerate();
+            let (_, first) = records.next()?;
+            let (mut min, mut max) = ((0, first), (0, first));
+            for (i, record) in records {
+                if record.bits_cmp(min.1).is_lt() {
+                    min = (i, record);
+                }
+                if record.bits_cmp(max.1).is_ge() {
+                    max = (i, record);
+                }
+            }
+            Some((min.0, max.0))
+        })
+    }
+
+    /// Divides `self` into two at a record index.
+    ///
+    /// The first returned value contains the records in `[0, mid)`
+    /// and the second contains the records in `[mid, len)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid > self.len()`.
+    pub fn split_at(self, mid: usize) -> (FieldData, FieldData) {
+        typed_field_data_go!(self, mut data, {
+            let tail = data.split_off(mid);
+            (FieldData::from(data), FieldData::from(tail))
+        })
+    }
+
+    /// Returns a copy of `self` with `f` applied to each value of a
+    /// fixed-length numeric field. Var-length fields are returned unchanged.
+    ///
+    /// Each value is converted to `f64` and the result of `f` is converted
+    /// back to the original type. For integer fields the result is rounded
+    /// to the nearest integer and saturates at the bounds of the type,
+    /// and NaN becomes zero. 64-bit integers which are not exactly
+    /// representable as `f64` lose precision.
+    pub fn map_numeric<F>(&self, f: F) -> FieldData
+    where
+        F: Fn(f64) -> f64,
+    {
+        match self
+            .try_map(|value| Ok::<f64, std::convert::Infallible>(f(value)))
+        {
+            Ok(mapped) => mapped,
+            Err(never) => match never {},
+        }
+    }
+
+    /// Returns a copy of `self` with `f` applied to each value of a
+    /// fixed-length numeric field, or the first error returned by `f`.
+    /// Var-length fields are returned unchanged.
+    ///
+    /// Values are converted as in [Self::map_numeric].
+    pub fn try_map<F, E>(&self, f: F) -> Result<FieldData, E>
+    where
+        F: Fn(f64) -> Result<f64, E>,
+    {
+        typed_field_data_go!(
+            self,
+            _DT,
+            ref data,
+            {
+                let mapped = data
+                    .iter()
+                    .map(|value| f(*value as f64).map(|v| v.round() as _DT))
+                    .collect::<Result<Vec<_DT>, E>>()?;
+                Ok(FieldData::from(mapped))
+            },
+            Ok(FieldData::from(data.clone())),
+            {
+                #[allow(clippy::unnecessary_cast)]
+                let mapped = data
+                    .iter()
+                    .map(|value| f(*value as f64).map(|v| v as _DT))
+                    .collect::<Result<Vec<_DT>, E>>()?;
+                Ok(FieldData::from(mapped))
+            },
+            Ok(FieldData::from(data.clone()))
+        )
+    }
+
+    /// Concatenates the records of each of `parts`, in order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `parts` is empty, or if any two parts have a different type.
+    pub fn concat(parts: Vec<FieldData>) -> FieldData {
+        let mut parts = parts.into_iter();
+        let mut concat = parts
+            .next()
+            .expect("Cannot concatenate empty list of `FieldData`");
+        for part in parts {
+            concat.extend(part);
+        }
+        concat
+    }
 }
 
 impl BitsEq for FieldData {
@@ -411,6 +521,66 @@ mod tests {
     use super::*;
     use crate::strategy::{FieldDataParameters, FieldStrategyDatatype};
 
+    #[test]
+    fn map_numeric() {
+        let scale = |v: f64| v * 2.0;
+
+        assert_eq!(
+            FieldData::Int32(vec![2, -4, 6]),
+            FieldData::Int32(vec![1, -2, 3]).map_numeric(scale)
+        );
+        assert_eq!(
+            FieldData::Float32(vec![1.0, -5.0]),
+            FieldData::Float32(vec![0.5, -2.5]).map_numeric(scale)
+        );
+        assert_eq!(
+            FieldData::UInt16(vec![2, 3]),
+            FieldData::UInt16(vec![1, 2]).map_numeric(|v| v + 0.6)
+        );
+
+        // integer casts saturate
+        assert_eq!(
+            FieldData::Int8(vec![127, -128, 20]),
+            FieldData::Int8(vec![100, -100, 10]).map_numeric(scale)
+        );
+        assert_eq!(
+            FieldData::UInt8(vec![0, 255]),
+            FieldData::UInt8(vec![1, 250]).map_numeric(|v| (v - 5.0) * 2.0)
+        );
+        assert_eq!(
+            FieldData::UInt64(vec![0]),
+            FieldData::UInt64(vec![1]).map_numeric(|_| f64::NAN)
+        );
+
+        // var-length fields are unchanged
+        let var = FieldData::VecInt32(vec![vec![1, 2], vec![3]]);
+        assert_eq!(var, var.map_numeric(scale));
+    }
+
+    #[test]
+    fn try_map() {
+        let positive = |v: f64| {
+            if v > 0.0 {
+                Ok(v.sqrt())
+            } else {
+                Err(v)
+            }
+        };
+
+        assert_eq!(
+            Ok(FieldData::Float64(vec![2.0, 3.0])),
+            FieldData::Float64(vec![4.0, 9.0]).try_map(positive)
+        );
+        assert_eq!(
+            Err(-1.0),
+            FieldData::Int16(vec![4, -1, -2]).try_map(positive)
+        );
+        assert_eq!(
+            Ok(FieldData::VecInt16(vec![vec![-1]])),
+            FieldData::VecInt16(vec![vec![-1]]).try_map(positive)
+        );
+    }
+
     fn do_field_data_extend(dst: FieldData, src: FieldData) {
         let orig_dst = dst.clone();
         let orig_src = src.clone();
@@ -431,7 +601,89 @@ mod tests {
         })
     }
 
+    fn do_field_data_split_at(field: FieldData, mid: usize) {
+        let orig = field.clone();
+
+        let (left, right) = field.split_at(mid);
+        assert_eq!(mid, left.len());
+        assert_eq!(orig.len() - mid, right.len());
+        assert_eq!(
+            std::mem::discriminant(&orig),
+            std::mem::discriminant(&left)
+        );
+        assert_eq!(
+            std::mem::discriminant(&orig),
+            std::mem::discriminant(&right)
+        );
+
+        let concat = FieldData::concat(vec![left, right]);
+        assert!(orig.bits_eq(&concat));
+    }
+
+    fn do_field_data_min_max_index(field: FieldData) {
+        let Some((min, max)) = field.min_max_index() else {
+            assert!(field.is_empty());
+            return;
+        };
+
+        typed_field_data_go!(field, ref data, {
+            let expect_min = data
+                .iter()
+                .enumerate()
+                .min_by(|(_, l), (_, r)| l.bits_cmp(r))
+                .unwrap()
+                .0;
+            let expect_max = data
+                .iter()
+                .enumerate()
+                .max_by(|(_, l), (_, r)| l.bits_cmp(r))
+                .unwrap()
+                .0;
+            assert_eq!(expect_min, min);
+            assert_eq!(expect_max, max);
+        })
+    }
+
+    fn strat_field_data_split_at(
+        datatype: FieldStrategyDatatype,
+    ) -> impl Strategy<Value = (FieldData, usize)> {
+        let params = FieldDataParameters {
+            datatype: Some(datatype),
+            ..Default::default()
+        };
+        any_with::<FieldData>(params).prop_flat_map(|field| {
+            let len = field.len();
+            (Just(field), 0..=len)
+        })
+    }
+
     proptest! {
+        #[test]
+        fn field_data_split_at((field, mid) in (any::<Datatype>(), any::<CellValNum>()).prop_flat_map(|(dt, cvn)| {
+            strat_field_data_split_at(FieldStrategyDatatype::Datatype(dt, cvn))
+        })) {
+            do_field_data_split_at(field, mid)
+        }
+
+        #[test]
+        fn field_data_split_at_var((field, mid) in strat_field_data_split_at(
+            FieldStrategyDatatype::Datatype(Datatype::StringAscii, CellValNum::Var)
+        )) {
+            assert!(matches!(field, FieldData::VecUInt8(_)));
+            do_field_data_split_at(field, mid)
+        }
+
+        #[test]
+        fn field_data_min_max_index(field in (any::<Datatype>(), any::<CellValNum>()).prop_flat_map(|(dt, cvn)| {
+            let params = FieldDataParameters {
+                datatype: Some(FieldStrategyDatatype::Datatype(dt, cvn)),
+                ..Default::default()
+            };
+            any_with::<FieldData>(params)
+        })) {
+            do_field_data_min_max_index(field)
+        }
+
         #[test]
         fn field_data_extend((dst, src) in (any::<Datatype>(), any::<CellValNum>()).prop_flat_map(|(dt, cvn)| {
             let params = FieldDataParameters {
@@ -1,6 +1,9 @@
 pub mod field;
 pub mod write;
 
+#[cfg(feature = "arrow")]
+pub mod arrow;
+
 #[cfg(any(test, feature = "proptest-strategies"))]
 pub mod strategy;
 
@@ -8,11 +11,14 @@ use std::cmp::Ordering;
 use std::collections::hash_map::Entry;
 use std::collections::HashMap;
 use std::fmt::Debug;
+use std::hash::{Hash, Hasher};
 use std::ops::Range;
 
 use proptest::bits::{BitSetLike, VarBitSet};
+use proptest::prelude::Rng;
 
-use tiledb_common::datatype::physical::{BitsEq, BitsOrd};
+use tiledb_common::array::CellOrder;
+use tiledb_common::datatype::physical::{BitsEq, BitsKeyAdapter, BitsOrd};
 
 pub use self::field::FieldData;
 
@@ -182,6 +188,24 @@ impl Cells {
         }
     }
 
+    /// Sorts the cells by the coordinates of `dimensions` in the cell order `order`.
+    /// This is the order in which a dense read returns cells.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `order` is not `CellOrder::RowMajor` or `CellOrder::ColumnMajor`.
+    pub fn sort_cell_order(&mut self, dimensions: &[String], order: CellOrder) {
+        match order {
+            CellOrder::RowMajor => self.sort(dimensions),
+            CellOrder::ColumnMajor => {
+                let keys =
+                    dimensions.iter().rev().cloned().collect::<Vec<String>>();
+                self.sort(&keys)
+            }
+            order => panic!("Cannot sort cells by cell order {:?}", order),
+        }
+    }
+
     /// Returns a copy of the cells, sorted as if by `self.sort()`.
     pub fn sorted(&self, keys: &[String]) -> Self {
         let mut sorted = self.clone();
@@ -189,6 +213,31 @@ impl Cells {
         sorted
     }
 
+    /// Returns a hash of the field names and contents of these cells.
+    ///
+    /// Cells which are equal according to [BitsEq] have equal fingerprints,
+    /// so this can be used to quickly reject unequal cells.
+    /// Equal fingerprints do not guarantee equality; use `bits_eq` to confirm.
+    ///
+    /// The hash is computed using FNV-1a, so the fingerprint of the same
+    /// cells does not change between runs, builds, or platforms.
+    pub fn fingerprint(&self) -> u64 {
+        let mut keys = self.fields.keys().collect::<Vec<&String>>();
+        keys.sort();
+
+        let mut state = FnvHasher::default();
+        self.len().hash(&mut state);
+        for key in keys {
+            key.hash(&mut state);
+            typed_field_data_go!(self.fields[key], ref data, {
+                for value in data.iter() {
+                    BitsKeyAdapter(value).hash(&mut state)
+                }
+            })
+        }
+        state.finish()
+    }
+
     /// Returns the list of offsets beginning each group, i.e. run of contiguous values on `keys`.
     ///
     /// This is best used with sorted cells, but that is not required.
@@ -265,6 +314,53 @@ impl Cells {
         )
     }
 
+    /// Returns a subset of `n` records of `self` chosen uniformly at random
+    /// without replacement, or a copy of `self` if `n >= self.len()`.
+    ///
+    /// The records are chosen using reservoir sampling, and the sampled
+    /// records keep their relative order from `self`.
+    pub fn sample<R>(&self, n: usize, rng: &mut R) -> Cells
+    where
+        R: Rng + ?Sized,
+    {
+        if n >= self.len() {
+            return self.clone();
+        }
+
+        let mut reservoir = (0..n).collect::<Vec<usize>>();
+        for i in n..self.len() {
+            let j = rng.gen_range(0..=i);
+            if j < n {
+                reservoir[j] = i;
+            }
+        }
+
+        let mut preserve = VarBitSet::new_bitset(self.len());
+        for i in reservoir {
+            preserve.set(i);
+        }
+        self.filter(&preserve)
+    }
+
+    /// Returns a copy of `self` with an additional field `field_name`
+    /// whose value for each record is the position of the record in `self`.
+    ///
+    /// This is useful for checking the order of records after operations
+    /// such as [Cells::sort] and [Cells::dedup].
+    ///
+    /// Panics if `self` already has a field named `field_name`.
+    pub fn with_row_index(&self, field_name: &str) -> Cells {
+        let mut cells = self.clone();
+        let index = FieldData::UInt64((0..self.len() as u64).collect());
+        if !cells.add_field(field_name, index) {
+            panic!(
+                "Cannot add row index: field '{}' already exists",
+                field_name
+            )
+        }
+        cells
+    }
+
     /// Returns a subset of `self` containing only cells which have distinct values in `keys`
     /// such that `self.dedup(keys).count_distinct(keys) == self.len()`.
     /// The order of cells in the input is preserved and the
@@ -315,6 +411,173 @@ impl Cells {
         Some(Cells::new(projection))
     }
 
+    /// Returns the inner equi-join of `self` and `other` on `keys`.
+    ///
+    /// The output contains the fields of both `self` and `other`, and has one
+    /// record for each pair of records from `self` and `other` which are equal
+    /// on all of `keys`. Records are ordered by their position in `self`,
+    /// and then by their position in `other`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a field in `keys` is not present in both `self` and `other`,
+    /// or if it has a different type in each.
+    ///
+    /// Panics if a field which is not in `keys` is present in both `self` and `other`.
+    pub fn join(&self, other: &Cells, keys: &[String]) -> Cells {
+        for field in self.fields.keys() {
+            if other.fields.contains_key(field) && !keys.contains(field) {
+                panic!(
+                    "Cannot join: non-key field '{}' is present in both inputs",
+                    field
+                )
+            }
+        }
+
+        // identify each distinct value of `keys` across both inputs
+        let key_ids = {
+            let num_records = self.len() + other.len();
+            let mut key_ids = vec![0usize; num_records];
+
+            if !keys.is_empty() {
+                let key_fields =
+                    keys.iter().map(|k| k.as_ref()).collect::<Vec<&str>>();
+                let mut key_cells = self
+                    .projection(&key_fields)
+                    .expect("Cannot join: key field not found");
+                key_cells.extend(
+                    other
+                        .projection(&key_fields)
+                        .expect("Cannot join: key field not found"),
+                );
+
+                let mut idx = (0..num_records).collect::<Vec<usize>>();
+                let idx_comparator = key_cells.index_comparator(keys);
+                idx.sort_by(&idx_comparator);
+
+                for i in 1..idx.len() {
+                    key_ids[idx[i]] = key_ids[idx[i - 1]];
+                    if idx_comparator(&idx[i - 1], &idx[i]) != Ordering::Equal {
+                        key_ids[idx[i]] += 1;
+                    }
+                }
+            }
+            key_ids
+        };
+
+        let (left_ids, right_ids) = key_ids.split_at(self.len());
+
+        let mut right_index = HashMap::<usize, Vec<usize>>::new();
+        for (r, key_id) in right_ids.iter().enumerate() {
+            right_index.entry(*key_id).or_default().push(r);
+        }
+
+        let (left, right): (Vec<usize>, Vec<usize>) = left_ids
+            .iter()
+            .enumerate()
+            .flat_map(|(l, key_id)| {
+                right_index
+                    .get(key_id)
+                    .into_iter()
+                    .flatten()
+                    .map(move |r| (l, *r))
+            })
+            .unzip();
+
+        let take = |data: &FieldData, idx: &[usize]| {
+            typed_field_data_go!(data, ref values, {
+                FieldData::from(
+                    idx.iter()
+                        .map(|i| &values[*i])
+                        .cloned()
+                        .collect::<Vec<_>>(),
+                )
+            })
+        };
+
+        let mut fields = self
+            .fields
+            .iter()
+            .map(|(k, v)| (k.clone(), take(v, &left)))
+            .collect::<HashMap<String, FieldData>>();
+        fields.extend(
+            other
+                .fields
+                .iter()
+                .filter(|(k, _)| !keys.contains(k))
+                .map(|(k, v)| (k.clone(), take(v, &right))),
+        );
+
+        Cells::new(fields)
+    }
+
+    /// Returns one record for each distinct value of `keys`, containing the
+    /// `keys` fields and one field for each of `aggregates`.
+    ///
+    /// Each entry of `aggregates` names an input field and the reduction to
+    /// apply to it over each group. The output field for each aggregate is
+    /// named as by [Aggregate::output_name]. Records are ordered by `keys`.
+    ///
+    /// `Aggregate::Count` produces `u64` and `Aggregate::Mean` produces `f64`.
+    /// `Aggregate::Sum` produces `i64`, `u64`, or `f64` depending on whether the
+    /// input field is signed, unsigned, or floating-point, and wraps on overflow.
+    /// `Aggregate::Min` and `Aggregate::Max` produce the type of the input field.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a field in `keys` or `aggregates` is not present.
+    ///
+    /// Panics if `Aggregate::Sum` or `Aggregate::Mean` is requested for a
+    /// variable-length field.
+    pub fn group_by(
+        &self,
+        keys: &[String],
+        aggregates: &[(String, Aggregate)],
+    ) -> Cells {
+        let sorted = self.sorted(keys);
+        let groups = sorted.identify_groups(keys).unwrap_or(vec![0]);
+        let group_starts = &groups[0..groups.len() - 1];
+
+        let mut fields = keys
+            .iter()
+            .map(|k| {
+                let Some(data) = sorted.fields.get(k) else {
+                    panic!(
+                        "Cannot group: key '{}' not found (fields are {:?})",
+                        k,
+                        self.fields.keys()
+                    )
+                };
+                let firsts = typed_field_data_go!(data, ref values, {
+                    FieldData::from(
+                        group_starts
+                            .iter()
+                            .map(|i| &values[*i])
+                            .cloned()
+                            .collect::<Vec<_>>(),
+                    )
+                });
+                (k.clone(), firsts)
+            })
+            .collect::<HashMap<String, FieldData>>();
+
+        for (field, aggregate) in aggregates.iter() {
+            let Some(data) = sorted.fields.get(field) else {
+                panic!(
+                    "Cannot group: field '{}' not found (fields are {:?})",
+                    field,
+                    self.fields.keys()
+                )
+            };
+            fields.insert(
+                aggregate.output_name(field),
+                aggregate.apply(data, &groups),
+            );
+        }
+
+        Cells::new(fields)
+    }
+
     /// Adds an additional field to `self`. Returns `true` if successful,
     /// i.e. the field data is valid for the current set of cells
     /// and there is not already a field for the key.
@@ -332,6 +595,136 @@ impl Cells {
     }
 }
 
+/// A reduction over a group of records. See [Cells::group_by].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Aggregate {
+    Count,
+    Sum,
+    Min,
+    Max,
+    Mean,
+}
+
+impl Aggregate {
+    /// Returns the name of the field produced by applying
+    /// this aggregate to `field`, e.g. `sum(a)`.
+    pub fn output_name(&self, field: &str) -> String {
+        let name = match self {
+            Self::Count => "count",
+            Self::Sum => "sum",
+            Self::Min => "min",
+            Self::Max => "max",
+            Self::Mean => "mean",
+        };
+        format!("{}({})", name, field)
+    }
+
+    /// Applies this aggregate to each run of `data` delimited by `groups`.
+    fn apply(&self, data: &FieldData, groups: &[usize]) -> FieldData {
+        let ranges = groups.windows(2).map(|w| w[0]..w[1]);
+        match self {
+            Self::Count => FieldData::from(
+                ranges.map(|r| r.len() as u64).collect::<Vec<u64>>(),
+            ),
+            Self::Min | Self::Max => {
+                let select = if *self == Self::Min {
+                    Ordering::Less
+                } else {
+                    Ordering::Greater
+                };
+                typed_field_data_go!(data, ref values, {
+                    FieldData::from(
+                        ranges
+                            .map(|r| {
+                                values[r]
+                                    .iter()
+                                    .reduce(|acc, v| {
+                                        if v.bits_cmp(acc) == select {
+                                            v
+                                        } else {
+                                            acc
+                                        }
+                                    })
+                                    .unwrap()
+                            })
+                            .cloned()
+                            .collect::<Vec<_>>(),
+                    )
+                })
+            }
+            Self::Sum | Self::Mean => {
+                assert!(
+                    data.is_cell_single(),
+                    "Cannot aggregate {:?} over variable-length field",
+                    self
+                );
+                let is_mean = *self == Self::Mean;
+                typed_field_data_go!(
+                    data,
+                    _DT,
+                    ref values,
+                    {
+                        let sums = ranges
+                            .map(|r| {
+                                let len = r.len();
+                                let sum = values[r]
+                                    .iter()
+                                    .fold(0i128, |acc, v| acc + *v as i128);
+                                (sum, len)
+                            })
+                            .collect::<Vec<_>>();
+                        if is_mean {
+                            FieldData::from(
+                                sums.into_iter()
+                                    .map(|(sum, len)| sum as f64 / len as f64)
+                                    .collect::<Vec<f64>>(),
+                            )
+                        } else if _DT::MIN == 0 {
+                            FieldData::from(
+                                sums.into_iter()
+                                    .map(|(sum, _)| sum as u64)
+                                    .collect::<Vec<u64>>(),
+                            )
+                        } else {
+                            FieldData::from(
+                                sums.into_iter()
+                                    .map(|(sum, _)| sum as i64)
+                                    .collect::<Vec<i64>>(),
+                            )
+                        }
+                    },
+                    {
+                        let _ = values;
+                        unreachable!()
+                    },
+                    {
+                        FieldData::from(
+                            ranges
+                                .map(|r| {
+                                    let len = r.len();
+                                    #[allow(clippy::unnecessary_cast)]
+                                    let sum = values[r]
+                                        .iter()
+                                        .fold(0f64, |acc, v| acc + *v as f64);
+                                    if is_mean {
+                                        sum / len as f64
+                                    } else {
+                                        sum
+                                    }
+                                })
+                                .collect::<Vec<f64>>(),
+                        )
+                    },
+                    {
+                        let _ = values;
+                        unreachable!()
+                    }
+                )
+            }
+        }
+    }
+}
+
 impl BitsEq for Cells {
     fn bits_eq(&self, other: &Self) -> bool {
         for (key, mine) in self.fields().iter() {
@@ -376,90 +769,128 @@ impl StructuredCells {
     pub fn slice(&self, slices: Vec<Range<usize>>) -> Self {
         assert_eq!(slices.len(), self.dimensions.len()); // this is doable but unimportant
 
-        struct NextIndex<'a> {
-            dimensions: &'a [usize],
-            ranges: &'a [Range<usize>],
-            cursors: Option<Vec<usize>>,
-        }
-
-        impl<'a> NextIndex<'a> {
-            fn new(
-                dimensions: &'a [usize],
-                ranges: &'a [Range<usize>],
-            ) -> Self {
-                for r in ranges {
-                    if r.is_empty() {
-                        return NextIndex {
-                            dimensions,
-                            ranges,
-                            cursors: None,
-                        };
-                    }
-                }
+        let steps = vec![1; slices.len()];
+        StructuredCells {
+            dimensions: self.dimensions.clone(),
+            cells: self.cells.filter(&self.select(&slices, &steps)),
+        }
+    }
+
+    /// Returns the cells at every `step`th position of each `Range`,
+    /// beginning with its `start`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of slices does not match the number of dimensions,
+    /// or if any step is zero.
+    pub fn slice_strided(&self, slices: Vec<(Range<usize>, usize)>) -> Self {
+        assert_eq!(slices.len(), self.dimensions.len());
+        assert!(slices.iter().all(|(_, step)| *step > 0));
 
-                NextIndex {
+        let (ranges, steps): (Vec<Range<usize>>, Vec<usize>) =
+            slices.into_iter().unzip();
+
+        let dimensions = ranges
+            .iter()
+            .zip(steps.iter())
+            .map(|(r, step)| r.len().div_ceil(*step))
+            .collect::<Vec<usize>>();
+
+        StructuredCells::new(
+            dimensions,
+            self.cells.filter(&self.select(&ranges, &steps)),
+        )
+    }
+
+    /// Returns the set of cell indices which are selected by
+    /// every `step`th position of each of `ranges`.
+    fn select(&self, ranges: &[Range<usize>], steps: &[usize]) -> VarBitSet {
+        let mut v = VarBitSet::new_bitset(self.cells.len());
+
+        NextIndex::new(self.dimensions.as_slice(), ranges, steps)
+            .for_each(|idx| v.set(idx));
+
+        v
+    }
+}
+
+/// Iterates over the indices of cells selected from each dimension of a `StructuredCells`.
+struct NextIndex<'a> {
+    dimensions: &'a [usize],
+    ranges: &'a [Range<usize>],
+    steps: &'a [usize],
+    cursors: Option<Vec<usize>>,
+}
+
+impl<'a> NextIndex<'a> {
+    fn new(
+        dimensions: &'a [usize],
+        ranges: &'a [Range<usize>],
+        steps: &'a [usize],
+    ) -> Self {
+        for r in ranges {
+            if r.is_empty() {
+                return NextIndex {
                     dimensions,
                     ranges,
-                    cursors: Some(
-                        ranges.iter().map(|r| r.start).collect::<Vec<usize>>(),
-                    ),
-                }
-            }
-
-            fn compute(&self) -> usize {
-                let Some(cursors) = self.cursors.as_ref() else {
-                    unreachable!()
+                    steps,
+                    cursors: None,
                 };
-                let mut index = 0;
-                let mut scale = 1;
-                for i in 0..self.dimensions.len() {
-                    let i = self.dimensions.len() - i - 1;
-                    index += cursors[i] * scale;
-                    scale *= self.dimensions[i];
-                }
-                index
             }
+        }
 
-            fn advance(&mut self) {
-                let Some(cursors) = self.cursors.as_mut() else {
-                    return;
-                };
-                for d in 0..self.dimensions.len() {
-                    let d = self.dimensions.len() - d - 1;
-                    if cursors[d] + 1 < self.ranges[d].end {
-                        cursors[d] += 1;
-                        return;
-                    } else {
-                        cursors[d] = self.ranges[d].start;
-                    }
-                }
+        NextIndex {
+            dimensions,
+            ranges,
+            steps,
+            cursors: Some(
+                ranges.iter().map(|r| r.start).collect::<Vec<usize>>(),
+            ),
+        }
+    }
 
-                // this means that we reset the final dimension
-                self.cursors = None;
-            }
+    fn compute(&self) -> usize {
+        let Some(cursors) = self.cursors.as_ref() else {
+            unreachable!()
+        };
+        let mut index = 0;
+        let mut scale = 1;
+        for i in 0..self.dimensions.len() {
+            let i = self.dimensions.len() - i - 1;
+            index += cursors[i] * scale;
+            scale *= self.dimensions[i];
         }
+        index
+    }
 
-        impl Iterator for NextIndex<'_> {
-            type Item = usize;
-            fn next(&mut self) -> Option<Self::Item> {
-                if self.cursors.is_some() {
-                    let index = self.compute();
-                    self.advance();
-                    Some(index)
-                } else {
-                    None
-                }
+    fn advance(&mut self) {
+        let Some(cursors) = self.cursors.as_mut() else {
+            return;
+        };
+        for d in 0..self.dimensions.len() {
+            let d = self.dimensions.len() - d - 1;
+            if cursors[d] + self.steps[d] < self.ranges[d].end {
+                cursors[d] += self.steps[d];
+                return;
+            } else {
+                cursors[d] = self.ranges[d].start;
             }
         }
 
-        let mut v = VarBitSet::new_bitset(self.cells.len());
-
-        NextIndex::new(self.dimensions.as_slice(), slices.as_slice())
-            .for_each(|idx| v.set(idx));
+        // this means that we reset the final dimension
+        self.cursors = None;
+    }
+}
 
-        StructuredCells {
-            dimensions: self.dimensions.clone(),
-            cells: self.cells.filter(&v),
+impl Iterator for NextIndex<'_> {
+    type Item = usize;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.cursors.is_some() {
+            let index = self.compute();
+            self.advance();
+            Some(index)
+        } else {
+            None
         }
     }
 }
@@ -504,13 +935,130 @@ impl<'b> PartialEq<CellsView<'b>> for CellsView<'_> {
     }
 }
 
+impl<'a> CellsView<'a> {
+    /// Returns an iterator over the records of this view.
+    ///
+    /// Each item borrows one value from each of the viewed fields,
+    /// and can be used as a key for hashing and equality comparison
+    /// of whole records.
+    pub fn rows(&self) -> impl Iterator<Item = RowRef<'a>> + 'a {
+        let (cells, keys) = (self.cells, self.keys);
+        self.slice
+            .clone()
+            .map(move |index| RowRef { cells, keys, index })
+    }
+}
+
+/// A [Hasher] implementing the 64-bit FNV-1a algorithm.
+///
+/// Integers are hashed as little-endian bytes, so the output does not depend
+/// on the platform, the Rust version, or a random seed.
+struct FnvHasher(u64);
+
+impl Default for FnvHasher {
+    fn default() -> Self {
+        FnvHasher(0xcbf29ce484222325)
+    }
+}
+
+impl Hasher for FnvHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= *byte as u64;
+            self.0 = self.0.wrapping_mul(0x100000001b3);
+        }
+    }
+
+    fn write_u16(&mut self, i: u16) {
+        self.write(&i.to_le_bytes())
+    }
+
+    fn write_u32(&mut self, i: u32) {
+        self.write(&i.to_le_bytes())
+    }
+
+    fn write_u64(&mut self, i: u64) {
+        self.write(&i.to_le_bytes())
+    }
+
+    fn write_u128(&mut self, i: u128) {
+        self.write(&i.to_le_bytes())
+    }
+
+    fn write_usize(&mut self, i: usize) {
+        self.write_u64(i as u64)
+    }
+}
+
+/// A reference to a single record of a [CellsView].
+///
+/// Two `RowRef`s are equal if they view the same fields in the same order
+/// and each of those fields has the same bits in both records.
+/// Hashing is consistent with equality, using [BitsKeyAdapter] for
+/// each field value.
+#[derive(Clone, Copy, Debug)]
+pub struct RowRef<'a> {
+    cells: &'a Cells,
+    keys: &'a [String],
+    index: usize,
+}
+
+impl RowRef<'_> {
+    /// Returns the index of this record in the underlying `Cells`.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+}
+
+impl PartialEq for RowRef<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        if self.keys != other.keys {
+            return false;
+        }
+
+        self.keys.iter().all(|key| {
+            let mine = &self.cells.fields[key];
+            let Some(theirs) = other.cells.fields.get(key) else {
+                return false;
+            };
+            typed_field_data_cmp!(
+                mine,
+                theirs,
+                _DT,
+                ref mine,
+                ref theirs,
+                mine[self.index].bits_eq(&theirs[other.index]),
+                false
+            )
+        })
+    }
+}
+
+impl Eq for RowRef<'_> {}
+
+impl Hash for RowRef<'_> {
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        for key in self.keys.iter() {
+            typed_field_data_go!(self.cells.fields[key], ref data, {
+                BitsKeyAdapter(&data[self.index]).hash(state)
+            })
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::collections::HashSet;
     use std::rc::Rc;
 
     use proptest::prelude::*;
-    use tiledb_common::datatype::physical::BitsKeyAdapter;
     use tiledb_pod::array::schema::SchemaData;
 
     use super::*;
@@ -556,31 +1104,30 @@ mod tests {
             assert_eq!(cells, cells_sorted);
         }
 
-        /*
-         * We want to verify that the contents of the records are the
-         * same before and after the sort. We can precisely do that
-         * with a hash join, though it's definitely tricky to turn
-         * the columnar data into rows, or we can approximate it
-         * by sorting and comparing each column, which is not fully
-         * precise but way easier.
-         */
-        for (fname, data) in cells.fields().iter() {
-            let Some(data_sorted) = cells_sorted.fields().get(fname) else {
-                unreachable!()
-            };
+        // verify that the contents of the records are the same
+        // before and after the sort using a hash join on all fields
+        let fields = cells.fields().keys().cloned().collect::<Vec<_>>();
+        let orig_view = cells.view(&fields, 0..cells.len());
+        let sorted_view = cells_sorted.view(&fields, 0..cells_sorted.len());
 
-            let orig_sorted = {
-                let mut orig = data.clone();
-                orig.sort();
-                orig
-            };
-            let sorted_sorted = {
-                let mut sorted = data_sorted.clone();
-                sorted.sort();
-                sorted
-            };
-            assert_eq!(orig_sorted, sorted_sorted);
+        let mut counts = HashMap::<RowRef, usize>::new();
+        for row in orig_view.rows() {
+            *counts.entry(row).or_default() += 1;
         }
+        for row in sorted_view.rows() {
+            match counts.entry(row) {
+                Entry::Occupied(mut o) => {
+                    *o.get_mut() -= 1;
+                    if *o.get() == 0 {
+                        o.remove();
+                    }
+                }
+                Entry::Vacant(_) => {
+                    panic!("Sorted record {} not found in input", row.index())
+                }
+            }
+        }
+        assert!(counts.is_empty());
     }
 
     fn do_cells_slice_1d(cells: Cells, slice: Range<usize>) {
@@ -648,6 +1195,55 @@ mod tests {
         }
     }
 
+    fn do_cells_slice_strided_2d(
+        cells: Cells,
+        d1: usize,
+        d2: usize,
+        s1: (Range<usize>, usize),
+        s2: (Range<usize>, usize),
+    ) {
+        let mut cells = cells;
+        cells.truncate(d1 * d2);
+
+        let cells = StructuredCells::new(vec![d1, d2], cells);
+        let sliced = cells.slice_strided(vec![s1.clone(), s2.clone()]);
+
+        let rows = s1.0.clone().step_by(s1.1).collect::<Vec<usize>>();
+        let cols = s2.0.clone().step_by(s2.1).collect::<Vec<usize>>();
+        assert_eq!(2, sliced.num_dimensions());
+        assert_eq!(rows.len(), sliced.dimension_len(0));
+        assert_eq!(cols.len(), sliced.dimension_len(1));
+
+        let sliced = sliced.into_inner();
+        let cells = cells.into_inner();
+
+        assert_eq!(cells.fields().len(), sliced.fields().len());
+
+        for (key, value) in cells.fields.iter() {
+            let Some(sliced) = sliced.fields().get(key) else {
+                unreachable!()
+            };
+
+            typed_field_data_cmp!(
+                value,
+                sliced,
+                _DT,
+                ref value_data,
+                ref sliced_data,
+                {
+                    let expect = rows
+                        .iter()
+                        .flat_map(|r| cols.iter().map(move |c| (r * d2) + c))
+                        .map(|i| &value_data[i])
+                        .cloned()
+                        .collect::<Vec<_>>();
+                    assert_eq!(expect, *sliced_data);
+                },
+                unreachable!()
+            );
+        }
+    }
+
     fn do_cells_slice_3d(
         cells: Cells,
         d1: usize,
@@ -845,6 +1441,109 @@ mod tests {
         assert_eq!(dedup.len(), out_cursor);
     }
 
+    fn do_cells_fingerprint(cells: Cells) {
+        // a new map has a different iteration order
+        let rebuilt = Cells::new(cells.fields().clone().into_iter().collect());
+        assert!(cells.bits_eq(&rebuilt));
+        assert_eq!(cells.fingerprint(), rebuilt.fingerprint());
+
+        let keys = cells.fields().keys().cloned().collect::<Vec<String>>();
+        let sorted = cells.sorted(&keys);
+        if cells.bits_eq(&sorted) {
+            assert_eq!(cells.fingerprint(), sorted.fingerprint());
+        }
+    }
+
+    fn do_cells_with_row_index(cells: Cells, keys: Vec<String>) {
+        const ROW_INDEX: &str = "__row_index";
+        let indexed = cells.with_row_index(ROW_INDEX);
+
+        assert_eq!(cells.len(), indexed.len());
+        assert_eq!(cells.fields().len() + 1, indexed.fields().len());
+        assert_eq!(
+            &FieldData::UInt64((0..cells.len() as u64).collect()),
+            indexed.fields().get(ROW_INDEX).unwrap()
+        );
+
+        let fields = cells
+            .fields()
+            .keys()
+            .map(|k| k.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(Some(cells.clone()), indexed.projection(&fields));
+
+        // dedup keeps the first record of each group, in order
+        let dedup = indexed.dedup(&keys);
+        let FieldData::UInt64(ref index) = dedup.fields()[ROW_INDEX] else {
+            unreachable!()
+        };
+        assert!(index.windows(2).all(|w| w[0] < w[1]));
+        if !index.is_empty() {
+            assert_eq!(0, index[0]);
+        }
+    }
+
+    #[test]
+    fn cells_fingerprint_stable() {
+        let cells = Cells::new(HashMap::from([
+            ("a".to_owned(), FieldData::Int32(vec![1, 2, 3])),
+            (
+                "b".to_owned(),
+                FieldData::Float64(vec![0.5, -0.0, f64::NAN]),
+            ),
+        ]));
+        // the fingerprint is the same in every build
+        assert_eq!(7385544628240283225, cells.fingerprint());
+    }
+
+    #[test]
+    #[should_panic]
+    fn cells_with_row_index_exists() {
+        let cells = Cells::new(HashMap::from([(
+            "i".to_owned(),
+            FieldData::from(vec![1u64, 2, 3]),
+        )]));
+        cells.with_row_index("i");
+    }
+
+    #[test]
+    fn cells_sort_cell_order() {
+        let cells = Cells::new(HashMap::from([
+            ("rows".to_owned(), FieldData::from(vec![2i32, 1, 2, 1])),
+            ("cols".to_owned(), FieldData::from(vec![1i32, 2, 2, 1])),
+            ("a".to_owned(), FieldData::from(vec![3u8, 2, 4, 1])),
+        ]));
+        let dimensions = vec!["rows".to_owned(), "cols".to_owned()];
+
+        let mut row_major = cells.clone();
+        row_major.sort_cell_order(&dimensions, CellOrder::RowMajor);
+        let expect = Cells::new(HashMap::from([
+            ("rows".to_owned(), FieldData::from(vec![1i32, 1, 2, 2])),
+            ("cols".to_owned(), FieldData::from(vec![1i32, 2, 1, 2])),
+            ("a".to_owned(), FieldData::from(vec![1u8, 2, 3, 4])),
+        ]));
+        assert_eq!(expect, row_major);
+
+        let mut col_major = cells.clone();
+        col_major.sort_cell_order(&dimensions, CellOrder::ColumnMajor);
+        let expect = Cells::new(HashMap::from([
+            ("rows".to_owned(), FieldData::from(vec![1i32, 2, 1, 2])),
+            ("cols".to_owned(), FieldData::from(vec![1i32, 1, 2, 2])),
+            ("a".to_owned(), FieldData::from(vec![1u8, 3, 2, 4])),
+        ]));
+        assert_eq!(expect, col_major);
+    }
+
+    #[test]
+    #[should_panic]
+    fn cells_sort_cell_order_hilbert() {
+        let mut cells = Cells::new(HashMap::from([(
+            "d".to_owned(),
+            FieldData::from(vec![1i32, 2, 3]),
+        )]));
+        cells.sort_cell_order(&["d".to_owned()], CellOrder::Hilbert);
+    }
+
     fn do_cells_projection(cells: Cells, keys: Vec<String>) {
         let proj = cells
             .projection(&keys.iter().map(|s| s.as_ref()).collect::<Vec<&str>>())
@@ -865,6 +1564,214 @@ mod tests {
         assert_eq!(keys.len(), proj.fields().len());
     }
 
+    fn do_cells_sample(cells: Cells, n: usize, seed: [u8; 32]) {
+        let mut rng = proptest::test_runner::TestRng::from_seed(
+            proptest::test_runner::RngAlgorithm::ChaCha,
+            &seed,
+        );
+        let sample = cells.sample(n, &mut rng);
+
+        assert_eq!(std::cmp::min(n, cells.len()), sample.len());
+        assert_eq!(cells.fields().len(), sample.fields().len());
+
+        let keys = cells.fields().keys().cloned().collect::<Vec<String>>();
+        let rows = cells
+            .view(&keys, 0..cells.len())
+            .rows()
+            .collect::<HashSet<_>>();
+        for row in sample.view(&keys, 0..sample.len()).rows() {
+            assert!(rows.contains(&row));
+        }
+    }
+
+    fn do_cells_join(cells: Cells, keys: Vec<String>, sides: Vec<bool>) {
+        let (left_fields, right_fields) = {
+            let mut left_fields = keys.clone();
+            let mut right_fields = keys.clone();
+            for (field, side) in cells
+                .fields()
+                .keys()
+                .filter(|f| !keys.contains(f))
+                .zip(sides)
+            {
+                if side {
+                    left_fields.push(field.clone());
+                } else {
+                    right_fields.push(field.clone());
+                }
+            }
+            (left_fields, right_fields)
+        };
+
+        let left = cells
+            .projection(
+                &left_fields
+                    .iter()
+                    .map(|s| s.as_ref())
+                    .collect::<Vec<&str>>(),
+            )
+            .unwrap();
+        let right = cells
+            .projection(
+                &right_fields
+                    .iter()
+                    .map(|s| s.as_ref())
+                    .collect::<Vec<&str>>(),
+            )
+            .unwrap();
+
+        let joined = left.join(&right, &keys);
+        assert_eq!(
+            left_fields.len() + right_fields.len() - keys.len(),
+            joined.fields().len()
+        );
+
+        // NB: compare bits rather than using `CellsView`, which does not match NaN
+        let record_eq =
+            |a: &Cells, ai: usize, b: &Cells, bi: usize, fields: &[String]| {
+                fields.iter().all(|f| {
+                    a.fields()[f]
+                        .slice(ai, 1)
+                        .bits_eq(&b.fields()[f].slice(bi, 1))
+                })
+            };
+
+        let mut out_cursor = 0;
+        for l in 0..left.len() {
+            for r in 0..right.len() {
+                if !record_eq(&left, l, &right, r, &keys) {
+                    continue;
+                }
+                assert!(out_cursor < joined.len());
+                assert!(record_eq(&left, l, &joined, out_cursor, &left_fields));
+                assert!(record_eq(
+                    &right,
+                    r,
+                    &joined,
+                    out_cursor,
+                    &right_fields
+                ));
+                out_cursor += 1;
+            }
+        }
+        assert_eq!(joined.len(), out_cursor);
+    }
+
+    fn do_cells_group_by(cells: Cells, keys: Vec<String>) {
+        let aggregates = cells
+            .fields()
+            .keys()
+            .flat_map(|f| {
+                [Aggregate::Count, Aggregate::Min, Aggregate::Max]
+                    .into_iter()
+                    .map(|a| (f.clone(), a))
+            })
+            .collect::<Vec<_>>();
+
+        let grouped = cells.group_by(&keys, &aggregates);
+        assert_eq!(cells.count_distinct(&keys), grouped.len());
+        assert_eq!(keys.len() + aggregates.len(), grouped.fields().len());
+        assert!(grouped.is_sorted(&keys));
+
+        for g in 0..grouped.len() {
+            let mut members = VarBitSet::new_bitset(cells.len());
+            for r in 0..cells.len() {
+                let is_member = keys.iter().all(|k| {
+                    typed_field_data_cmp!(
+                        &cells.fields()[k],
+                        &grouped.fields()[k],
+                        _DT,
+                        ref input,
+                        ref output,
+                        input[r].bits_eq(&output[g]),
+                        unreachable!()
+                    )
+                });
+                if is_member {
+                    members.set(r);
+                }
+            }
+            let group = cells.filter(&members);
+
+            for (field, aggregate) in aggregates.iter() {
+                let output = &grouped.fields()[&aggregate.output_name(field)];
+                if *aggregate == Aggregate::Count {
+                    let FieldData::UInt64(ref counts) = output else {
+                        unreachable!()
+                    };
+                    assert_eq!(group.len() as u64, counts[g]);
+                    continue;
+                }
+                typed_field_data_cmp!(
+                    &group.fields()[field],
+                    output,
+                    _DT,
+                    ref input,
+                    ref output,
+                    {
+                        let select = if *aggregate == Aggregate::Min {
+                            Ordering::Less
+                        } else {
+                            Ordering::Greater
+                        };
+                        assert!(input.iter().any(|v| v.bits_eq(&output[g])));
+                        assert!(input
+                            .iter()
+                            .all(|v| v.bits_cmp(&output[g]) != select));
+                    },
+                    unreachable!()
+                )
+            }
+        }
+    }
+
+    #[test]
+    fn cells_group_by_sum_mean() {
+        let cells = Cells::new(HashMap::from([
+            ("k".to_owned(), FieldData::from(vec![2u8, 1, 2, 1, 2])),
+            ("i".to_owned(), FieldData::from(vec![-5i16, 3, 10, 4, 1])),
+            ("u".to_owned(), FieldData::from(vec![1u32, 2, 3, 4, 5])),
+            (
+                "f".to_owned(),
+                FieldData::from(vec![0.5f32, 1.0, 1.5, 2.0, 4.0]),
+            ),
+        ]));
+
+        let keys = vec!["k".to_owned()];
+        let aggregates = ["i", "u", "f"]
+            .into_iter()
+            .flat_map(|f| {
+                [
+                    (f.to_owned(), Aggregate::Sum),
+                    (f.to_owned(), Aggregate::Mean),
+                ]
+            })
+            .collect::<Vec<_>>();
+
+        let grouped = cells.group_by(&keys, &aggregates);
+
+        let expect = Cells::new(HashMap::from([
+            ("k".to_owned(), FieldData::from(vec![1u8, 2])),
+            ("sum(i)".to_owned(), FieldData::from(vec![7i64, 6])),
+            ("mean(i)".to_owned(), FieldData::from(vec![3.5f64, 2.0])),
+            ("sum(u)".to_owned(), FieldData::from(vec![6u64, 9])),
+            ("mean(u)".to_owned(), FieldData::from(vec![3.0f64, 3.0])),
+            ("sum(f)".to_owned(), FieldData::from(vec![3.0f64, 6.0])),
+            ("mean(f)".to_owned(), FieldData::from(vec![1.5f64, 2.0])),
+        ]));
+        assert_eq!(expect, grouped);
+    }
+
+    #[test]
+    #[should_panic]
+    fn cells_group_by_sum_var() {
+        let cells = Cells::new(HashMap::from([(
+            "s".to_owned(),
+            FieldData::from(vec![b"foo".to_vec(), b"bar".to_vec()]),
+        )]));
+        cells.group_by(&[], &[("s".to_owned(), Aggregate::Sum)]);
+    }
+
     proptest! {
         #[test]
         fn cells_extend((dst, src) in any::<SchemaData>().prop_flat_map(|s| {
@@ -923,6 +1830,32 @@ mod tests {
             do_cells_slice_2d(cells, d1, d2, s1, s2)
         }
 
+        #[test]
+        fn cells_slice_strided_2d((cells, d1, d2, b11, b12, b21, b22, step1, step2) in any_with::<Cells>(CellsParameters {
+            min_records: 1,
+            ..Default::default()
+        }).prop_flat_map(|cells| {
+            let ncells = cells.len();
+            (Just(cells),
+            1..=((ncells as f64).sqrt() as usize),
+            1..=((ncells as f64).sqrt() as usize))
+                .prop_flat_map(|(cells, d1, d2)| {
+                    (Just(cells),
+                    Just(d1),
+                    Just(d2),
+                    0..=d1,
+                    0..=d1,
+                    0..=d2,
+                    0..=d2,
+                    1..=d1,
+                    1..=d2)
+                })
+        })) {
+            let s1 = std::cmp::min(b11, b12).. std::cmp::max(b11, b12);
+            let s2 = std::cmp::min(b21, b22).. std::cmp::max(b21, b22);
+            do_cells_slice_strided_2d(cells, d1, d2, (s1, step1), (s2, step2))
+        }
+
         #[test]
         fn cells_slice_3d((cells, d1, d2, d3, b11, b12, b21, b22, b31, b32) in any_with::<Cells>(CellsParameters {
             min_records: 1,
@@ -983,6 +1916,21 @@ mod tests {
             do_cells_dedup(cells, keys)
         }
 
+        #[test]
+        fn cells_fingerprint(cells in any::<Cells>()) {
+            do_cells_fingerprint(cells)
+        }
+
+        #[test]
+        fn cells_with_row_index((cells, keys) in any::<Cells>().prop_flat_map(|c| {
+            let keys = c.fields().keys().cloned().collect::<Vec<String>>();
+            let nkeys = keys.len();
+            (Just(c), proptest::sample::subsequence(keys, 0..=nkeys).prop_shuffle())
+        })) {
+            prop_assume!(!cells.fields().contains_key("__row_index"));
+            do_cells_with_row_index(cells, keys)
+        }
+
         #[test]
         fn cells_projection((cells, keys) in any::<Cells>().prop_flat_map(|c| {
             let keys = c.fields().keys().cloned().collect::<Vec<String>>();
@@ -991,5 +1939,31 @@ mod tests {
         })) {
             do_cells_projection(cells, keys)
         }
+
+        #[test]
+        fn cells_sample((cells, n) in any::<Cells>().prop_flat_map(|c| {
+            let len = c.len();
+            (Just(c), 0..=len + 1)
+        }), seed in any::<[u8; 32]>()) {
+            do_cells_sample(cells, n, seed)
+        }
+
+        #[test]
+        fn cells_group_by((cells, keys) in any::<Cells>().prop_flat_map(|c| {
+            let keys = c.fields().keys().cloned().collect::<Vec<String>>();
+            let nkeys = keys.len();
+            (Just(c), proptest::sample::subsequence(keys, 1..=nkeys).prop_shuffle())
+        })) {
+            do_cells_group_by(cells, keys)
+        }
+
+        #[test]
+        fn cells_join((cells, keys, sides) in any::<Cells>().prop_flat_map(|c| {
+            let keys = c.fields().keys().cloned().collect::<Vec<String>>();
+            let nkeys = keys.len();
+            (Just(c), proptest::sample::subsequence(keys, 1..=nkeys).prop_shuffle(), proptest::collection::vec(any::<bool>(), nkeys))
+        })) {
+            do_cells_join(cells, keys, sides)
+        }
     }
 }
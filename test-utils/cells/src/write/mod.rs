@@ -4,7 +4,6 @@ pub mod strategy;
 use std::ops::Deref;
 
 use tiledb_common::array::{CellOrder, CellValNum};
-use tiledb_common::datatype::physical::BitsOrd;
 use tiledb_common::range::{NonEmptyDomain, Range, SingleValueRange};
 use tiledb_pod::array::schema::SchemaData;
 
@@ -41,28 +40,15 @@ impl SparseWriteInput {
             .iter()
             .map(|(dim, cell_val_num)| {
                 let dim_cells = self.data.fields().get(dim).unwrap();
+                let (min, max) = dim_cells.min_max_index()?;
                 Some(typed_field_data_go!(
                     dim_cells,
                     _DT,
                     ref dim_cells,
+                    Range::from(&[dim_cells[min], dim_cells[max]]),
                     {
-                        let min =
-                            *dim_cells.iter().min_by(|l, r| l.bits_cmp(r))?;
-                        let max =
-                            *dim_cells.iter().max_by(|l, r| l.bits_cmp(r))?;
-                        Range::from(&[min, max])
-                    },
-                    {
-                        let min = dim_cells
-                            .iter()
-                            .min_by(|l, r| l.bits_cmp(r))?
-                            .clone()
-                            .into_boxed_slice();
-                        let max = dim_cells
-                            .iter()
-                            .max_by(|l, r| l.bits_cmp(r))?
-                            .clone()
-                            .into_boxed_slice();
+                        let min = dim_cells[min].clone().into_boxed_slice();
+                        let max = dim_cells[max].clone().into_boxed_slice();
                         match cell_val_num {
                             CellValNum::Fixed(_) => {
                                 Range::try_from((*cell_val_num, min, max))
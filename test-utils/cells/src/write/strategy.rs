@@ -6,7 +6,7 @@ use proptest::prelude::*;
 use proptest::strategy::{NewTree, ValueTree};
 use proptest::test_runner::TestRunner;
 
-use tiledb_common::array::{ArrayType, CellOrder, CellValNum};
+use tiledb_common::array::{ArrayType, CellOrder, CellValNum, Mode};
 use tiledb_common::range::{Range, SingleValueRange};
 use tiledb_common::single_value_range_go;
 use tiledb_pod::array::schema::SchemaData;
@@ -533,6 +533,48 @@ impl Arbitrary for WriteInput {
     }
 }
 
+/// Returns a strategy producing query layouts which are valid for
+/// a query of the given `mode` against an array with `schema`.
+///
+/// * Dense writes may use `RowMajor`, `ColumnMajor`, or `Global`.
+/// * Sparse writes may use `Unordered` or `Global`.
+/// * Dense reads may use `RowMajor`, `ColumnMajor`, or `Global`.
+/// * Sparse reads may use any of the above.
+///
+/// `Hilbert` is never produced. It is a valid cell order for a sparse
+/// schema but not a valid query layout; a query can request the
+/// Hilbert order of such a schema by using `Global`.
+///
+/// Other modes do not use the query layout and always produce `RowMajor`,
+/// which is the default query layout.
+pub fn prop_query_layout(
+    schema: &SchemaData,
+    mode: Mode,
+) -> impl Strategy<Value = CellOrder> {
+    let layouts = match (mode, schema.array_type) {
+        (Mode::Write, ArrayType::Dense) | (Mode::Read, ArrayType::Dense) => {
+            vec![
+                CellOrder::RowMajor,
+                CellOrder::ColumnMajor,
+                CellOrder::Global,
+            ]
+        }
+        (Mode::Write, ArrayType::Sparse) => {
+            vec![CellOrder::Unordered, CellOrder::Global]
+        }
+        (Mode::Read, ArrayType::Sparse) => vec![
+            CellOrder::Unordered,
+            CellOrder::RowMajor,
+            CellOrder::ColumnMajor,
+            CellOrder::Global,
+        ],
+        (Mode::Delete, _) | (Mode::Update, _) | (Mode::ModifyExclusive, _) => {
+            vec![CellOrder::RowMajor]
+        }
+    };
+    proptest::sample::select(layouts)
+}
+
 #[derive(Clone, Debug)]
 pub struct WriteSequenceParametersImpl<W> {
     pub write: Rc<W>,
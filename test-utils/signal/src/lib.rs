@@ -74,24 +74,62 @@ pub struct SignalCallback<'a> {
     guard: SignalGuard,
     restore_callback: Option<RawSignalCallback>,
     action: Box<dyn FnMut() + 'a>,
+    chain: bool,
 }
 
 extern "C" fn signal_callback_dispatch(signo: i32) {
     if let Some(handler) =
         SIGNAL_CALLBACKS[signo as usize].lock().unwrap().as_mut()
     {
-        let action = &mut unsafe {
-            &mut *std::mem::transmute::<*mut (), *mut SignalCallback<'static>>(
-                handler.0,
-            )
+        signal_callback_run(handler, signo)
+    }
+}
+
+fn signal_callback_run(handler: &mut RawSignalCallback, signo: i32) {
+    let callback = unsafe {
+        &mut *std::mem::transmute::<*mut (), *mut SignalCallback<'static>>(
+            handler.0,
+        )
+    };
+    (callback.action)();
+
+    if !callback.chain {
+        return;
+    }
+    if let SigHandler::Handler(prev) = callback.guard.restore_handler {
+        if prev as usize == signal_callback_dispatch as *const () as usize {
+            // the previous handler is also a `SignalCallback`, which
+            // is no longer registered for dispatch, so run it directly
+            if let Some(prev) = callback.restore_callback.as_mut() {
+                signal_callback_run(prev, signo)
+            }
+        } else {
+            prev(signo)
         }
-        .action;
-        action();
     }
 }
 
 impl<'a> SignalCallback<'a> {
     pub fn new<F>(signo: Signal, handler: F) -> Pin<Box<Self>>
+    where
+        F: FnMut() + 'a,
+    {
+        Self::install(signo, handler, false)
+    }
+
+    /// Like `new`, but after running `handler` the callback also invokes
+    /// the handler function which was previously installed for `signo`, if any.
+    ///
+    /// This preserves signal handling which was installed outside of Rust,
+    /// such as by libtiledb.
+    pub fn new_chained<F>(signo: Signal, handler: F) -> Pin<Box<Self>>
+    where
+        F: FnMut() + 'a,
+    {
+        Self::install(signo, handler, true)
+    }
+
+    fn install<F>(signo: Signal, handler: F, chain: bool) -> Pin<Box<Self>>
     where
         F: FnMut() + 'a,
     {
@@ -106,6 +144,7 @@ impl<'a> SignalCallback<'a> {
             guard,
             restore_callback: None,
             action: Box::new(handler),
+            chain,
         });
         callback.restore_callback = std::mem::replace(
             &mut SIGNAL_CALLBACKS[signo as i32 as usize].lock().unwrap(),
@@ -251,4 +290,59 @@ mod tests {
         assert_eq!(v1, 2);
         assert_eq!(v2, 2);
     }
+
+    static CHAINED_COUNT: std::sync::atomic::AtomicUsize =
+        std::sync::atomic::AtomicUsize::new(0);
+
+    extern "C" fn chained_counter(_: i32) {
+        CHAINED_COUNT.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+    }
+
+    #[test]
+    fn callback_chained() {
+        use std::sync::atomic::Ordering;
+
+        let mut v1 = 0;
+        let mut v2 = 0;
+
+        // install a non-Rust-callback handler for the test
+        let _counter = SignalGuard::new(
+            Signal::SIGALRM,
+            SigHandler::Handler(chained_counter),
+        );
+
+        nix::sys::signal::raise(Signal::SIGALRM).unwrap();
+        assert_eq!(CHAINED_COUNT.load(Ordering::SeqCst), 1);
+
+        // chained callback shall run both
+        {
+            let _cb1 = SignalCallback::new_chained(Signal::SIGALRM, || v1 += 1);
+            nix::sys::signal::raise(Signal::SIGALRM).unwrap();
+            assert_eq!(CHAINED_COUNT.load(Ordering::SeqCst), 2);
+
+            // nested chained callback shall run all three
+            {
+                let _cb2 =
+                    SignalCallback::new_chained(Signal::SIGALRM, || v2 += 100);
+                nix::sys::signal::raise(Signal::SIGALRM).unwrap();
+                assert_eq!(CHAINED_COUNT.load(Ordering::SeqCst), 3);
+            }
+            assert_eq!(v2, 100);
+
+            // unchained callback shall overwrite
+            {
+                let _cb2 = SignalCallback::new(Signal::SIGALRM, || v2 += 100);
+                nix::sys::signal::raise(Signal::SIGALRM).unwrap();
+                assert_eq!(CHAINED_COUNT.load(Ordering::SeqCst), 3);
+            }
+            assert_eq!(v2, 200);
+        }
+        assert_eq!(v1, 2);
+
+        // then the counter should have been restored
+        nix::sys::signal::raise(Signal::SIGALRM).unwrap();
+        assert_eq!(CHAINED_COUNT.load(Ordering::SeqCst), 4);
+        assert_eq!(v1, 2);
+        assert_eq!(v2, 200);
+    }
 }
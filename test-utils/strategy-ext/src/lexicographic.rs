@@ -0,0 +1,74 @@
+//! Provides strategies for generating lexicographically ordered records.
+
+use std::fmt::Debug;
+
+use proptest::prelude::*;
+
+/// Returns a strategy which produces a `Vec` of `n` values from `element`,
+/// sorted by the key which `key` projects from each value.
+///
+/// If the key is a tuple then the values are sorted lexicographically,
+/// e.g. a list of coordinates can be sorted in row-major order by projecting
+/// each coordinate tuple onto itself.
+///
+/// The sort is applied to every value produced by the strategy,
+/// including while shrinking, so every produced `Vec` is sorted.
+/// The sort is stable, so values with equal keys retain the order
+/// in which they were generated.
+pub fn prop_sorted_tuples<S, K, F>(
+    element: S,
+    n: usize,
+    key: F,
+) -> impl Strategy<Value = Vec<S::Value>>
+where
+    S: Strategy,
+    S::Value: Debug,
+    K: Ord,
+    F: Fn(&S::Value) -> K,
+{
+    proptest::collection::vec(element, n).prop_map(move |mut values| {
+        values.sort_by_key(&key);
+        values
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::strategy::ValueTree;
+    use proptest::test_runner::TestRunner;
+
+    use super::*;
+
+    fn is_sorted<T: Ord>(values: &[T]) -> bool {
+        values.windows(2).all(|w| w[0] <= w[1])
+    }
+
+    proptest! {
+        #[test]
+        fn sorted_tuples(values in prop_sorted_tuples((any::<i8>(), any::<u16>()), 32, |t| *t)) {
+            assert_eq!(32, values.len());
+            assert!(is_sorted(&values));
+        }
+
+        #[test]
+        fn sorted_tuples_projection(values in prop_sorted_tuples((any::<i8>(), any::<u16>()), 32, |t| (t.1, t.0))) {
+            assert!(is_sorted(&values.iter().map(|t| (t.1, t.0)).collect::<Vec<_>>()));
+        }
+    }
+
+    /// Shrinking must produce only sorted values.
+    #[test]
+    fn sorted_tuples_shrink() {
+        let strat =
+            prop_sorted_tuples((any::<i32>(), any::<i32>()), 16, |t| *t);
+        let mut runner = TestRunner::deterministic();
+
+        for _ in 0..16 {
+            let mut tree = strat.new_tree(&mut runner).unwrap();
+            assert!(is_sorted(&tree.current()));
+            while tree.simplify() {
+                assert!(is_sorted(&tree.current()));
+            }
+        }
+    }
+}
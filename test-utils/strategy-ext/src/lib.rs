@@ -1,9 +1,12 @@
+pub mod lexicographic;
 pub mod meta;
 pub mod records;
 pub mod sequence;
 pub mod strategy;
+pub mod without_replacement;
 
 use std::fmt::Debug;
+use std::hash::Hash;
 
 use proptest::strategy::{Strategy, ValueTree};
 
@@ -33,6 +36,20 @@ pub trait StrategyExt: Strategy {
     {
         meta::MapValueTree::new(self, transform)
     }
+
+    /// Returns a strategy which produces values from [self]
+    /// but never produces the same value twice.
+    ///
+    /// See [without_replacement::WithoutReplacement].
+    fn prop_without_replacement(
+        self,
+    ) -> without_replacement::WithoutReplacement<Self>
+    where
+        Self: Sized,
+        Self::Value: Clone + Eq + Hash,
+    {
+        without_replacement::WithoutReplacement::new(self)
+    }
 }
 
 impl<S> StrategyExt for S where S: Strategy {}
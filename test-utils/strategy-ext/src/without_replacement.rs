@@ -0,0 +1,215 @@
+//! Provides a [Strategy] adapter which does not produce the same value twice.
+
+use std::cell::RefCell;
+use std::collections::{HashSet, VecDeque};
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use proptest::strategy::{NewTree, Strategy, ValueTree};
+use proptest::test_runner::TestRunner;
+
+/// Strategy which produces values from another strategy,
+/// rejecting values which it has already produced.
+///
+/// By default every produced value is remembered for the lifetime of the
+/// strategy. This is appropriate for a single test case, but over a large
+/// number of cases the remembered values may use a lot of memory.
+/// [WithoutReplacement::with_capacity] bounds the number of remembered
+/// values, at the cost of a weaker guarantee: once more than `max` values
+/// have been produced, the least recently used values are forgotten and
+/// may be produced again. A value is used when it is produced, and also
+/// when the inner strategy produces it again and it is rejected.
+///
+/// Only the initial value of each [ValueTree] is checked; values produced
+/// while shrinking may repeat previously produced values.
+#[derive(Debug)]
+pub struct WithoutReplacement<S>
+where
+    S: Strategy,
+{
+    inner: S,
+    max: Option<usize>,
+    produced: RefCell<Produced<S::Value>>,
+}
+
+/// Values which have been produced by a [WithoutReplacement] strategy.
+#[derive(Debug)]
+struct Produced<T> {
+    values: HashSet<T>,
+    /// Order in which `values` were last used, least recent first.
+    order: VecDeque<T>,
+}
+
+impl<T> Default for Produced<T> {
+    fn default() -> Self {
+        Produced {
+            values: HashSet::new(),
+            order: VecDeque::new(),
+        }
+    }
+}
+
+impl<S> WithoutReplacement<S>
+where
+    S: Strategy,
+    S::Value: Clone + Eq + Hash,
+{
+    /// Returns a strategy which never produces the same value twice.
+    pub fn new(inner: S) -> Self {
+        WithoutReplacement {
+            inner,
+            max: None,
+            produced: Default::default(),
+        }
+    }
+
+    /// Returns a strategy which remembers at most `max` of the values
+    /// it has produced.
+    ///
+    /// Once `max` is exceeded, the least recently used remembered value
+    /// is forgotten and may be produced again. If `max` is zero then no
+    /// values are remembered and every value of `inner` is accepted.
+    pub fn with_capacity(inner: S, max: usize) -> Self {
+        WithoutReplacement {
+            inner,
+            max: Some(max),
+            produced: Default::default(),
+        }
+    }
+
+    /// Forgets all previously produced values.
+    pub fn clear(&self) {
+        let mut produced = self.produced.borrow_mut();
+        produced.values.clear();
+        produced.order.clear();
+    }
+
+    /// Returns the number of values which are currently remembered.
+    pub fn num_remembered(&self) -> usize {
+        self.produced.borrow().values.len()
+    }
+
+    /// Records `value` as produced, returning `false` if it was already
+    /// remembered.
+    ///
+    /// A remembered value which is produced again by the inner strategy
+    /// becomes the most recently used, so that it is forgotten last.
+    fn remember(&self, value: S::Value) -> bool {
+        if self.max == Some(0) {
+            return true;
+        }
+        let mut produced = self.produced.borrow_mut();
+        if produced.values.contains(&value) {
+            if let Some(pos) = produced.order.iter().position(|v| *v == value) {
+                let used = produced.order.remove(pos).unwrap();
+                produced.order.push_back(used);
+            }
+            return false;
+        }
+        if self.max.is_some_and(|max| produced.order.len() >= max) {
+            if let Some(oldest) = produced.order.pop_front() {
+                produced.values.remove(&oldest);
+            }
+        }
+        produced.values.insert(value.clone());
+        produced.order.push_back(value);
+        true
+    }
+}
+
+impl<S> Strategy for WithoutReplacement<S>
+where
+    S: Strategy,
+    S::Value: Clone + Eq + Hash,
+{
+    type Tree = S::Tree;
+    type Value = S::Value;
+
+    fn new_tree(&self, runner: &mut TestRunner) -> NewTree<Self> {
+        loop {
+            let tree = self.inner.new_tree(runner)?;
+            if self.remember(tree.current()) {
+                return Ok(tree);
+            }
+            runner.reject_local("value was already produced")?;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn unique() {
+        let strat = WithoutReplacement::new(0..16u8);
+        let mut runner = TestRunner::deterministic();
+
+        let mut values = (0..16)
+            .map(|_| strat.new_tree(&mut runner).unwrap().current())
+            .collect::<Vec<_>>();
+        values.sort();
+        assert_eq!((0..16).collect::<Vec<u8>>(), values);
+
+        // the domain is exhausted
+        assert!(strat.new_tree(&mut runner).is_err());
+
+        strat.clear();
+        assert_eq!(0, strat.num_remembered());
+        assert!(strat.new_tree(&mut runner).is_ok());
+    }
+
+    #[test]
+    fn with_capacity() {
+        let strat = WithoutReplacement::with_capacity(0..4u8, 2);
+        let mut runner = TestRunner::deterministic();
+
+        // the domain is never exhausted since old values are forgotten
+        let mut prev = None;
+        for _ in 0..64 {
+            let value = strat.new_tree(&mut runner).unwrap().current();
+            assert_ne!(prev, Some(value));
+            prev = Some(value);
+            assert!(strat.num_remembered() <= 2);
+        }
+    }
+
+    #[test]
+    fn with_capacity_zero() {
+        let strat = WithoutReplacement::with_capacity(Just(1u8), 0);
+        let mut runner = TestRunner::deterministic();
+
+        for _ in 0..4 {
+            assert_eq!(1, strat.new_tree(&mut runner).unwrap().current());
+            assert_eq!(0, strat.num_remembered());
+        }
+    }
+
+    #[test]
+    fn with_capacity_lru() {
+        let strat = WithoutReplacement::with_capacity(Just(0u8), 2);
+
+        assert!(strat.remember(1));
+        assert!(strat.remember(2));
+
+        // using 1 again makes 2 the least recently used
+        assert!(!strat.remember(1));
+        assert!(strat.remember(3));
+        assert!(!strat.remember(1));
+        assert!(strat.remember(2));
+        assert!(!strat.remember(1));
+    }
+
+    proptest! {
+        #[test]
+        fn remember_bounded(max in 1..16usize, values in proptest::collection::vec(any::<u8>(), 0..64)) {
+            let strat = WithoutReplacement::with_capacity(Just(0u8), max);
+            for value in values {
+                strat.remember(value);
+                assert!(strat.num_remembered() <= max);
+            }
+        }
+    }
+}
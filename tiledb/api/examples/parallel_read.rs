@@ -0,0 +1,138 @@
+extern crate tiledb_api as tiledb;
+
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use tiledb::array::{
+    Array, ArrayType, AttributeBuilder, CellOrder, DimensionBuilder,
+    DomainBuilder, SchemaBuilder, TileOrder,
+};
+use tiledb::context::Context;
+use tiledb::query::{ParallelReader, Query, QueryBuilder, WriteBuilder};
+use tiledb::range::Range;
+use tiledb::Datatype;
+use tiledb::Result as TileDBResult;
+
+const ARRAY_URI: &str = "parallel_read";
+
+const NUM_ROWS: i32 = 4096;
+const NUM_COLS: i32 = 1024;
+const NUM_PARTITIONS: usize = 16;
+const NUM_TRIALS: u32 = 5;
+
+/// This example compares reading a dense 4096x1024 array serially, one
+/// partition after another, with reading the same partitions concurrently
+/// using a `ParallelReader`.
+///
+/// The partitions are computed by splitting the rows dimension
+/// on tile boundaries with `Dimension::tile_partition`.
+/// Build with `--release` for meaningful timings.
+fn main() -> TileDBResult<()> {
+    if let Ok(manifest_dir) = std::env::var("CARGO_MANIFEST_DIR") {
+        let _ = std::env::set_current_dir(
+            PathBuf::from(manifest_dir).join("examples").join("output"),
+        );
+    }
+
+    let ctx = Context::new()?;
+    if !Array::exists(&ctx, ARRAY_URI)? {
+        create_array(&ctx)?;
+        write_array(&ctx)?;
+    }
+
+    let array = Array::open(&ctx, ARRAY_URI, tiledb::array::Mode::Read)?;
+    let partitions = array
+        .schema()?
+        .domain()?
+        .dimension("rows")?
+        .tile_partition(NUM_PARTITIONS)?
+        .into_iter()
+        .map(|rows| vec![vec![rows], vec![]])
+        .collect::<Vec<Vec<Vec<Range>>>>();
+
+    let serial = time(|| {
+        let ncells = partitions
+            .iter()
+            .map(|p| Ok(array.read_subarray(&["a"], p.clone())?[0].1.ncells))
+            .collect::<TileDBResult<Vec<usize>>>()?;
+        Ok(ncells.into_iter().sum())
+    })?;
+    println!("Serial:   {:?} per read", serial);
+
+    let reader = ParallelReader::new(&array, &["a"])?;
+    let parallel = time(|| {
+        Ok(reader
+            .read(&partitions)?
+            .into_iter()
+            .map(|p| p[0].1.ncells)
+            .sum())
+    })?;
+    println!("Parallel: {:?} per read", parallel);
+
+    Ok(())
+}
+
+/// Returns the mean duration of `read` over several trials.
+fn time<F>(read: F) -> TileDBResult<Duration>
+where
+    F: Fn() -> TileDBResult<usize>,
+{
+    let start = Instant::now();
+    for _ in 0..NUM_TRIALS {
+        let ncells = read()?;
+        assert_eq!((NUM_ROWS * NUM_COLS) as usize, ncells);
+    }
+    Ok(start.elapsed() / NUM_TRIALS)
+}
+
+fn create_array(ctx: &Context) -> TileDBResult<()> {
+    let schema = {
+        let domain = DomainBuilder::new(ctx)?
+            .add_dimension(
+                DimensionBuilder::new(
+                    ctx,
+                    "rows",
+                    Datatype::Int32,
+                    ([1i32, NUM_ROWS], 64i32),
+                )?
+                .build(),
+            )?
+            .add_dimension(
+                DimensionBuilder::new(
+                    ctx,
+                    "cols",
+                    Datatype::Int32,
+                    ([1i32, NUM_COLS], 64i32),
+                )?
+                .build(),
+            )?
+            .build();
+
+        SchemaBuilder::new(ctx, ArrayType::Dense, domain)?
+            .cell_order(CellOrder::RowMajor)?
+            .tile_order(TileOrder::RowMajor)?
+            .add_attribute(
+                AttributeBuilder::new(ctx, "a", Datatype::Int32)?.build(),
+            )?
+            .build()?
+    };
+
+    Array::create(ctx, ARRAY_URI, schema)?;
+    Ok(())
+}
+
+fn write_array(ctx: &Context) -> TileDBResult<()> {
+    let data = (0..NUM_ROWS * NUM_COLS).collect::<Vec<i32>>();
+
+    let array =
+        tiledb::Array::open(ctx, ARRAY_URI, tiledb::array::Mode::Write)?;
+
+    let query = WriteBuilder::new(array)?
+        .layout(CellOrder::RowMajor)?
+        .data_typed("a", &data)?
+        .build();
+
+    query.submit().and_then(|_| query.finalize())?;
+
+    Ok(())
+}
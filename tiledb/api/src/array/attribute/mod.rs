@@ -780,6 +780,79 @@ mod tests {
         Ok(())
     }
 
+    /// Cells of a dense array which have not been written
+    /// are read back as the attribute fill value.
+    #[test]
+    fn attribute_fill_value_read() -> TileDBResult<()> {
+        use crate::array::{
+            Array, ArrayType, DimensionBuilder, DomainBuilder, Mode,
+            SchemaBuilder,
+        };
+        use crate::query::{
+            Query, QueryBuilder, QueryLayout, ReadBuilder, ReadQuery,
+            ReadQueryBuilder, WriteBuilder,
+        };
+        use uri::TestArrayUri;
+
+        let ctx = Context::new()?;
+        let test_uri = uri::get_uri_generator()
+            .map_err(|e| Error::Other(e.to_string()))?;
+
+        let domain = {
+            let id = DimensionBuilder::new(
+                &ctx,
+                "id",
+                Datatype::Int32,
+                ([1, 8], 4),
+            )?
+            .build();
+            DomainBuilder::new(&ctx)?.add_dimension(id)?.build()
+        };
+        let schema = SchemaBuilder::new(&ctx, ArrayType::Dense, domain)?
+            .add_attribute(
+                Builder::new(&ctx, "a", Datatype::Int32)?
+                    .fill_value(42i32)?
+                    .build(),
+            )?
+            .build()?;
+        let uri = test_uri
+            .with_path("attribute_fill_value_read")
+            .map_err(|e| Error::Other(e.to_string()))?;
+        Array::create(&ctx, &uri, schema)?;
+
+        let read = |range: [i32; 2]| -> TileDBResult<Vec<i32>> {
+            let array = Array::open(&ctx, &uri, Mode::Read)?;
+            let mut q = ReadBuilder::new(array)?
+                .register_constructor::<_, Vec<i32>>("a", Default::default())?
+                .start_subarray()?
+                .add_range(0, &range)?
+                .finish_subarray()?
+                .build();
+            let (a, ()) = q.execute()?;
+            Ok(a)
+        };
+
+        // nothing written, every cell is the fill value
+        assert_eq!(vec![42; 8], read([1, 8])?);
+
+        {
+            let a = vec![1i32, 2, 3, 4];
+            let q = WriteBuilder::new(Array::open(&ctx, &uri, Mode::Write)?)?
+                .layout(QueryLayout::RowMajor)?
+                .start_subarray()?
+                .add_range(0, &[3i32, 6])?
+                .finish_subarray()?
+                .data_typed("a", &a)?
+                .build();
+            q.submit().and_then(|_| q.finalize())?;
+        }
+
+        // cells outside of the write are the fill value
+        assert_eq!(vec![42, 42, 1, 2, 3, 4, 42, 42], read([1, 8])?);
+
+        Ok(())
+    }
+
     #[test]
     fn test_eq() {
         let ctx = Context::new().unwrap();
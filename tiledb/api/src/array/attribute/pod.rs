@@ -59,11 +59,12 @@ impl Factory for AttributeData {
             }
         }
         if let Some(ref fill) = self.fill {
-            b = metadata_value_go!(fill.data, _DT, ref value, {
+            b = metadata_value_go!(fill.data, DT, ref value, {
+                let value = AsRef::<[DT]>::as_ref(value);
                 if let Some(fill_nullability) = fill.nullability {
-                    b.fill_value_nullability(value.as_slice(), fill_nullability)
+                    b.fill_value_nullability(value, fill_nullability)
                 } else {
-                    b.fill_value(value.as_slice())
+                    b.fill_value(value)
                 }
             })?;
         }
@@ -0,0 +1,221 @@
+use std::ops::Deref;
+
+use anyhow::anyhow;
+
+use crate::array::{Array, ArrayOpener, Mode};
+use crate::config::Config;
+use crate::context::{CApiInterface, Context, ContextBound};
+use crate::error::Error;
+use crate::Result as TileDBResult;
+
+pub(crate) enum RawConsolidationPlan {
+    Owned(*mut ffi::tiledb_consolidation_plan_t),
+}
+
+impl Deref for RawConsolidationPlan {
+    type Target = *mut ffi::tiledb_consolidation_plan_t;
+    fn deref(&self) -> &Self::Target {
+        let RawConsolidationPlan::Owned(ref ffi) = *self;
+        ffi
+    }
+}
+
+impl Drop for RawConsolidationPlan {
+    fn drop(&mut self) {
+        let RawConsolidationPlan::Owned(ref mut ffi) = *self;
+        unsafe {
+            ffi::tiledb_consolidation_plan_free(ffi);
+        }
+    }
+}
+
+/// Describes which fragments of an array would be merged by consolidation.
+///
+/// The plan is a list of nodes, each of which is a group of fragments
+/// which would be consolidated together into a single fragment.
+/// The fragments of a node can be consolidated by passing their names to
+/// [Array::consolidate_fragments], which allows the plan to be inspected
+/// and then applied selectively.
+pub struct ConsolidationPlan {
+    context: Context,
+    raw: RawConsolidationPlan,
+}
+
+impl ContextBound for ConsolidationPlan {
+    fn context(&self) -> Context {
+        self.context.clone()
+    }
+}
+
+impl ConsolidationPlan {
+    pub(crate) fn capi(&self) -> *mut ffi::tiledb_consolidation_plan_t {
+        *self.raw
+    }
+
+    /// Computes a plan to consolidate the fragments of the array at `uri`
+    /// into fragments of approximately `fragment_size` bytes.
+    ///
+    /// The array is opened for reading using `config` if provided,
+    /// so the plan is computed over the fragments visible at the
+    /// timestamps which `config` selects.
+    pub fn create<S>(
+        context: &Context,
+        uri: S,
+        fragment_size: u64,
+        config: Option<&Config>,
+    ) -> TileDBResult<Self>
+    where
+        S: AsRef<str>,
+    {
+        let array = {
+            let opener = ArrayOpener::new(context, uri, Mode::Read)?;
+            if let Some(config) = config {
+                opener.config(config)?.open()?
+            } else {
+                opener.open()?
+            }
+        };
+        Self::for_array(&array, fragment_size)
+    }
+
+    /// Computes a plan to consolidate the fragments of the open `array`
+    /// into fragments of approximately `fragment_size` bytes.
+    pub fn for_array(array: &Array, fragment_size: u64) -> TileDBResult<Self> {
+        let c_array = **array.capi();
+        let mut c_plan: *mut ffi::tiledb_consolidation_plan_t = out_ptr!();
+        array.capi_call(|ctx| unsafe {
+            ffi::tiledb_consolidation_plan_create_with_mbr(
+                ctx,
+                c_array,
+                fragment_size,
+                &mut c_plan,
+            )
+        })?;
+
+        Ok(ConsolidationPlan {
+            context: array.context(),
+            raw: RawConsolidationPlan::Owned(c_plan),
+        })
+    }
+
+    /// Returns the number of nodes in the plan.
+    pub fn num_nodes(&self) -> TileDBResult<u64> {
+        let c_plan = self.capi();
+        let mut num_nodes: u64 = 0;
+        self.capi_call(|ctx| unsafe {
+            ffi::tiledb_consolidation_plan_get_num_nodes(
+                ctx,
+                c_plan,
+                &mut num_nodes,
+            )
+        })?;
+        Ok(num_nodes)
+    }
+
+    /// Returns the number of fragments which node `node` would consolidate.
+    pub fn num_fragments(&self, node: u64) -> TileDBResult<u64> {
+        let c_plan = self.capi();
+        let mut num_fragments: u64 = 0;
+        self.capi_call(|ctx| unsafe {
+            ffi::tiledb_consolidation_plan_get_num_fragments(
+                ctx,
+                c_plan,
+                node,
+                &mut num_fragments,
+            )
+        })?;
+        Ok(num_fragments)
+    }
+
+    /// Returns the URIs of the fragments which node `node` would consolidate.
+    pub fn fragment_uris(&self, node: u64) -> TileDBResult<Vec<String>> {
+        let c_plan = self.capi();
+        (0..self.num_fragments(node)?)
+            .map(|f| {
+                let mut c_uri: *const std::os::raw::c_char = out_ptr!();
+                self.capi_call(|ctx| unsafe {
+                    ffi::tiledb_consolidation_plan_get_fragment_uri(
+                        ctx, c_plan, node, f, &mut c_uri,
+                    )
+                })?;
+                let uri = unsafe { std::ffi::CStr::from_ptr(c_uri) };
+                uri.to_str()
+                    .map(|s| s.to_owned())
+                    .map_err(|e| Error::InvalidArgument(anyhow!(e)))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::array::tests::create_quickstart_sparse_string;
+    use crate::query::{Query, QueryBuilder, WriteBuilder};
+
+    /// Writes one single-cell fragment for each of `cols`.
+    fn write_fragments(
+        ctx: &Context,
+        uri: &str,
+        cols: &[i32],
+    ) -> TileDBResult<()> {
+        for c in cols.iter() {
+            let rows = vec!["foo".to_owned()];
+            let cols = vec![*c];
+            let atts = vec![*c * 10];
+            let q = WriteBuilder::new(Array::open(ctx, uri, Mode::Write)?)?
+                .data("rows", &rows)?
+                .data("cols", &cols)?
+                .data("a", &atts)?
+                .build();
+            q.submit()?;
+            q.finalize()?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn consolidation_plan() -> TileDBResult<()> {
+        let ctx = Context::new()?;
+        let test_uri = uri::get_uri_generator()
+            .map_err(|e| Error::Other(e.to_string()))?;
+        let uri = create_quickstart_sparse_string(&test_uri, &ctx)?;
+
+        // no fragments, nothing to consolidate
+        let plan = ConsolidationPlan::create(&ctx, &uri, 1024 * 1024, None)?;
+        assert_eq!(0, plan.num_nodes()?);
+
+        write_fragments(&ctx, &uri, &[1, 2, 3, 4])?;
+        let mut names = Array::open(&ctx, &uri, Mode::Read)?
+            .fragment_info()?
+            .iter()?
+            .map(|f| f.name())
+            .collect::<TileDBResult<Vec<String>>>()?;
+        names.sort();
+        assert_eq!(4, names.len());
+
+        // the fragments are small, so they can all go into one fragment
+        let plan = ConsolidationPlan::create(&ctx, &uri, 1024 * 1024, None)?;
+        assert_eq!(1, plan.num_nodes()?);
+        let mut node_names = plan
+            .fragment_uris(0)?
+            .into_iter()
+            .map(|u| {
+                u.trim_end_matches('/')
+                    .rsplit('/')
+                    .next()
+                    .unwrap()
+                    .to_owned()
+            })
+            .collect::<Vec<String>>();
+        node_names.sort();
+        assert_eq!(names, node_names);
+
+        // apply the plan
+        Array::consolidate_fragments(&ctx, uri.clone(), &names, None)?;
+        Array::vacuum(&ctx, &uri, None)?;
+        assert_eq!(1, Array::open(&ctx, &uri, Mode::Read)?.fragment_count()?);
+
+        Ok(())
+    }
+}
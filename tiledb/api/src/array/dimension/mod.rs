@@ -3,10 +3,14 @@ use std::ops::Deref;
 #[cfg(any(test, feature = "pod"))]
 use std::fmt::{Debug, Formatter, Result as FmtResult};
 
+use anyhow::anyhow;
+
 use crate::array::CellValNum;
 use crate::context::{CApiInterface, Context, ContextBound};
 use crate::datatype::PhysicalType;
+use crate::error::Error;
 use crate::filter::list::{FilterList, RawFilterList};
+use crate::range::Range;
 use crate::{physical_type_go, Datatype, Result as TileDBResult};
 
 pub use tiledb_common::array::dimension::DimensionConstraints;
@@ -127,6 +131,41 @@ impl Dimension {
         }
     }
 
+    /// Returns the domain and tile extent of this dimension.
+    pub fn constraints(&self) -> TileDBResult<DimensionConstraints> {
+        Ok(physical_type_go!(self.datatype()?, DT, {
+            let domain = self.domain::<DT>()?;
+            let extent = self.extent::<DT>()?;
+            if let Some(domain) = domain {
+                DimensionConstraints::from((domain, extent))
+            } else {
+                assert!(extent.is_none());
+                DimensionConstraints::StringAscii
+            }
+        }))
+    }
+
+    /// Splits the domain of this dimension into at most `n` contiguous,
+    /// non-overlapping ranges aligned to the tile extent.
+    /// See [DimensionConstraints::tile_partition].
+    ///
+    /// Each range can be used as the subarray of a separate query
+    /// to scan the array in parallel.
+    ///
+    /// Returns `Err` if this is a string dimension, which has no domain
+    /// to partition, or if `n` is zero.
+    pub fn tile_partition(&self, n: usize) -> TileDBResult<Vec<Range>> {
+        let ranges =
+            self.constraints()?.tile_partition(n).ok_or_else(|| {
+                Error::InvalidArgument(anyhow!(
+                    "Cannot partition dimension '{}' into {} ranges",
+                    self.name().unwrap_or_default(),
+                    n
+                ))
+            })?;
+        Ok(ranges.into_iter().map(Range::Single).collect())
+    }
+
     pub fn filters(&self) -> TileDBResult<FilterList> {
         let mut c_fl: *mut ffi::tiledb_filter_list_t = out_ptr!();
 
@@ -357,6 +396,58 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_dimension_tile_partition() -> TileDBResult<()> {
+        let context = Context::new()?;
+
+        let dim =
+            Builder::new(&context, "d", Datatype::Int32, ([1i32, 100], 10i32))?
+                .build();
+        let ranges = dim.tile_partition(3)?;
+        assert_eq!(
+            vec![
+                Range::from(&[1i32, 30]),
+                Range::from(&[31i32, 60]),
+                Range::from(&[61i32, 100])
+            ],
+            ranges
+        );
+
+        let dim = Builder::new(
+            &context,
+            "d",
+            Datatype::Float64,
+            ([0f64, 1.0], 0.25f64),
+        )?
+        .build();
+        let ranges = dim.tile_partition(2)?;
+        assert_eq!(2, ranges.len());
+        let (start, end) = ranges[0].as_typed::<f64>().unwrap();
+        assert_eq!(0.0, start);
+        assert!(end < 0.5 && end > 0.49);
+        assert_eq!(Some((0.5, 1.0)), ranges[1].as_typed::<f64>());
+
+        let dim = Builder::new(
+            &context,
+            "d",
+            Datatype::StringAscii,
+            DimensionConstraints::StringAscii,
+        )?
+        .build();
+        assert!(matches!(
+            dim.tile_partition(2),
+            Err(Error::InvalidArgument(_))
+        ));
+        assert!(matches!(
+            Builder::new(&context, "d", Datatype::Int32, ([1i32, 4], 1i32))?
+                .build()
+                .tile_partition(0),
+            Err(Error::InvalidArgument(_))
+        ));
+
+        Ok(())
+    }
+
     #[test]
     fn test_dimension_cell_val_num() {
         let context = Context::new().unwrap();
@@ -1,6 +1,4 @@
-use tiledb_common::array::dimension::DimensionConstraints;
 use tiledb_common::filter::FilterData;
-use tiledb_common::physical_type_go;
 use tiledb_pod::array::dimension::DimensionData;
 
 use super::{Builder, Dimension};
@@ -11,22 +9,10 @@ impl TryFrom<&Dimension> for DimensionData {
     type Error = TileDBError;
 
     fn try_from(dim: &Dimension) -> Result<Self, Self::Error> {
-        let datatype = dim.datatype()?;
-        let constraints = physical_type_go!(datatype, DT, {
-            let domain = dim.domain::<DT>()?;
-            let extent = dim.extent::<DT>()?;
-            if let Some(domain) = domain {
-                DimensionConstraints::from((domain, extent))
-            } else {
-                assert!(extent.is_none());
-                DimensionConstraints::StringAscii
-            }
-        });
-
         Ok(DimensionData {
             name: dim.name()?,
-            datatype,
-            constraints,
+            datatype: dim.datatype()?,
+            constraints: dim.constraints()?,
             filters: {
                 let fl = Vec::<FilterData>::try_from(&dim.filters()?)?;
                 if fl.is_empty() {
@@ -6,6 +6,8 @@ use std::fmt::{Debug, Formatter, Result as FmtResult};
 use tiledb_common::array::CellValNum;
 
 use crate::context::{CApiInterface, Context, ContextBound};
+use crate::error::DatatypeError;
+use crate::query::condition::Literal;
 use crate::string::{RawTDBString, TDBString};
 use crate::{Datatype, Result as TileDBResult};
 
@@ -149,11 +151,80 @@ impl Enumeration {
         Ok(Some(unsafe { std::slice::from_raw_parts(ptr, elems) }))
     }
 
+    /// Returns the raw bytes of the variant at `index`,
+    /// or `None` if `index` is out of bounds.
+    fn variant_bytes(&self, index: usize) -> TileDBResult<Option<&[u8]>> {
+        let data = self.data()?;
+        if let Some(offsets) = self.offsets()? {
+            let Some(start) = offsets.get(index) else {
+                return Ok(None);
+            };
+            let end =
+                offsets.get(index + 1).copied().unwrap_or(data.len() as u64);
+            Ok(data.get(*start as usize..end as usize))
+        } else {
+            let fixed = self.datatype()?.size()
+                * u32::from(self.cell_val_num()?) as usize;
+            Ok(index
+                .checked_mul(fixed)
+                .and_then(|start| data.get(start..start.checked_add(fixed)?)))
+        }
+    }
+
+    /// Returns the value of the variant at `index`, or `None` if `index`
+    /// is out of bounds or if the variant cannot be represented as a
+    /// [Literal]. See [Literal::from_bytes].
+    pub fn variant_at(&self, index: u64) -> TileDBResult<Option<Literal>> {
+        let Ok(index) = usize::try_from(index) else {
+            return Ok(None);
+        };
+        let Some(bytes) = self.variant_bytes(index)? else {
+            return Ok(None);
+        };
+        Ok(Literal::from_bytes(
+            self.datatype()?,
+            self.cell_val_num()?,
+            bytes,
+        ))
+    }
+
+    /// Returns the index of the variant whose value is `value`,
+    /// or `None` if no variant matches.
+    ///
+    /// This is the key which a cell of an attribute using this enumeration
+    /// contains to represent `value`.
+    pub fn index_of(&self, value: &Literal) -> TileDBResult<Option<u64>> {
+        let value = value.to_bytes();
+        let mut index = 0;
+        while let Some(variant) = self.variant_bytes(index)? {
+            if variant == value.as_slice() {
+                return Ok(Some(index as u64));
+            }
+            index += 1;
+        }
+        Ok(None)
+    }
+
+    /// Returns a new enumeration which contains the variants of `self`
+    /// followed by the new variants in `data` and `offsets`.
+    ///
+    /// Returns an error if `T` is not compatible with the datatype of `self`.
     pub fn extend<T>(
         &self,
         data: &[T],
         offsets: Option<&[u64]>,
-    ) -> TileDBResult<ExtendedEnumeration> {
+    ) -> TileDBResult<ExtendedEnumeration>
+    where
+        T: 'static,
+    {
+        let datatype = self.datatype()?;
+        if !datatype.is_compatible_type::<T>() {
+            return Err(DatatypeError::physical_type_incompatible::<T>(
+                datatype,
+            )
+            .into());
+        }
+
         let c_enmr = self.capi();
         let mut c_new_enmr: *mut ffi::tiledb_enumeration_t = out_ptr!();
 
@@ -451,6 +522,41 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn variant_lookup() -> TileDBResult<()> {
+        let ctx = Context::new().expect("Error creating context instance.");
+
+        let data = &vec![1, 2, 3][..];
+        let enmr = Builder::new(&ctx, "foo", Datatype::Int32, data, None)
+            .build()
+            .expect("Error building enumeration.");
+        assert_eq!(Some(Literal::Int32(2)), enmr.variant_at(1)?);
+        assert_eq!(None, enmr.variant_at(3)?);
+        assert_eq!(None, enmr.variant_at(u64::MAX)?);
+        assert_eq!(Some(2), enmr.index_of(&Literal::Int32(3))?);
+        assert_eq!(None, enmr.index_of(&Literal::Int32(4))?);
+
+        let data = "fredwilmageorgebetty".as_bytes();
+        let offsets = &vec![0u64, 4, 9, 15][..];
+        let enmr = Builder::new(
+            &ctx,
+            "flintstones",
+            Datatype::StringUtf8,
+            data,
+            Some(offsets),
+        )
+        .var_sized()
+        .build()
+        .expect("Error building enumeration.");
+        assert_eq!(Some(Literal::from("wilma")), enmr.variant_at(1)?);
+        assert_eq!(Some(Literal::from("betty")), enmr.variant_at(3)?);
+        assert_eq!(None, enmr.variant_at(4)?);
+        assert_eq!(Some(2), enmr.index_of(&Literal::from("george"))?);
+        assert_eq!(None, enmr.index_of(&Literal::from("geo"))?);
+
+        Ok(())
+    }
+
     #[test]
     fn extend_enumeration() -> TileDBResult<()> {
         let ctx = Context::new().expect("Error creating context instance.");
@@ -469,6 +575,11 @@ mod tests {
         assert_eq!(enmr1.ordered()?, enmr2.ordered()?);
         assert_ne!(enmr1, enmr2.into_inner());
 
+        assert!(matches!(
+            enmr1.extend(&[6u64][..], None),
+            Err(crate::error::Error::Datatype(_))
+        ));
+
         Ok(())
     }
 
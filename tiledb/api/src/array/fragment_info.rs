@@ -580,6 +580,18 @@ impl FragmentInfo<'_> {
     pub fn to_vacuum_uri(&self) -> TileDBResult<String> {
         self.info.to_vacuum_uri(self.index)
     }
+
+    /// Returns whether this fragment was produced by consolidating
+    /// other fragments.
+    ///
+    /// A fragment which is written by a query has a single timestamp,
+    /// whereas a consolidated fragment spans the timestamps of the fragments
+    /// which were consolidated into it. As such this cannot detect the
+    /// consolidation of fragments which were all written at the same timestamp.
+    pub fn is_consolidated(&self) -> TileDBResult<bool> {
+        let [start, end] = self.timestamp_range()?;
+        Ok(start < end)
+    }
 }
 
 pub struct FragmentInfoList {
@@ -609,6 +621,14 @@ impl FragmentInfoList {
         self.info.num_to_vacuum()
     }
 
+    /// Returns the URIs of the fragments which have been consolidated
+    /// into other fragments and which will be removed by vacuuming.
+    pub fn to_vacuum(&self) -> TileDBResult<Vec<String>> {
+        (0..self.num_to_vacuum()?)
+            .map(|v| self.info.to_vacuum_uri(v))
+            .collect()
+    }
+
     pub fn total_cell_count(&self) -> TileDBResult<u64> {
         self.info.total_cell_count()
     }
@@ -933,6 +953,56 @@ pub mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_to_vacuum() -> TileDBResult<()> {
+        let ctx = Context::new().unwrap();
+        let test_uri = uri::get_uri_generator()
+            .map_err(|e| Error::Other(e.to_string()))?;
+        let array_uri = create_dense_array(&ctx, &test_uri)?;
+
+        // write another fragment at a later timestamp, so that the
+        // consolidated fragment spans more than one timestamp
+        let latest = Builder::new(&ctx, &array_uri)?
+            .build()?
+            .iter()?
+            .map(|f| f.timestamp_range().map(|[_, end]| end))
+            .collect::<TileDBResult<Vec<u64>>>()?
+            .into_iter()
+            .max()
+            .unwrap();
+        write_dense_array_at(&ctx, &array_uri, latest + 1)?;
+
+        let frag_infos = Builder::new(&ctx, &array_uri)?.build()?;
+        assert_eq!(3, frag_infos.num_fragments()?);
+        assert!(frag_infos.to_vacuum()?.is_empty());
+        for frag in frag_infos.iter()? {
+            assert!(!frag.is_consolidated()?);
+        }
+        let mut uris = frag_infos
+            .iter()?
+            .map(|f| f.uri())
+            .collect::<TileDBResult<Vec<String>>>()?;
+        uris.sort();
+
+        // consolidate without vacuuming
+        Array::consolidate(&ctx, &array_uri, None)?;
+
+        let frag_infos = Builder::new(&ctx, &array_uri)?.build()?;
+        assert_eq!(1, frag_infos.num_fragments()?);
+        assert!(frag_infos.get_fragment(0)?.is_consolidated()?);
+        let mut to_vacuum = frag_infos.to_vacuum()?;
+        to_vacuum.sort();
+        assert_eq!(uris, to_vacuum);
+
+        Array::vacuum(&ctx, &array_uri, None)?;
+
+        let frag_infos = Builder::new(&ctx, &array_uri)?.build()?;
+        assert!(frag_infos.to_vacuum()?.is_empty());
+        assert!(frag_infos.get_fragment(0)?.is_consolidated()?);
+
+        Ok(())
+    }
+
     /// Create a simple dense test array with a couple fragments to inspect.
     pub fn create_dense_array(
         ctx: &Context,
@@ -978,6 +1048,22 @@ pub mod tests {
         Ok(())
     }
 
+    /// Write another fragment to the test array at `timestamp`.
+    fn write_dense_array_at(
+        ctx: &Context,
+        array_uri: &str,
+        timestamp: u64,
+    ) -> TileDBResult<()> {
+        let data = vec![1u64, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+        let array = ArrayOpener::new(ctx, array_uri, Mode::Write)?
+            .end_timestamp(timestamp)?
+            .open()?;
+        let query =
+            WriteBuilder::new(array)?.data_typed("attr", &data)?.build();
+        query.submit()?;
+        Ok(())
+    }
+
     /// Create a simple sparse test array with a couple fragments to inspect.
     pub fn create_sparse_array(
         ctx: &Context,
@@ -15,7 +15,7 @@ use crate::datatype::PhysicalType;
 use crate::error::{DatatypeError, Error};
 use crate::key::LookupKey;
 use crate::metadata;
-use crate::metadata::Metadata;
+use crate::metadata::{Metadata, MetadataValue};
 use crate::range::{
     Range, SingleValueRange, TypedNonEmptyDomain, TypedRange, VarValueRange,
 };
@@ -23,15 +23,18 @@ use crate::Result as TileDBResult;
 use crate::{physical_type_go, Datatype};
 
 pub mod attribute;
+pub mod consolidation_plan;
 pub mod dimension;
 pub mod domain;
 pub mod enumeration;
 pub mod fragment_info;
+pub(crate) mod read_all;
 pub mod schema;
 
-use crate::config::Config;
+use crate::config::{CommonOption, Config};
 
 pub use attribute::{Attribute, Builder as AttributeBuilder};
+pub use consolidation_plan::ConsolidationPlan;
 pub use dimension::{
     Builder as DimensionBuilder, Dimension, DimensionConstraints,
 };
@@ -133,6 +136,9 @@ impl TryFrom<ffi::tiledb_encryption_type_t> for Encryption {
     }
 }
 
+/// Length in bytes of an AES-256-GCM encryption key.
+pub const AES_256_GCM_KEY_LEN: usize = 32;
+
 pub enum RawArray {
     Owned(*mut ffi::tiledb_array_t),
 }
@@ -230,6 +236,37 @@ impl Array {
         ArrayOpener::new(context, uri, mode)?.open()
     }
 
+    /// Opens the array located at `uri` for queries of type `mode`,
+    /// using `key` to decrypt an array which is encrypted with AES-256-GCM.
+    ///
+    /// This is a shorthand for opening the array with an [ArrayOpener]
+    /// whose configuration sets [CommonOption::Aes256GcmEncryptionKey].
+    /// Returns `Error::InvalidArgument` if `key` is not
+    /// [AES_256_GCM_KEY_LEN] bytes long.
+    pub fn open_encrypted<S>(
+        context: &Context,
+        uri: S,
+        mode: Mode,
+        key: &[u8],
+    ) -> TileDBResult<Self>
+    where
+        S: AsRef<str>,
+    {
+        if key.len() != AES_256_GCM_KEY_LEN {
+            return Err(Error::InvalidArgument(anyhow!(
+                "AES-256-GCM encryption key must be {} bytes, found {}",
+                AES_256_GCM_KEY_LEN,
+                key.len()
+            )));
+        }
+        let config = Config::new()?.with_common_option(
+            &CommonOption::Aes256GcmEncryptionKey(key.to_vec()),
+        )?;
+        ArrayOpener::new(context, uri, mode)?
+            .config(&config)?
+            .open()
+    }
+
     /// Prepares an array to be "re-opened". Re-opening the array will bring in any changes
     /// which occured since it was initially opened. This also allows changing configurations
     /// of an open array, such as the timestamp range.
@@ -240,11 +277,65 @@ impl Array {
         }
     }
 
+    /// Re-opens the array with a new timestamp range.
+    /// A bound which is `None` retains its current value.
+    ///
+    /// This is a shorthand for `reopen` which is convenient for
+    /// repeatedly querying the array at different points in time.
+    /// Returns an error if the array is not open for reading.
+    pub fn reopen_at(
+        self,
+        start: Option<u64>,
+        end: Option<u64>,
+    ) -> TileDBResult<Array> {
+        let mut opener = self.reopen();
+        if let Some(start) = start {
+            opener = opener.start_timestamp(start)?;
+        }
+        if let Some(end) = end {
+            opener = opener.end_timestamp(end)?;
+        }
+        opener.open()
+    }
+
     /// Returns the URI that this array is located at
     pub fn uri(&self) -> &str {
         self.uri.as_ref()
     }
 
+    /// Returns whether this array is open.
+    pub fn is_open(&self) -> TileDBResult<bool> {
+        let c_array = *self.raw;
+        let mut c_is_open: i32 = out_ptr!();
+        self.capi_call(|ctx| unsafe {
+            ffi::tiledb_array_is_open(ctx, c_array, &mut c_is_open)
+        })?;
+        Ok(c_is_open == 1)
+    }
+
+    /// Returns the mode which this array is open in.
+    pub fn query_type(&self) -> TileDBResult<Mode> {
+        let c_array = *self.raw;
+        let mut c_query_type: ffi::tiledb_query_type_t = out_ptr!();
+        self.capi_call(|ctx| unsafe {
+            ffi::tiledb_array_get_query_type(ctx, c_array, &mut c_query_type)
+        })?;
+        Ok(Mode::try_from(c_query_type)?)
+    }
+
+    /// Returns the start and end timestamps at which this array is open.
+    pub fn timestamp_range(&self) -> TileDBResult<(u64, u64)> {
+        let c_array = *self.raw;
+        let (mut start, mut end) = (0u64, 0u64);
+        self.capi_call(|ctx| unsafe {
+            ffi::tiledb_array_get_open_timestamp_start(ctx, c_array, &mut start)
+        })?;
+        self.capi_call(|ctx| unsafe {
+            ffi::tiledb_array_get_open_timestamp_end(ctx, c_array, &mut end)
+        })?;
+        Ok((start, end))
+    }
+
     pub fn schema(&self) -> TileDBResult<Schema> {
         let c_array = *self.raw;
         let mut c_schema: *mut ffi::tiledb_array_schema_t = out_ptr!();
@@ -264,6 +355,21 @@ impl Array {
         FragmentInfoBuilder::new(&self.context, self.uri())?.build()
     }
 
+    /// Returns the number of fragments in this array.
+    pub fn fragment_count(&self) -> TileDBResult<usize> {
+        Ok(self.fragment_info()?.num_fragments()? as usize)
+    }
+
+    /// Returns the largest end timestamp of any fragment in this array,
+    /// or `None` if the array has no fragments.
+    pub fn latest_fragment_timestamp(&self) -> TileDBResult<Option<u64>> {
+        let fragments = self.fragment_info()?;
+        (0..fragments.num_fragments()?).try_fold(None, |latest, f| {
+            let [_, end] = fragments.get_fragment(f)?.timestamp_range()?;
+            Ok(Some(latest.map_or(end, |latest: u64| latest.max(end))))
+        })
+    }
+
     pub fn put_metadata(&mut self, metadata: Metadata) -> TileDBResult<()> {
         let c_array = *self.raw;
         let (vec_size, vec_ptr, datatype) =
@@ -282,6 +388,18 @@ impl Array {
         Ok(())
     }
 
+    /// Writes each of the metadata `items` to the array.
+    ///
+    /// Stops at the first item which cannot be written.
+    pub fn put_metadata_all<I>(&mut self, items: I) -> TileDBResult<()>
+    where
+        I: IntoIterator<Item = Metadata>,
+    {
+        items
+            .into_iter()
+            .try_for_each(|metadata| self.put_metadata(metadata))
+    }
+
     pub fn delete_metadata<S>(&mut self, name: S) -> TileDBResult<()>
     where
         S: AsRef<str>,
@@ -347,11 +465,31 @@ impl Array {
             }
         }?;
         let datatype = Datatype::try_from(c_datatype)?;
-        Ok(metadata::metadata_from_ffi(
-            name,
-            datatype,
-            (vec_size, vec_ptr),
-        ))
+        metadata::metadata_from_ffi(name, datatype, (vec_size, vec_ptr))
+    }
+
+    /// Returns all of the metadata items of the array, in index order.
+    pub fn metadata_all(&self) -> TileDBResult<Vec<Metadata>> {
+        (0..self.num_metadata()?)
+            .map(|i| self.metadata(LookupKey::Index(i as usize)))
+            .collect()
+    }
+
+    /// Returns the values of the metadata item for `name` as `T`,
+    /// or `None` if there is no metadata item for `name`.
+    ///
+    /// Returns `Err` if the metadata datatype is not compatible with `T`.
+    pub fn metadata_value<T, S>(&self, name: S) -> TileDBResult<Option<Vec<T>>>
+    where
+        T: MetadataValue,
+        S: AsRef<str>,
+    {
+        if self.has_metadata_key(name.as_ref())?.is_none() {
+            return Ok(None);
+        }
+        let metadata =
+            self.metadata(LookupKey::Name(name.as_ref().to_owned()))?;
+        T::from_metadata(metadata).map(Some)
     }
 
     pub fn has_metadata_key<S>(&self, name: S) -> TileDBResult<Option<Datatype>>
@@ -406,6 +544,51 @@ impl Array {
         ))
     }
 
+    /// Loads the data of every enumeration of the array into its schema.
+    ///
+    /// Enumerations are otherwise loaded lazily, so a schema returned by
+    /// [Array::schema] may not contain the enumeration data needed
+    /// to fully describe it.
+    pub fn load_all_enumerations(&self) -> TileDBResult<()> {
+        let c_array = *self.raw;
+        self.capi_call(|ctx| unsafe {
+            ffi::tiledb_array_load_all_enumerations(ctx, c_array)
+        })?;
+        Ok(())
+    }
+
+    /// Loads the schema of the array at `uri` into an offline
+    /// [tiledb_pod::array::schema::SchemaData].
+    ///
+    /// The schema is read without opening the array unless it has
+    /// enumerations, in which case the array is opened only for as long as
+    /// it takes to load their data.
+    #[cfg(any(test, feature = "pod"))]
+    pub fn load_schema_offline<S>(
+        ctx: &Context,
+        uri: S,
+    ) -> TileDBResult<tiledb_pod::array::schema::SchemaData>
+    where
+        S: AsRef<str>,
+    {
+        use tiledb_pod::array::schema::SchemaData;
+
+        let schema = Schema::load(ctx, uri.as_ref())?;
+        let has_enumerations = schema
+            .attributes()?
+            .map(|a| a.and_then(|a| a.enumeration_name()))
+            .collect::<TileDBResult<Vec<Option<String>>>>()?
+            .iter()
+            .any(|e| e.is_some());
+        if !has_enumerations {
+            return SchemaData::try_from(schema);
+        }
+
+        let array = Array::open(ctx, uri, Mode::Read)?;
+        array.load_all_enumerations()?;
+        SchemaData::try_from(array.schema()?)
+    }
+
     /// Cleans up the array, such as consolidated fragments and array metadata.
     pub fn vacuum<S>(
         ctx: &Context,
@@ -831,19 +1014,16 @@ impl Array {
 
 impl Drop for Array {
     fn drop(&mut self) {
-        let c_array = *self.raw;
-        let mut c_is_open: i32 = out_ptr!();
-
-        self.capi_call(|ctx| unsafe {
-            ffi::tiledb_array_is_open(ctx, c_array, &mut c_is_open)
-        })
-        .expect("TileDB internal error when closing array");
+        let is_open = self
+            .is_open()
+            .expect("TileDB internal error when closing array");
 
         // the array will not be open if the user constructs Opener and drops it without calling
         // `Opener::open`.  This bit of al dente buccatini is mitigated by the fact that the
         // user still never sees a non-open Array object. Maybe worth refactoring at some point
         // nonetheless.
-        if c_is_open == 1 {
+        if is_open {
+            let c_array = *self.raw;
             self.capi_call(|ctx| unsafe {
                 ffi::tiledb_array_close(ctx, c_array)
             })
@@ -944,6 +1124,7 @@ pub mod tests {
     use tiledb_common::array::dimension::DimensionConstraints;
     use tiledb_common::datatype::Datatype;
     use tiledb_common::metadata::Value;
+    use tiledb_pod::array::attribute::AttributeData;
     use tiledb_pod::array::enumeration::EnumerationData;
     use tiledb_pod::array::schema::SchemaData;
     use uri::{self, TestArrayUri};
@@ -953,8 +1134,10 @@ pub mod tests {
     use super::*;
     use crate::config::CommonOption;
     use crate::query::{
-        Query, QueryBuilder, QueryLayout, QueryType, WriteBuilder,
+        Query, QueryBuilder, QueryLayout, QueryType, ReadBuilder, ReadQuery,
+        ReadQueryBuilder, WriteBuilder,
     };
+    use crate::tests::examples::quickstart;
     use crate::Factory;
 
     /// Create the array used in the "quickstart_dense" example
@@ -1043,6 +1226,55 @@ pub mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_array_open_state() -> TileDBResult<()> {
+        let test_uri = uri::get_uri_generator()
+            .map_err(|e| Error::Other(e.to_string()))?;
+
+        let ctx = Context::new()?;
+        let uri = create_quickstart_dense(&test_uri, &ctx)?;
+
+        let array = Array::open(&ctx, &uri, Mode::Read)?;
+        assert!(array.is_open()?);
+        assert_eq!(Mode::Read, array.query_type()?);
+
+        let array = ArrayOpener::new(&ctx, &uri, Mode::Write)?
+            .start_timestamp(5)?
+            .end_timestamp(10)?
+            .open()?;
+        assert!(array.is_open()?);
+        assert_eq!(Mode::Write, array.query_type()?);
+        assert_eq!((5, 10), array.timestamp_range()?);
+
+        let array = ArrayOpener::new(&ctx, &uri, Mode::Read)?
+            .start_timestamp(5)?
+            .end_timestamp(10)?
+            .open()?;
+        assert_eq!((5, 10), array.timestamp_range()?);
+
+        let array = array.reopen_at(None, Some(20))?;
+        assert!(array.is_open()?);
+        assert_eq!(Mode::Read, array.query_type()?);
+        assert_eq!((5, 20), array.timestamp_range()?);
+
+        Ok(())
+    }
+
+    /// Only arrays which are open for reading can be re-opened
+    #[test]
+    fn test_array_reopen_write() -> TileDBResult<()> {
+        let test_uri = uri::get_uri_generator()
+            .map_err(|e| Error::Other(e.to_string()))?;
+
+        let ctx = Context::new()?;
+        let uri = create_quickstart_dense(&test_uri, &ctx)?;
+
+        let array = Array::open(&ctx, &uri, Mode::Write)?;
+        assert!(array.reopen_at(None, Some(20)).is_err());
+
+        Ok(())
+    }
+
     #[test]
     fn proptest_array_create() {
         let ctx = Context::new().expect("Error creating context");
@@ -1132,6 +1364,128 @@ pub mod tests {
         test_uri.close().map_err(|e| Error::Other(e.to_string()))
     }
 
+    #[test]
+    fn test_array_metadata_value() -> TileDBResult<()> {
+        let test_uri = uri::get_uri_generator()
+            .map_err(|e| Error::Other(e.to_string()))?;
+
+        let tdb = Context::new()?;
+        let uri = create_quickstart_dense(&test_uri, &tdb)?;
+
+        {
+            let mut array = Array::open(&tdb, &uri, QueryType::Write)?;
+
+            array.put_metadata(Metadata::new(
+                "aaa".to_owned(),
+                Datatype::Int32,
+                vec![5],
+            )?)?;
+            array.put_metadata(Metadata::new(
+                "bb".to_owned(),
+                Datatype::Float32,
+                vec![1.1f32, 2.2f32],
+            )?)?;
+            array.put_metadata(Metadata::new(
+                "ccc".to_owned(),
+                Datatype::StringUtf8,
+                "foo".as_bytes().to_vec(),
+            )?)?;
+            array.put_metadata(Metadata::new(
+                "dd".to_owned(),
+                Datatype::StringAscii,
+                "bar".as_bytes().to_vec(),
+            )?)?;
+        }
+
+        {
+            let array = Array::open(&tdb, &uri, QueryType::Read)?;
+
+            assert_eq!(Some(vec![5]), array.metadata_value::<i32, _>("aaa")?);
+            assert_eq!(
+                Some(vec![1.1f32, 2.2f32]),
+                array.metadata_value::<f32, _>("bb")?
+            );
+            assert_eq!(
+                Some(vec!["foo".to_owned()]),
+                array.metadata_value::<String, _>("ccc")?
+            );
+            let metadata_ccc =
+                array.metadata(LookupKey::Name("ccc".to_owned()))?;
+            assert_eq!(metadata_ccc.datatype, Datatype::StringUtf8);
+            assert_eq!(metadata_ccc.value, Value::StringUtf8("foo".to_owned()));
+            assert_eq!(
+                Some(vec!["bar".to_owned()]),
+                array.metadata_value::<String, _>("dd")?
+            );
+
+            // string metadata can also be read as bytes
+            assert_eq!(
+                Some("foo".as_bytes().to_vec()),
+                array.metadata_value::<u8, _>("ccc")?
+            );
+            assert_eq!(
+                Some("bar".as_bytes().to_vec()),
+                array.metadata_value::<u8, _>("dd")?
+            );
+            assert_eq!(None, array.metadata_value::<i32, _>("zzz")?);
+            assert!(matches!(
+                array.metadata_value::<f64, _>("aaa"),
+                Err(Error::Datatype(_))
+            ));
+        }
+
+        test_uri.close().map_err(|e| Error::Other(e.to_string()))
+    }
+
+    #[test]
+    fn test_array_metadata_all() -> TileDBResult<()> {
+        let test_uri = uri::get_uri_generator()
+            .map_err(|e| Error::Other(e.to_string()))?;
+
+        let tdb = Context::new()?;
+        let uri = create_quickstart_dense(&test_uri, &tdb)?;
+
+        let mut items = vec![
+            Metadata::new("u8".to_owned(), Datatype::UInt8, vec![1u8, 2])?,
+            Metadata::new("u16".to_owned(), Datatype::UInt16, vec![3u16])?,
+            Metadata::new("u32".to_owned(), Datatype::UInt32, vec![4u32])?,
+            Metadata::new("u64".to_owned(), Datatype::UInt64, vec![5u64])?,
+            Metadata::new("i8".to_owned(), Datatype::Int8, vec![-6i8])?,
+            Metadata::new("i16".to_owned(), Datatype::Int16, vec![-7i16])?,
+            Metadata::new("i32".to_owned(), Datatype::Int32, vec![8, 9, 10])?,
+            Metadata::new("i64".to_owned(), Datatype::Int64, vec![-11i64])?,
+            Metadata::new("f32".to_owned(), Datatype::Float32, vec![1.5f32])?,
+            Metadata::new("f64".to_owned(), Datatype::Float64, vec![2.5f64])?,
+            Metadata::new(
+                "date".to_owned(),
+                Datatype::DateTimeDay,
+                vec![19000i64],
+            )?,
+            Metadata::new(
+                "string".to_owned(),
+                Datatype::StringUtf8,
+                "hello metadata".as_bytes().to_vec(),
+            )?,
+        ];
+
+        {
+            let mut array = Array::open(&tdb, &uri, QueryType::Write)?;
+            array.put_metadata_all(items.clone())?;
+        }
+
+        {
+            let array = Array::open(&tdb, &uri, QueryType::Read)?;
+            let mut metadata = array.metadata_all()?;
+            assert_eq!(items.len(), metadata.len());
+
+            items.sort_by(|l, r| l.key.cmp(&r.key));
+            metadata.sort_by(|l, r| l.key.cmp(&r.key));
+            assert_eq!(items, metadata);
+        }
+
+        test_uri.close().map_err(|e| Error::Other(e.to_string()))
+    }
+
     #[test]
     fn test_mode_metadata() -> TileDBResult<()> {
         let test_uri = uri::get_uri_generator()
@@ -1274,6 +1628,28 @@ pub mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_array_fragment_summary() -> TileDBResult<()> {
+        let ctx: Context = Context::new().unwrap();
+        let array_uri = uri::get_uri_generator().unwrap();
+        let array_uri = create_simple_dense(&array_uri, &ctx)?;
+        {
+            let array = Array::open(&ctx, &array_uri, Mode::Read)?;
+            assert_eq!(0, array.fragment_count()?);
+            assert_eq!(None, array.latest_fragment_timestamp()?);
+        }
+
+        write_dense_vector_4_fragments(&ctx, &array_uri, 0)?;
+        {
+            let array = Array::open(&ctx, &array_uri, Mode::Read)?;
+            assert_eq!(4, array.fragment_count()?);
+            // fragment `i` is written at timestamp `i + 1`
+            assert_eq!(Some(4), array.latest_fragment_timestamp()?);
+        }
+
+        Ok(())
+    }
+
     #[test]
     fn test_array_consolidation() -> TileDBResult<()> {
         // Test advanced consolidation. Based on unit-capi-consolidation.cc.
@@ -1320,6 +1696,73 @@ pub mod tests {
         Ok(())
     }
 
+    #[test]
+    fn fragment_info_non_empty_domain() -> TileDBResult<()> {
+        let ctx: Context = Context::new().unwrap();
+        let array_uri = uri::get_uri_generator().unwrap();
+        let array_uri = create_simple_dense(&array_uri, &ctx)?;
+        write_dense_vector_4_fragments(&ctx, &array_uri, 0).unwrap();
+
+        let fragments = FragmentInfoBuilder::new(&ctx, array_uri)?.build()?;
+        assert_eq!(4, fragments.num_fragments()?);
+
+        let expect = [[1, 200], [201, 250], [251, 310], [311, 410]];
+        for (i, (frag, expect)) in
+            fragments.iter()?.zip(expect.iter()).enumerate()
+        {
+            let domain = frag.non_empty_domain()?;
+            assert_eq!(1, domain.len());
+            assert_eq!(Datatype::Int32, domain[0].datatype);
+            assert_eq!(Range::from(expect), domain[0].range);
+
+            let timestamp = i as u64 + 1;
+            assert_eq!([timestamp, timestamp], frag.timestamp_range()?);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn reopen_at() -> TileDBResult<()> {
+        let ctx: Context = Context::new().unwrap();
+        let array_uri = uri::get_uri_generator().unwrap();
+        let array_uri = create_simple_dense(&array_uri, &ctx)?;
+        write_dense_vector_4_fragments(&ctx, &array_uri, 0).unwrap();
+
+        let read_range = |array: Array, range: [i32; 2]| {
+            let mut q = ReadBuilder::new(array)?
+                .register_constructor::<_, Vec<i32>>("a", Default::default())?
+                .start_subarray()?
+                .add_range(0, &range)?
+                .finish_subarray()?
+                .build();
+            let (a, ()) = q.execute()?;
+            Ok((q.finalize()?, a)) as TileDBResult<(Array, Vec<i32>)>
+        };
+
+        let array = Array::open(&ctx, &array_uri, Mode::Read)?;
+
+        // only the first fragment is visible
+        let array = array.reopen_at(None, Some(1))?;
+        let (array, a) = read_range(array, [201, 250])?;
+        assert!(a.iter().all(|v| *v == i32::MIN));
+
+        // the first two fragments are visible
+        let array = array.reopen_at(None, Some(2))?;
+        let (array, a) = read_range(array, [201, 250])?;
+        assert_eq!((200..250).collect::<Vec<i32>>(), a);
+
+        // only the second fragment is visible, the end is unchanged
+        let array = array.reopen_at(Some(2), None)?;
+        let (array, a) = read_range(array, [1, 200])?;
+        assert!(a.iter().all(|v| *v == i32::MIN));
+        let (_, a) = read_range(array, [201, 310])?;
+        assert_eq!((200..250).collect::<Vec<i32>>(), a[0..50]);
+        assert!(a[50..].iter().all(|v| *v == i32::MIN));
+
+        Ok(())
+    }
+
     #[test]
     fn delete() -> TileDBResult<()> {
         let test_uri = uri::get_uri_generator()
@@ -1429,6 +1872,110 @@ pub mod tests {
         Ok(())
     }
 
+    /// Test that `SchemaData` captures the enumerations of a schema read
+    /// from storage so that the schema can be recreated elsewhere
+    #[test]
+    fn schema_data_enumerations() -> TileDBResult<()> {
+        let test_uri = uri::get_uri_generator()
+            .map_err(|e| Error::Other(e.to_string()))?;
+
+        let ctx = Context::new()?;
+
+        let schema_in = SchemaData {
+            enumerations: vec![EnumerationData {
+                name: "flintstones".to_owned(),
+                datatype: Datatype::StringUtf8,
+                cell_val_num: Some(CellValNum::Var),
+                ordered: Some(false),
+                data: "fredwilmageorgebetty".as_bytes().into(),
+                offsets: Some(vec![0u64, 4, 9, 15].into_boxed_slice()),
+            }],
+            attributes: vec![AttributeData {
+                name: "a".to_owned(),
+                datatype: Datatype::Int32,
+                enumeration: Some("flintstones".to_owned()),
+                ..Default::default()
+            }],
+            ..quickstart::Builder::new(ArrayType::Sparse).build()
+        };
+
+        let uri = test_uri
+            .with_path("schema_data_enumerations")
+            .map_err(|e| Error::Other(e.to_string()))?;
+        Array::create(&ctx, &uri, schema_in.create(&ctx)?)?;
+
+        {
+            let array = Array::open(&ctx, &uri, Mode::Read)?;
+            let err = SchemaData::try_from(array.schema()?).unwrap_err();
+            assert!(matches!(err, Error::InvalidArgument(_)));
+            assert!(err.to_string().contains("'flintstones' is not loaded"));
+        }
+
+        let schema_out = {
+            let array = Array::open(&ctx, &uri, Mode::Read)?;
+            array.load_all_enumerations()?;
+            SchemaData::try_from(array.schema()?)?
+        };
+        assert_option_subset!(schema_in, schema_out);
+
+        let uri_copy = test_uri
+            .with_path("schema_data_enumerations_copy")
+            .map_err(|e| Error::Other(e.to_string()))?;
+        Array::create(&ctx, &uri_copy, schema_out.create(&ctx)?)?;
+
+        let schema_copy = {
+            let array = Array::open(&ctx, &uri_copy, Mode::Read)?;
+            array.load_all_enumerations()?;
+            SchemaData::try_from(array.schema()?)?
+        };
+        assert_option_subset!(schema_in, schema_copy);
+        assert_eq!(schema_out, schema_copy);
+
+        Ok(())
+    }
+
+    #[test]
+    fn load_schema_offline() -> TileDBResult<()> {
+        let test_uri = uri::get_uri_generator()
+            .map_err(|e| Error::Other(e.to_string()))?;
+
+        let ctx = Context::new()?;
+
+        let plain = quickstart::Builder::new(ArrayType::Dense).build();
+        let with_enumeration = SchemaData {
+            enumerations: vec![EnumerationData {
+                name: "sizes".to_owned(),
+                datatype: Datatype::StringAscii,
+                cell_val_num: Some(CellValNum::Var),
+                ordered: Some(true),
+                data: "smallmediumlarge".as_bytes().into(),
+                offsets: Some(vec![0u64, 5, 11].into_boxed_slice()),
+            }],
+            attributes: vec![AttributeData {
+                name: "size".to_owned(),
+                datatype: Datatype::UInt8,
+                enumeration: Some("sizes".to_owned()),
+                ..Default::default()
+            }],
+            ..quickstart::Builder::new(ArrayType::Sparse).build()
+        };
+
+        for (name, schema_in) in [
+            ("load_schema_offline_plain", plain),
+            ("load_schema_offline_enumeration", with_enumeration),
+        ] {
+            let uri = test_uri
+                .with_path(name)
+                .map_err(|e| Error::Other(e.to_string()))?;
+            Array::create(&ctx, &uri, schema_in.create(&ctx)?)?;
+
+            let schema_out = Array::load_schema_offline(&ctx, &uri)?;
+            assert_option_subset!(schema_in, schema_out);
+        }
+
+        Ok(())
+    }
+
     #[test]
     fn encryption_type_str() {
         assert_eq!(
@@ -1518,4 +2065,53 @@ pub mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn encrypted_array_open_encrypted() -> TileDBResult<()> {
+        let test_uri = uri::get_uri_generator()
+            .map_err(|e| Error::Other(e.to_string()))?;
+
+        let key = "0123456789abcdeF0123456789abcdeF";
+        let key_config =
+            CommonOption::Aes256GcmEncryptionKey(key.as_bytes().to_vec());
+
+        let uri = {
+            let context = {
+                let mut config = Config::new()?;
+                config.set_common_option(&key_config)?;
+
+                Context::from_config(&config)
+            }?;
+            create_quickstart_dense(&test_uri, &context)?
+        };
+
+        let context = Context::new()?;
+
+        // the convenience method should open with just the key
+        let _ =
+            Array::open_encrypted(&context, &uri, Mode::Read, key.as_bytes())?;
+        let _ =
+            Array::open_encrypted(&context, &uri, Mode::Write, key.as_bytes())?;
+
+        // the wrong key of the right length should fail in libtiledb
+        let wrong_key = "0123456789abcdeF0123456789abcdeX";
+        let open_wrong = Array::open_encrypted(
+            &context,
+            &uri,
+            Mode::Read,
+            wrong_key.as_bytes(),
+        );
+        assert!(matches!(open_wrong, Err(Error::LibTileDB(_))));
+
+        // the wrong length should fail before reaching libtiledb
+        let open_short = Array::open_encrypted(
+            &context,
+            &uri,
+            Mode::Read,
+            &key.as_bytes()[1..],
+        );
+        assert!(matches!(open_short, Err(Error::InvalidArgument(_))));
+
+        Ok(())
+    }
 }
@@ -0,0 +1,421 @@
+use crate::array::schema::Field;
+use crate::array::{Array, ArrayOpener, Mode};
+use crate::context::{CApiError, Context, ContextBound};
+use crate::error::Error;
+use crate::query::buffer::{Buffer, CellStructure, QueryBuffers};
+use crate::query::read::output::{RawReadOutput, TypedRawReadOutput};
+use crate::query::read::{
+    managed_read_handles, output_order_handles, ReadCallbackVarArg,
+};
+use crate::query::{
+    Query, QueryBuilder, ReadBuilder, ReadQuery, ReadQueryBuilder,
+};
+use crate::range::Range;
+use crate::typed_query_buffers_go;
+use crate::Result as TileDBResult;
+
+/// Returns whether `message` is the error message which libtiledb
+/// reports when the checksum of some data does not match.
+fn is_checksum_mismatch(message: &str) -> bool {
+    message.to_lowercase().contains("checksum mismatch")
+}
+
+impl Array {
+    /// Opens another handle to this array for reading,
+    /// at the same timestamps as `self`.
+    pub(crate) fn reopen_for_read(&self) -> TileDBResult<Array> {
+        let (start, end) = self.timestamp_range()?;
+        ArrayOpener::new(&self.context(), self.uri(), Mode::Read)?
+            .start_timestamp(start)?
+            .end_timestamp(end)?
+            .open()
+    }
+
+    /// Reads every cell of the array at `uri` with checksum validation
+    /// enabled, and returns whether all of the checksums matched.
+    ///
+    /// Only data which is written using a filter list containing a checksum
+    /// filter (see [crate::filter::FilterListBuilder::add_checksum])
+    /// can be validated. Data without a checksum is read but not validated.
+    /// Returns `Ok(false)` if a checksum does not match the stored data,
+    /// and `Err` if the array could not be read for any other reason.
+    ///
+    /// This loads the entire array into memory in the manner of
+    /// [Array::read_all]. The checksum filters require TileDB 2.0 or later.
+    ///
+    /// libtiledb does not report a distinct error code for a failed
+    /// checksum, so a mismatch is recognized by the text of the error
+    /// message, which contains "checksum mismatch" in any case.
+    pub fn verify_integrity<S>(ctx: &Context, uri: S) -> TileDBResult<bool>
+    where
+        S: AsRef<str>,
+    {
+        let mut config = ctx.get_config()?;
+        config.set("sm.skip_checksum_validation", "false")?;
+        let ctx = Context::from_config(&config)?;
+
+        let result = Array::open(&ctx, uri, Mode::Read)
+            .and_then(|array| array.read_all(&[]));
+        match result {
+            Ok(_) => Ok(true),
+            Err(Error::LibTileDB(CApiError::Error(message)))
+                if is_checksum_mismatch(&message) =>
+            {
+                Ok(false)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Reads the values of `fields` from every cell of the array
+    /// into memory, or of all fields if `fields` is empty.
+    ///
+    /// The returned outputs are in the same order as the requested fields.
+    /// The results of each step of the query are concatenated,
+    /// so the returned buffers contain the values of all cells
+    /// within the non-empty domain of the array.
+    ///
+    /// This loads the entire array into memory and is intended
+    /// for small arrays and tests. The array is re-opened for reading
+    /// at the same timestamps as `self`.
+    pub fn read_all(
+        &self,
+        fields: &[&str],
+    ) -> TileDBResult<Vec<(String, TypedRawReadOutput<'static>)>> {
+        self.read_all_within(fields, None)
+    }
+
+    /// Reads the values of `fields` from every cell of the array
+    /// within `ranges` into memory, as if by [Array::read_all].
+    ///
+    /// Each element of `ranges` is the list of ranges to read
+    /// for the dimension at the same index. If the list for a dimension
+    /// is empty then all of the coordinates of that dimension are read.
+    pub fn read_subarray(
+        &self,
+        fields: &[&str],
+        ranges: Vec<Vec<Range>>,
+    ) -> TileDBResult<Vec<(String, TypedRawReadOutput<'static>)>> {
+        self.read_all_within(fields, Some(ranges))
+    }
+
+    fn read_all_within(
+        &self,
+        fields: &[&str],
+        ranges: Option<Vec<Vec<Range>>>,
+    ) -> TileDBResult<Vec<(String, TypedRawReadOutput<'static>)>> {
+        let schema = self.schema()?;
+        let fields = if fields.is_empty() {
+            schema.fields()?.collect::<TileDBResult<Vec<Field>>>()?
+        } else {
+            schema.fields_by_name(fields)?
+        };
+        let field_names = fields
+            .iter()
+            .map(|f| f.name())
+            .collect::<TileDBResult<Vec<String>>>()?;
+
+        let b = ReadBuilder::new(self.reopen_for_read()?)?;
+        let b = if let Some(ranges) = ranges {
+            b.start_subarray()?
+                .dimension_ranges(ranges)?
+                .finish_subarray()?
+        } else {
+            b.with_full_domain()?
+        };
+        let handles = managed_read_handles(b.base(), &fields)?;
+        let mut query = b
+            .register_callback_var(
+                handles,
+                ReadAllCallback::new(field_names.len()),
+            )?
+            .build();
+
+        let (outputs, _) = query.execute()?;
+        query.finalize()?;
+
+        Ok(field_names.into_iter().zip(outputs).collect())
+    }
+
+    /// Reads the values of `fields` from every cell of the array into memory
+    /// as if by [Array::read_all], and then sorts the cells by the values
+    /// of the dimensions `order`.
+    ///
+    /// Cells are ordered lexicographically by the dimensions in `order`,
+    /// and cells which are equal on those dimensions retain the order
+    /// in which they were read. This is useful to obtain cells in a
+    /// specific dimension order from arrays whose cell order is not,
+    /// such as arrays with `CellOrder::Hilbert`.
+    ///
+    /// Sorting requires every cell to be read into memory before
+    /// any can be returned, and then copied once more in sorted order.
+    ///
+    /// Returns `Error::InvalidArgument` if any key in `order`
+    /// is not a dimension of the array.
+    pub fn read_all_ordered(
+        &self,
+        fields: &[&str],
+        order: &[&str],
+    ) -> TileDBResult<Vec<(String, TypedRawReadOutput<'static>)>> {
+        let schema = self.schema()?;
+
+        // the order keys must be read even if they are not requested
+        let read_fields = if fields.is_empty() {
+            schema.fields()?.collect::<TileDBResult<Vec<Field>>>()?
+        } else {
+            let names = fields
+                .iter()
+                .chain(order.iter().filter(|key| !fields.contains(key)))
+                .copied()
+                .collect::<Vec<&str>>();
+            schema.fields_by_name(&names)?
+        };
+        let keys = order.iter().map(|k| k.to_string()).collect::<Vec<_>>();
+
+        let b =
+            ReadBuilder::new(self.reopen_for_read()?)?.with_full_domain()?;
+        let (handles, callback) =
+            output_order_handles(b.base(), &read_fields, &keys)?;
+        let mut query = b.register_callback_var(handles, callback)?.build();
+
+        let (outputs, _) = query.execute()?;
+        query.finalize()?;
+
+        Ok(outputs
+            .into_iter()
+            .take(if fields.is_empty() {
+                usize::MAX
+            } else {
+                fields.len()
+            })
+            .collect())
+    }
+}
+
+/// Accumulates the results of each step of a read query into owned buffers.
+pub(crate) struct ReadAllCallback {
+    outputs: Vec<Option<TypedRawReadOutput<'static>>>,
+}
+
+impl ReadAllCallback {
+    pub fn new(nfields: usize) -> Self {
+        ReadAllCallback {
+            outputs: (0..nfields).map(|_| None).collect(),
+        }
+    }
+}
+
+impl ReadCallbackVarArg for ReadAllCallback {
+    type Intermediate = ();
+    type Final = Vec<TypedRawReadOutput<'static>>;
+    type Error = crate::error::Error;
+
+    fn intermediate_result(
+        &mut self,
+        args: Vec<TypedRawReadOutput>,
+    ) -> Result<Self::Intermediate, Self::Error> {
+        for (output, batch) in self.outputs.iter_mut().zip(args.iter()) {
+            output
+                .get_or_insert_with(|| empty_like(batch))
+                .append(batch)?;
+        }
+        Ok(())
+    }
+
+    fn final_result(
+        mut self,
+        args: Vec<TypedRawReadOutput>,
+    ) -> Result<Self::Final, Self::Error> {
+        self.intermediate_result(args)?;
+        Ok(self
+            .outputs
+            .into_iter()
+            .map(|output| output.unwrap())
+            .collect())
+    }
+
+    fn cleared(&self) -> Option<Self> {
+        Some(Self::new(self.outputs.len()))
+    }
+}
+
+/// Returns an empty output with the same datatype and structure as `batch`.
+fn empty_like(batch: &TypedRawReadOutput) -> TypedRawReadOutput<'static> {
+    typed_query_buffers_go!(batch.buffers, DT, ref batch_buffers, {
+        let empty = RawReadOutput::<DT> {
+            ncells: 0,
+            input: QueryBuffers {
+                data: Buffer::Empty,
+                cell_structure: match batch_buffers.cell_structure {
+                    CellStructure::Fixed(nz) => CellStructure::Fixed(nz),
+                    CellStructure::Var(_) => CellStructure::Var(Buffer::Empty),
+                },
+                validity: batch_buffers
+                    .validity
+                    .as_ref()
+                    .map(|_| Buffer::Empty),
+            },
+        };
+        TypedRawReadOutput::new(batch.datatype, empty)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use super::*;
+    use crate::array::tests::create_quickstart_sparse_string;
+    use crate::query::buffer::TypedQueryBuffers;
+    use crate::query::read::output::{
+        CellStructureSingleIterator, VarDataIterator,
+    };
+    use crate::query::WriteBuilder;
+    use crate::tests::examples::TestArray;
+    use crate::tests::prelude::ArrayType;
+    use crate::Context;
+
+    #[test]
+    fn read_all_sparse() -> TileDBResult<()> {
+        let ctx = Context::new()?;
+        let test_uri = uri::get_uri_generator()
+            .map_err(|e| Error::Other(e.to_string()))?;
+        let array_uri = create_quickstart_sparse_string(&test_uri, &ctx)?;
+
+        let rows = vec!["foo", "bar", "baz"]
+            .into_iter()
+            .map(|s| s.to_string())
+            .collect::<Vec<String>>();
+        let cols = vec![1i32, 2, 3];
+        let atts = vec![10i32, 20, 30];
+        {
+            let array = Array::open(&ctx, &array_uri, Mode::Write)?;
+            let q = WriteBuilder::new(array)?
+                .data("rows", &rows)?
+                .data("cols", &cols)?
+                .data("a", &atts)?
+                .build();
+            q.submit()?;
+            q.finalize()?;
+        }
+
+        let array = Array::open(&ctx, &array_uri, Mode::Read)?;
+        let outputs = array.read_all(&[])?;
+        assert_eq!(
+            vec!["rows", "cols", "a"],
+            outputs.iter().map(|(f, _)| f.as_str()).collect::<Vec<_>>()
+        );
+
+        let TypedQueryBuffers::UInt8(ref rows_out) = outputs[0].1.buffers
+        else {
+            unreachable!()
+        };
+        let rows_out = VarDataIterator::try_from(RawReadOutput {
+            ncells: outputs[0].1.ncells,
+            input: rows_out.borrow(),
+        })?
+        .map(|s| String::from_utf8(s.to_vec()).unwrap())
+        .collect::<Vec<String>>();
+
+        let TypedQueryBuffers::Int32(ref atts_out) = outputs[2].1.buffers
+        else {
+            unreachable!()
+        };
+        let atts_out = CellStructureSingleIterator::try_from(RawReadOutput {
+            ncells: outputs[2].1.ncells,
+            input: atts_out.borrow(),
+        })?
+        .collect::<Vec<i32>>();
+
+        let mut cells = rows_out.into_iter().zip(atts_out).collect::<Vec<_>>();
+        cells.sort();
+        assert_eq!(
+            vec![
+                ("bar".to_owned(), 20),
+                ("baz".to_owned(), 30),
+                ("foo".to_owned(), 10)
+            ],
+            cells
+        );
+
+        let outputs = array.read_all(&["a"])?;
+        assert_eq!(1, outputs.len());
+        assert_eq!(3, outputs[0].1.ncells);
+
+        Ok(())
+    }
+
+    #[test]
+    fn verify_integrity() -> TileDBResult<()> {
+        use std::io::{Read, Write};
+
+        use crate::config::Config;
+        use crate::filter::{ChecksumType, FilterData};
+        use crate::vfs::{VFSMode, WalkOrder, VFS};
+
+        let schema = {
+            let mut b = crate::tests::examples::quickstart::Builder::new(
+                ArrayType::Sparse,
+            );
+            b.attribute().filters =
+                vec![FilterData::Checksum(ChecksumType::Sha256)];
+            Rc::new(b.build())
+        };
+        let mut array = TestArray::new("verify_integrity", schema)?;
+        let rows = vec![1i32, 2, 3, 4];
+        let cols = vec![4i32, 3, 2, 1];
+        let atts = vec![10i32, 20, 30, 40];
+        {
+            let q = WriteBuilder::new(array.for_write()?)?
+                .data("rows", &rows)?
+                .data("cols", &cols)?
+                .data("a", &atts)?
+                .build();
+            q.submit()?;
+            q.finalize()?;
+        }
+
+        let ctx = array.context.clone();
+        assert!(Array::verify_integrity(&ctx, &array.uri)?);
+
+        // corrupt the last byte of the attribute data
+        let vfs = VFS::new(&ctx, &Config::new()?)?;
+        let attribute_file = vfs
+            .walk(&array.uri, WalkOrder::Preorder)?
+            .into_iter()
+            .find(|u| u.ends_with("a0.tdb"))
+            .expect("Attribute data file not found");
+        let mut contents = vec![];
+        vfs.open_file(&attribute_file, VFSMode::Read)?
+            .read_to_end(&mut contents)
+            .map_err(|e| Error::Other(e.to_string()))?;
+        *contents.last_mut().unwrap() ^= 0xFF;
+        {
+            let mut f = vfs.open_file(&attribute_file, VFSMode::Write)?;
+            f.write_all(&contents)
+                .map_err(|e| Error::Other(e.to_string()))?;
+            f.close()?;
+        }
+
+        assert!(!Array::verify_integrity(&ctx, &array.uri)?);
+
+        // the mismatch is recognized only by the error message text
+        {
+            let mut config = ctx.get_config()?;
+            config.set("sm.skip_checksum_validation", "false")?;
+            let ctx = Context::from_config(&config)?;
+            let err = Array::open(&ctx, &array.uri, Mode::Read)?
+                .read_all(&[])
+                .unwrap_err();
+            let Error::LibTileDB(CApiError::Error(message)) = err else {
+                unreachable!("Expected libtiledb error, found {:?}", err)
+            };
+            assert!(is_checksum_mismatch(&message), "{}", message);
+        }
+        assert!(is_checksum_mismatch("Checksum mismatch; data corrupt"));
+        assert!(is_checksum_mismatch("SHA256 checksum mismatch"));
+        assert!(!is_checksum_mismatch("Cannot open array; does not exist"));
+
+        Ok(())
+    }
+}
@@ -178,5 +178,8 @@ impl Builder {
     }
 }
 
+#[cfg(any(test, feature = "pod"))]
+mod pod;
+
 #[cfg(test)]
 mod tests;
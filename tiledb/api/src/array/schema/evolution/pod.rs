@@ -0,0 +1,41 @@
+use anyhow::anyhow;
+use tiledb_pod::array::schema::{SchemaDiff, SchemaEvolutionOp};
+
+use super::Builder;
+use crate::error::Error;
+use crate::{Context, ContextBound, Factory, Result as TileDBResult};
+
+impl Builder {
+    /// Registers a single schema evolution operation.
+    pub fn operation(self, op: &SchemaEvolutionOp) -> TileDBResult<Self> {
+        match op {
+            SchemaEvolutionOp::AddAttribute(a) => {
+                let attribute = a.create(&self.context())?;
+                self.add_attribute(attribute)
+            }
+            SchemaEvolutionOp::DropAttribute(name) => self.drop_attribute(name),
+            SchemaEvolutionOp::AddEnumeration(e) => {
+                let enumeration = e.create(&self.context())?;
+                self.add_enumeration(enumeration)
+            }
+            SchemaEvolutionOp::DropEnumeration(name) => {
+                self.drop_enumeration(name)
+            }
+        }
+    }
+
+    /// Returns a builder which registers the operations needed to apply `diff`.
+    ///
+    /// Returns `Error::InvalidArgument` if `diff` contains a change
+    /// which schema evolution does not support.
+    pub fn from_diff(
+        context: &Context,
+        diff: &SchemaDiff,
+    ) -> TileDBResult<Self> {
+        let ops = diff
+            .evolution()
+            .map_err(|e| Error::InvalidArgument(anyhow!(e)))?;
+        ops.iter()
+            .try_fold(Self::new(context)?, |b, op| b.operation(op))
+    }
+}
@@ -2,12 +2,15 @@ use super::*;
 
 use tiledb_common::array::{ArrayType, CellValNum};
 use tiledb_common::Datatype;
-use tiledb_pod::array::{AttributeData, EnumerationData};
+use tiledb_pod::array::{AttributeData, EnumerationData, SchemaData};
 use tiledb_utils::assert_option_subset;
 
 use crate::array::schema::EnumerationKey;
 use crate::array::Array;
 use crate::error::Error;
+use crate::query::{
+    Query, QueryBuilder, ReadBuilder, ReadQuery, ReadQueryBuilder, WriteBuilder,
+};
 use crate::tests::examples::{quickstart, TestArray};
 use crate::Factory;
 
@@ -214,6 +217,69 @@ fn extend_enumeration() -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Test that a cell can use a variant added by extending an enumeration
+#[test]
+fn extend_enumeration_flintstones() -> anyhow::Result<()> {
+    let ename = "flintstones";
+
+    let mut flintstones = EnumerationData {
+        name: ename.to_owned(),
+        datatype: Datatype::StringUtf8,
+        cell_val_num: Some(CellValNum::Var),
+        ordered: None,
+        data: "fredwilmageorgebetty".as_bytes().into(),
+        offsets: Some(vec![0u64, 4, 9, 15].into_boxed_slice()),
+    };
+
+    let mut array = TestArray::new("extend_enumeration_flintstones", {
+        let mut b = quickstart::Builder::new(ArrayType::Sparse);
+        b.schema.attributes[0].enumeration = Some(ename.to_owned());
+        b.schema.enumerations.push(flintstones.clone());
+        b.build().into()
+    })?;
+
+    let extended = array
+        .for_read()?
+        .schema()?
+        .enumeration(EnumerationKey::EnumerationName(ename))?
+        .extend("barney".as_bytes(), Some(&[0]))?;
+    flintstones.extend("barney".as_bytes(), Some(&[0]))?;
+    assert_eq!(flintstones, EnumerationData::try_from(&*extended)?);
+
+    let evolution = Builder::new(&array.context)?
+        .extend_enumeration(extended)?
+        .build();
+    Array::evolve(&array.context, &array.uri, evolution)?;
+
+    // write a cell using the new variant
+    {
+        let (rows, cols, a) = (vec![1i32], vec![1i32], vec![4i32]);
+        let w = WriteBuilder::new(array.for_write()?)?
+            .data("rows", &rows)?
+            .data("cols", &cols)?
+            .data("a", &a)?
+            .build();
+        w.submit()?;
+        w.finalize()?;
+    }
+
+    let mut q = ReadBuilder::new(array.for_read()?)?
+        .register_constructor::<_, Vec<i32>>("a", Default::default())?
+        .build();
+    let (a, ()) = q.execute()?;
+    assert_eq!(vec![4], a);
+
+    let enumeration = EnumerationData::try_from(
+        q.finalize()?
+            .schema()?
+            .enumeration(EnumerationKey::EnumerationName(ename))?,
+    )?;
+    assert_eq!(flintstones, enumeration);
+    assert_eq!(b"barney".to_vec(), enumeration.records()[a[0] as usize]);
+
+    Ok(())
+}
+
 #[test]
 fn drop_enumeration() -> anyhow::Result<()> {
     let ename = "states_enumeration";
@@ -259,3 +325,53 @@ fn drop_enumeration() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+/// Test evolving an array to match a desired schema
+#[test]
+fn evolve_from_diff() -> anyhow::Result<()> {
+    let array = TestArray::new(
+        "evolve_from_diff",
+        quickstart::Builder::new(ArrayType::Sparse).build().into(),
+    )?;
+
+    let old_schema = SchemaData::try_from(array.for_read()?.schema()?)?;
+
+    let new_schema = {
+        let mut s = old_schema.clone();
+        s.attributes.push(AttributeData {
+            name: "foobar".to_owned(),
+            datatype: Datatype::Float64,
+            cell_val_num: None,
+            nullability: None,
+            fill: None,
+            filters: Default::default(),
+            enumeration: None,
+        });
+        s
+    };
+
+    let evolution =
+        Builder::from_diff(&array.context, &old_schema.diff(&new_schema))?
+            .build();
+    Array::evolve(&array.context, &array.uri, evolution)?;
+
+    let evolved_schema = SchemaData::try_from(array.for_read()?.schema()?)?;
+    assert_eq!(2, evolved_schema.attributes.len());
+    assert!(evolved_schema.diff(&new_schema).is_empty());
+
+    // changing the datatype of an attribute is not supported
+    let bad_schema = {
+        let mut s = evolved_schema.clone();
+        s.attributes[1].datatype = Datatype::Int64;
+        s
+    };
+    let bad_diff = evolved_schema.diff(&bad_schema);
+    assert!(bad_diff.added_attributes.is_empty());
+    assert!(bad_diff.dropped_attributes.is_empty());
+    assert!(matches!(
+        Builder::from_diff(&array.context, &bad_diff),
+        Err(Error::InvalidArgument(_))
+    ));
+
+    Ok(())
+}
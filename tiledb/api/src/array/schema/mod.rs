@@ -20,6 +20,7 @@ use crate::error::Error;
 use crate::filter::list::{FilterList, RawFilterList};
 use crate::key::LookupKey;
 use crate::query::read::output::FieldScratchAllocator;
+use crate::serialization::{RawBuffer, SerializationType};
 use crate::Datatype;
 use crate::Result as TileDBResult;
 
@@ -104,7 +105,9 @@ impl Field {
          */
         let est_values_per_cell = match self.cell_val_num()? {
             CellValNum::Fixed(nz) => nz.get() as usize,
-            CellValNum::Var => 64,
+            CellValNum::Var => {
+                FieldScratchAllocator::DEFAULT_VAR_VALUES_PER_RECORD.get()
+            }
         };
         let est_cell_size = est_values_per_cell * self.datatype()?.size();
 
@@ -112,11 +115,38 @@ impl Field {
             .unwrap_or(FieldScratchAllocator::DEFAULT_MEMORY_LIMIT)
             / est_cell_size;
 
-        Ok(FieldScratchAllocator {
-            cell_val_num: self.cell_val_num().unwrap_or_default(),
-            record_capacity: NonZeroUsize::new(est_cell_capacity).unwrap(),
-            is_nullable: self.nullability().unwrap_or(true),
-        })
+        Ok(FieldScratchAllocator::new(
+            self.cell_val_num().unwrap_or_default(),
+            NonZeroUsize::new(est_cell_capacity).unwrap(),
+            self.nullability().unwrap_or(true),
+        ))
+    }
+
+    /// Returns an allocator whose initial scratch space holds exactly
+    /// `records` records of this field.
+    ///
+    /// For var-sized fields, the data space is estimated as for
+    /// `query_scratch_allocator`, and so may not be large enough.
+    /// [crate::query::QueryBuilder::with_buffer_capacity] instead estimates
+    /// it from the estimated result size of the query.
+    pub fn query_scratch_allocator_for_records(
+        &self,
+        records: NonZeroUsize,
+    ) -> TileDBResult<crate::query::read::output::FieldScratchAllocator> {
+        let cell_val_num = self.cell_val_num()?;
+
+        // var-sized fields are read with an extra trailing offset
+        let record_capacity = if cell_val_num.is_var_sized() {
+            records.checked_add(1).unwrap()
+        } else {
+            records
+        };
+
+        Ok(FieldScratchAllocator::new(
+            cell_val_num,
+            record_capacity,
+            self.nullability()?,
+        ))
     }
 }
 
@@ -186,6 +216,63 @@ impl Schema {
         Ok(Schema::new(context, RawSchema::Owned(c_schema)))
     }
 
+    /// Returns the serialized representation of this schema in `format`,
+    /// as used by the TileDB REST protocol.
+    pub fn serialize(
+        &self,
+        format: SerializationType,
+    ) -> TileDBResult<Vec<u8>> {
+        let c_schema = self.capi();
+        let c_format = ffi::tiledb_serialization_type_t::from(format);
+        let mut c_buffer: *mut ffi::tiledb_buffer_t = out_ptr!();
+        self.capi_call(|ctx| unsafe {
+            ffi::tiledb_serialize_array_schema(
+                ctx,
+                c_schema,
+                c_format,
+                1,
+                &mut c_buffer,
+            )
+        })?;
+
+        RawBuffer::Owned(c_buffer).to_vec(&self.context)
+    }
+
+    /// Constructs a schema from its serialized representation in `format`,
+    /// such as the output of [Schema::serialize].
+    pub fn deserialize(
+        context: &Context,
+        data: &[u8],
+        format: SerializationType,
+    ) -> TileDBResult<Self> {
+        let buffer = RawBuffer::borrowed(context, data)?;
+        let c_buffer = *buffer;
+        let c_format = ffi::tiledb_serialization_type_t::from(format);
+        let mut c_schema: *mut ffi::tiledb_array_schema_t = out_ptr!();
+        context.capi_call(|ctx| unsafe {
+            ffi::tiledb_deserialize_array_schema(
+                ctx,
+                c_buffer,
+                c_format,
+                1,
+                &mut c_schema,
+            )
+        })?;
+
+        Ok(Schema::new(context, RawSchema::Owned(c_schema)))
+    }
+
+    /// Checks that this schema is valid for creating an array.
+    ///
+    /// Returns an error describing the first problem found if the schema is invalid.
+    pub fn check(&self) -> TileDBResult<()> {
+        let c_schema = *self.raw;
+        self.capi_call(|ctx| unsafe {
+            ffi::tiledb_array_schema_check(ctx, c_schema)
+        })?;
+        Ok(())
+    }
+
     pub fn version(&self) -> TileDBResult<u32> {
         let c_schema = self.capi();
         let mut c_version: u32 = out_ptr!();
@@ -283,6 +370,22 @@ impl Schema {
         Ok(c_nattrs as usize)
     }
 
+    /// Returns whether this schema has an attribute named `name`.
+    pub fn has_attribute(&self, name: &str) -> TileDBResult<bool> {
+        let c_schema = *self.raw;
+        let c_name = cstring!(name);
+        let mut c_has: i32 = out_ptr!();
+        self.capi_call(|ctx| unsafe {
+            ffi::tiledb_array_schema_has_attribute(
+                ctx,
+                c_schema,
+                c_name.as_ptr(),
+                &mut c_has,
+            )
+        })?;
+        Ok(c_has != 0)
+    }
+
     pub fn attribute<K: Into<LookupKey>>(
         &self,
         key: K,
@@ -358,10 +461,40 @@ impl Schema {
         }
     }
 
+    /// Returns the fields of this schema named by `names`, in the same order.
+    ///
+    /// Returns `Error::InvalidArgument` listing every name in `names`
+    /// which is neither a dimension nor an attribute of this schema.
+    pub fn fields_by_name(&self, names: &[&str]) -> TileDBResult<Vec<Field>> {
+        let domain = self.domain()?;
+        let mut unknown = vec![];
+        for name in names.iter().copied() {
+            if !domain.has_dimension(name)? && !self.has_attribute(name)? {
+                unknown.push(name);
+            }
+        }
+        if !unknown.is_empty() {
+            return Err(Error::InvalidArgument(anyhow!(
+                "Unknown field(s): {}",
+                unknown.join(", ")
+            )));
+        }
+        names.iter().map(|name| self.field(*name)).collect()
+    }
+
+    /// Returns an iterator over the fields of this schema,
+    /// dimensions first and then attributes, in the order of
+    /// the indices accepted by [Schema::field].
     pub fn fields(&self) -> TileDBResult<Fields<'_>> {
         Fields::new(self)
     }
 
+    /// Returns the names of the fields of this schema,
+    /// in the same order as [Schema::fields].
+    pub fn field_names(&self) -> TileDBResult<Vec<String>> {
+        self.fields()?.map(|f| f?.name()).collect()
+    }
+
     /// Returns the enumeration identified by the requested key.
     pub fn enumeration(
         &self,
@@ -701,10 +834,7 @@ impl Builder {
     }
 
     pub fn build(self) -> TileDBResult<Schema> {
-        let c_schema = *self.schema.raw;
-        self.capi_call(|ctx| unsafe {
-            ffi::tiledb_array_schema_check(ctx, c_schema)
-        })?;
+        self.schema.check()?;
         Ok(self.schema)
     }
 }
@@ -743,6 +873,7 @@ mod tests {
     use crate::filter::{
         CompressionData, CompressionType, FilterData, FilterListBuilder,
     };
+    use crate::tests::examples::quickstart::Builder as QuickstartBuilder;
     use crate::{Context, Factory};
 
     fn sample_attribute(c: &Context) -> Attribute {
@@ -793,6 +924,30 @@ mod tests {
         assert_eq!(schema_version, 22);
     }
 
+    #[test]
+    fn test_check() -> TileDBResult<()> {
+        let c: Context = Context::new()?;
+
+        // a dense array must have at least one attribute
+        {
+            let b = Builder::new(&c, ArrayType::Dense, sample_domain(&c))?;
+            let e = b.schema.check();
+            assert!(matches!(e, Err(Error::LibTileDB(_))));
+        }
+
+        // the check passes once the schema is complete
+        {
+            let b = with_attribute(
+                &c,
+                Builder::new(&c, ArrayType::Dense, sample_domain(&c))?,
+            );
+            b.schema.check()?;
+            b.build()?.check()?;
+        }
+
+        Ok(())
+    }
+
     #[test]
     fn test_array_type() -> TileDBResult<()> {
         let c: Context = Context::new()?;
@@ -930,6 +1085,28 @@ mod tests {
         test_uri.close().map_err(|e| Error::Other(e.to_string()))
     }
 
+    #[test]
+    fn test_serialize() -> TileDBResult<()> {
+        let c: Context = Context::new()?;
+
+        let spec = QuickstartBuilder::new(ArrayType::Dense).build();
+        let schema = spec.create(&c)?;
+
+        let json = schema.serialize(SerializationType::Json)?;
+        assert!(!json.is_empty());
+
+        let deserialized =
+            Schema::deserialize(&c, &json, SerializationType::Json)?;
+        assert_eq!(schema, deserialized);
+
+        // not a serialized schema
+        assert!(
+            Schema::deserialize(&c, b"{}", SerializationType::Json).is_err()
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn test_layout() -> TileDBResult<()> {
         let c: Context = Context::new()?;
@@ -1098,6 +1275,35 @@ mod tests {
             assert_eq!(Datatype::Float64, a2.datatype()?);
         }
 
+        // iteration
+        {
+            assert_eq!(vec!["test", "a1", "a2"], s.field_names()?);
+
+            let fields = s.fields()?;
+            assert_eq!(3, fields.num_fields());
+            let fields = fields.collect::<TileDBResult<Vec<Field>>>()?;
+            assert!(matches!(fields[0], Field::Dimension(_)));
+            assert!(matches!(fields[1], Field::Attribute(_)));
+            assert!(matches!(fields[2], Field::Attribute(_)));
+            for (i, f) in fields.iter().enumerate() {
+                assert_eq!(s.field(i)?.name()?, f.name()?);
+            }
+        }
+
+        // selection by name
+        {
+            let fields = s.fields_by_name(&["a2", "test"])?;
+            assert_eq!(2, fields.len());
+            assert_eq!("a2", fields[0].name()?);
+            assert_eq!("test", fields[1].name()?);
+
+            let e = s.fields_by_name(&["a1", "foo", "test", "bar"]);
+            let Err(Error::InvalidArgument(e)) = e else {
+                unreachable!("Expected InvalidArgument error")
+            };
+            assert_eq!("Unknown field(s): foo, bar", e.to_string());
+        }
+
         Ok(())
     }
 
@@ -1,3 +1,4 @@
+use anyhow::anyhow;
 use itertools::Itertools;
 use tiledb_common::filter::FilterData;
 use tiledb_pod::array::attribute::AttributeData;
@@ -8,6 +9,20 @@ use super::{Builder, EnumerationKey, Field, Schema};
 use crate::error::Error;
 use crate::{Context, Factory, Result as TileDBResult};
 
+/// Converts a [Schema] into its plain-old-data representation.
+///
+/// The data of each enumeration used by an attribute is read from the
+/// schema, so the enumerations must have been loaded first.
+/// The schema of an array opened with [Array::open] loads enumerations
+/// lazily; call [Array::load_all_enumerations] before [Array::schema],
+/// or use [Array::load_schema_offline], to convert it.
+/// If an enumeration is not loaded then this returns
+/// [Error::InvalidArgument] naming the enumeration.
+///
+/// [Array::open]: crate::array::Array::open
+/// [Array::schema]: crate::array::Array::schema
+/// [Array::load_all_enumerations]: crate::array::Array::load_all_enumerations
+/// [Array::load_schema_offline]: crate::array::Array::load_schema_offline
 impl TryFrom<&Schema> for SchemaData {
     type Error = Error;
 
@@ -21,10 +36,17 @@ impl TryFrom<&Schema> for SchemaData {
             .filter_map(|a| a.enumeration.as_ref())
             .unique()
             .map(|ename| {
-                EnumerationData::try_from(
-                    schema
-                        .enumeration(EnumerationKey::EnumerationName(ename))?,
-                )
+                let enumeration = schema
+                    .enumeration(EnumerationKey::EnumerationName(ename))
+                    .map_err(|e| {
+                        Error::InvalidArgument(anyhow!(
+                            "Enumeration '{}' is not loaded, \
+                             see Array::load_all_enumerations: {}",
+                            ename,
+                            e
+                        ))
+                    })?;
+                EnumerationData::try_from(enumeration)
             })
             .collect::<TileDBResult<Vec<EnumerationData>>>()?;
 
@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::ops::Deref;
 
 use crate::context::{CApiError, CApiResult, RawError};
@@ -159,6 +160,68 @@ impl Config {
         }
     }
 
+    /// Returns an iterator over the keys and values of this configuration.
+    ///
+    /// If `prefix` is provided, then only the parameters whose keys begin
+    /// with `prefix` are produced, and `prefix` is removed from each key.
+    pub fn iter(&self, prefix: Option<&str>) -> CApiResult<ConfigIterator<'_>> {
+        let c_prefix = prefix.map(|p| {
+            std::ffi::CString::new(p).expect("Error creating CString")
+        });
+        let c_prefix_ptr = c_prefix
+            .as_ref()
+            .map(|p| p.as_c_str().as_ptr())
+            .unwrap_or_else(std::ptr::null::<std::os::raw::c_char>);
+        let mut c_iter: *mut ffi::tiledb_config_iter_t = out_ptr!();
+        let mut c_err: *mut ffi::tiledb_error_t = out_ptr!();
+        let res = unsafe {
+            ffi::tiledb_config_iter_alloc(
+                *self.raw,
+                c_prefix_ptr,
+                &mut c_iter,
+                &mut c_err,
+            )
+        };
+
+        if res == ffi::TILEDB_OK {
+            Ok(ConfigIterator {
+                _cfg: self,
+                raw: RawConfigIter::Owned(c_iter),
+            })
+        } else {
+            Err(CApiError::from(RawError::Owned(c_err)))
+        }
+    }
+
+    /// Copies the values of `other` which differ from the defaults
+    /// into `self`, overriding any values which `self` already has.
+    ///
+    /// A configuration from [Config::new] has a value for every
+    /// parameter. Parameters which `other` leaves at their default value
+    /// are not copied, so they do not replace values which `self` has changed.
+    pub fn merge(&mut self, other: &Config) -> CApiResult<()> {
+        for (key, value) in Config::new()?.diff(other) {
+            self.set(&key, value)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the keys and values of `other` which differ from `self`.
+    ///
+    /// Each returned key either is not set in `self` or is set to a different
+    /// value than it is in `other`. The value in the returned pair is
+    /// the value from `other`.
+    ///
+    /// The difference is one-sided: keys which are set in `self` but not
+    /// in `other` are not returned. Use `other.diff(self)` for those.
+    pub fn diff(&self, other: &Config) -> Vec<(String, String)> {
+        let mine = self.into_iter().collect::<HashMap<String, String>>();
+        other
+            .into_iter()
+            .filter(|(key, value)| mine.get(key) != Some(value))
+            .collect()
+    }
+
     pub fn load(&mut self, path: &str) -> CApiResult<()> {
         let c_path =
             std::ffi::CString::new(path).expect("Error creating CString");
@@ -215,26 +278,7 @@ impl<'cfg> IntoIterator for &'cfg Config {
     type IntoIter = ConfigIterator<'cfg>;
 
     fn into_iter(self) -> Self::IntoIter {
-        let mut c_iter: *mut ffi::tiledb_config_iter_t = out_ptr!();
-        let c_path = std::ptr::null::<std::os::raw::c_char>();
-        let mut c_err: *mut ffi::tiledb_error_t = out_ptr!();
-        let res = unsafe {
-            ffi::tiledb_config_iter_alloc(
-                *self.raw,
-                c_path,
-                &mut c_iter,
-                &mut c_err,
-            )
-        };
-
-        if res == ffi::TILEDB_OK {
-            ConfigIterator {
-                _cfg: self,
-                raw: RawConfigIter::Owned(c_iter),
-            }
-        } else {
-            panic!("Not entirely sure what to do here.")
-        }
+        self.iter(None).expect("Error allocating config iterator")
     }
 }
 
@@ -418,6 +462,102 @@ mod tests {
         assert_eq!(val.unwrap(), "NO_ENCRYPTION");
     }
 
+    #[test]
+    fn config_iter_prefix() {
+        let cfg = Config::new()
+            .and_then(|c| c.with("rs.tiledb.test_key", "foobar"))
+            .expect("Error creating config instance.");
+
+        let all = cfg
+            .iter(None)
+            .expect("Error creating config iterator.")
+            .collect::<Vec<_>>();
+        assert!(all
+            .contains(&("rs.tiledb.test_key".to_owned(), "foobar".to_owned())));
+
+        let rs = cfg
+            .iter(Some("rs.tiledb."))
+            .expect("Error creating config iterator.")
+            .collect::<Vec<_>>();
+        assert_eq!(vec![("test_key".to_owned(), "foobar".to_owned())], rs);
+
+        let sm = cfg
+            .iter(Some("sm."))
+            .expect("Error creating config iterator.")
+            .collect::<Vec<_>>();
+        assert!(!sm.is_empty());
+        assert!(sm.len() < all.len());
+        assert!(sm.contains(&(
+            "encryption_type".to_owned(),
+            "NO_ENCRYPTION".to_owned()
+        )));
+    }
+
+    #[test]
+    fn config_diff() {
+        let cfg1 = Config::new().expect("Error creating config instance.");
+        let cfg2 = Config::new().expect("Error creating config instance.");
+        assert!(cfg1.diff(&cfg2).is_empty());
+
+        let cfg2 = cfg2
+            .with("rs.tiledb.test_key", "foobar")
+            .and_then(|c| c.with("sm.encryption_type", "AES_256_GCM"))
+            .expect("Error setting config key.");
+
+        let mut diff = cfg1.diff(&cfg2);
+        diff.sort();
+        assert_eq!(
+            vec![
+                ("rs.tiledb.test_key".to_owned(), "foobar".to_owned()),
+                ("sm.encryption_type".to_owned(), "AES_256_GCM".to_owned())
+            ],
+            diff
+        );
+
+        let diff = cfg2.diff(&cfg1);
+        assert_eq!(
+            vec![("sm.encryption_type".to_owned(), "NO_ENCRYPTION".to_owned())],
+            diff
+        );
+    }
+
+    #[test]
+    fn config_merge() {
+        let mut base = Config::new()
+            .and_then(|c| c.with("rs.tiledb.base_key", "base"))
+            .and_then(|c| c.with("rs.tiledb.test_key", "base"))
+            .and_then(|c| c.with("sm.io_concurrency_level", "101"))
+            .expect("Error creating config instance.");
+        let overrides = Config::new()
+            .and_then(|c| c.with("rs.tiledb.test_key", "override"))
+            .and_then(|c| c.with("sm.compute_concurrency_level", "2"))
+            .expect("Error creating config instance.");
+
+        base.merge(&overrides).expect("Error merging config.");
+
+        let get = |key: &str| {
+            base.get(key)
+                .expect("Error getting config key.")
+                .expect("Expected config key to be set")
+        };
+        assert_eq!("base", get("rs.tiledb.base_key"));
+        assert_eq!("override", get("rs.tiledb.test_key"));
+        assert_eq!("2", get("sm.compute_concurrency_level"));
+
+        // the default of `overrides` does not replace the value of `base`
+        assert_eq!("101", get("sm.io_concurrency_level"));
+
+        // the changed values of `overrides` are now in `base`,
+        // which also keeps the values that `overrides` does not change
+        assert_eq!(
+            HashMap::from([
+                ("rs.tiledb.base_key".to_owned(), "base".to_owned()),
+                ("sm.io_concurrency_level".to_owned(), "101".to_owned())
+            ]),
+            overrides.diff(&base).into_iter().collect::<HashMap<_, _>>()
+        );
+    }
+
     #[test]
     fn config_with_common_options() {
         let common_options = vec![
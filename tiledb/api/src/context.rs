@@ -1,8 +1,11 @@
+use std::backtrace::Backtrace;
 use std::convert::From;
 use std::fmt::{Debug, Display, Formatter, Result as FmtResult};
 use std::ops::Deref;
 use std::rc::Rc;
 
+use anyhow::anyhow;
+
 use crate::config::{Config, RawConfig};
 use crate::filesystem::Filesystem;
 use crate::stats::RawStatsString;
@@ -28,6 +31,10 @@ pub enum CApiError {
     InvalidCString(std::ffi::NulError),
     #[error("Error returned from libtiledb: {0}")]
     Error(String),
+    /// An error returned from libtiledb by a context which captures
+    /// a backtrace for each error. See [Context::with_error_capture].
+    #[error("Error returned from libtiledb: {0}")]
+    ErrorWithTrace(String, Box<Backtrace>),
     #[error("Internal error retrieving error message from libtiledb")]
     Internal,
 }
@@ -112,6 +119,7 @@ where
 #[derive(Clone)]
 pub struct Context {
     raw: Rc<RawContext>,
+    capture_errors: bool,
 }
 
 impl Context {
@@ -126,6 +134,7 @@ impl Context {
         match res {
             ffi::TILEDB_OK => Ok(Context {
                 raw: Rc::new(RawContext { raw: c_ctx }),
+                capture_errors: false,
             }),
             ffi::TILEDB_OOM => Err(CreateContextError::OutOfMemory),
             ffi::TILEDB_ERR => Err(CreateContextError::Fatal),
@@ -135,6 +144,21 @@ impl Context {
         }
     }
 
+    /// Returns a handle to the same context which, if `capture` is true,
+    /// captures a backtrace for each error returned from libtiledb.
+    ///
+    /// Such errors are reported as [crate::error::Error::LibTileDBWithTrace]
+    /// rather than [crate::error::Error::LibTileDB], which can help to find
+    /// the origin of an error in a chain of failures. Capturing a backtrace
+    /// is expensive, so this is disabled by default. Objects created using
+    /// the returned context, and objects bound to them, also capture errors.
+    pub fn with_error_capture(self, capture: bool) -> Self {
+        Context {
+            capture_errors: capture,
+            ..self
+        }
+    }
+
     pub fn capi_call<Callable>(&self, action: Callable) -> CApiResult<()>
     where
         Callable: FnOnce(*mut ffi::tiledb_ctx_t) -> i32,
@@ -143,7 +167,15 @@ impl Context {
         if c_ret == ffi::TILEDB_OK {
             Ok(())
         } else if let Some(e) = self.get_last_error() {
-            Err(e)
+            match e {
+                CApiError::Error(message) if self.capture_errors => {
+                    Err(CApiError::ErrorWithTrace(
+                        message,
+                        Box::new(Backtrace::force_capture()),
+                    ))
+                }
+                e => Err(e),
+            }
         } else {
             panic!("libtiledb context did not have error for error return value: {}", c_ret)
         }
@@ -164,6 +196,44 @@ impl Context {
         Ok(String::from(json.to_string_lossy()))
     }
 
+    /// Resets the statistics gathered by libtiledb.
+    ///
+    /// libtiledb statistics are global to the process rather than scoped
+    /// to a `Context`. This resets the statistics gathered by operations
+    /// on every context, and operations on other contexts which run
+    /// concurrently will be included in subsequent statistics.
+    /// For an accurate measurement of a single operation, such as a query
+    /// in a single-threaded benchmark, call this before the operation and
+    /// [Self::dump_stats] after it.
+    pub fn reset_stats(&self) -> TileDBResult<()> {
+        Ok(crate::stats::reset()?)
+    }
+
+    /// Returns the statistics gathered by libtiledb since they were last reset,
+    /// as a JSON string.
+    ///
+    /// Statistics are only gathered while [crate::stats::enable] is in effect.
+    /// See [Self::reset_stats] for caveats about their scope.
+    pub fn dump_stats(&self) -> TileDBResult<String> {
+        Ok(crate::stats::dump()?.unwrap_or_default())
+    }
+
+    /// Returns the statistics gathered by libtiledb since they were last reset,
+    /// parsed as JSON.
+    ///
+    /// Returns [serde_json::Value::Null] if libtiledb has no statistics
+    /// to report. See [Self::dump_stats].
+    #[cfg(feature = "serde")]
+    pub fn stats_json(&self) -> TileDBResult<serde_json::Value> {
+        let dump = self.dump_stats()?;
+        if dump.trim().is_empty() {
+            return Ok(serde_json::Value::Null);
+        }
+        serde_json::from_str::<serde_json::Value>(&dump).map_err(|e| {
+            crate::error::Error::from(crate::stats::Error::ToJson(anyhow!(e)))
+        })
+    }
+
     pub fn get_config(&self) -> TileDBResult<Config> {
         let mut c_cfg: *mut ffi::tiledb_config_t = out_ptr!();
         self.capi_call(|ctx| unsafe {
@@ -175,6 +245,36 @@ impl Context {
         })
     }
 
+    /// Returns the number of threads which this context uses for compute
+    /// tasks and for IO tasks, in that order.
+    ///
+    /// These are the values of the `sm.compute_concurrency_level` and
+    /// `sm.io_concurrency_level` configuration parameters respectively.
+    /// If either is unset, then the hardware concurrency is returned in its place.
+    pub fn concurrency_level(&self) -> TileDBResult<(usize, usize)> {
+        let config = self.get_config()?;
+
+        let level = |key: &str| -> TileDBResult<usize> {
+            if let Some(value) = config.get(key)? {
+                value.parse::<usize>().map_err(|e| {
+                    crate::error::Error::InvalidArgument(
+                        anyhow!("Invalid value for '{}': {}", key, value)
+                            .context(e),
+                    )
+                })
+            } else {
+                Ok(std::thread::available_parallelism()
+                    .map(|n| n.get())
+                    .unwrap_or(1))
+            }
+        };
+
+        Ok((
+            level("sm.compute_concurrency_level")?,
+            level("sm.io_concurrency_level")?,
+        ))
+    }
+
     pub fn get_last_error(&self) -> Option<CApiError> {
         let mut c_err: *mut ffi::tiledb_error_t = out_ptr!();
         let res = self.capi_call(|ctx| unsafe {
@@ -276,6 +376,34 @@ mod tests {
         Context::new().expect("Error creating context instance.");
     }
 
+    /// Test that errors capture a backtrace only when enabled.
+    #[test]
+    fn ctx_error_capture() -> TileDBResult<()> {
+        use std::backtrace::BacktraceStatus;
+
+        use crate::array::{Array, Mode};
+        use crate::error::Error;
+
+        let ctx = Context::new()?;
+        let open = Array::open(&ctx, "not_an_array", Mode::Read);
+        assert!(matches!(open, Err(Error::LibTileDB(CApiError::Error(_)))));
+
+        let ctx = ctx.with_error_capture(true);
+        let open = Array::open(&ctx, "not_an_array", Mode::Read);
+        let Err(Error::LibTileDBWithTrace(CApiError::Error(_), backtrace)) =
+            open
+        else {
+            unreachable!("Expected error with backtrace")
+        };
+        assert_eq!(BacktraceStatus::Captured, backtrace.status());
+
+        let ctx = ctx.with_error_capture(false);
+        let open = Array::open(&ctx, "not_an_array", Mode::Read);
+        assert!(matches!(open, Err(Error::LibTileDB(CApiError::Error(_)))));
+
+        Ok(())
+    }
+
     #[test]
     fn ctx_from_config() {
         let cfg = Config::new().expect("Error creating config instance.");
@@ -291,6 +419,17 @@ mod tests {
         assert!(json.unwrap() == "");
     }
 
+    #[test]
+    fn ctx_concurrency_level() {
+        let cfg = Config::new()
+            .and_then(|c| c.with("sm.compute_concurrency_level", "3"))
+            .and_then(|c| c.with("sm.io_concurrency_level", "5"))
+            .expect("Error creating config instance.");
+        let ctx = Context::from_config(&cfg)
+            .expect("Error creating context instance.");
+        assert_eq!((3, 5), ctx.concurrency_level().unwrap());
+    }
+
     #[test]
     fn ctx_get_last_error() {
         let ctx = Context::new().expect("Error creating instance.");
@@ -22,7 +22,15 @@ pub enum Error {
     CreateContext(#[from] crate::context::CreateContextError),
     /// Error received from the libtiledb backend
     #[error("libtiledb error: {0}")]
-    LibTileDB(#[from] crate::context::CApiError),
+    LibTileDB(crate::context::CApiError),
+    /// Error received from the libtiledb backend, together with the
+    /// backtrace of the call which received it.
+    /// See [crate::context::Context::with_error_capture].
+    #[error("libtiledb error: {0}")]
+    LibTileDBWithTrace(
+        #[source] crate::context::CApiError,
+        Box<std::backtrace::Backtrace>,
+    ),
     /// Error retrieving a string from libtiledb
     #[error("libtiledb string error: {0}")]
     LibTileDBString(#[from] crate::string::Error),
@@ -97,6 +105,18 @@ pub enum Error {
     Other(String),
 }
 
+impl From<crate::context::CApiError> for Error {
+    fn from(value: crate::context::CApiError) -> Self {
+        use crate::context::CApiError;
+        match value {
+            CApiError::ErrorWithTrace(message, backtrace) => {
+                Error::LibTileDBWithTrace(CApiError::Error(message), backtrace)
+            }
+            value => Error::LibTileDB(value),
+        }
+    }
+}
+
 #[cfg(feature = "serde")]
 impl Serialize for Error {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -3,7 +3,7 @@ use std::fmt::{Debug, Formatter, Result as FmtResult};
 use std::ops::Deref;
 
 use crate::context::{CApiInterface, Context, ContextBound};
-use crate::filter::{Filter, FilterData, RawFilter};
+use crate::filter::{ChecksumType, Filter, FilterData, RawFilter};
 use crate::Result as TileDBResult;
 
 pub(crate) enum RawFilterList {
@@ -175,6 +175,15 @@ impl Builder {
         self.add_filter(Filter::create(&ctx, filter)?)
     }
 
+    /// Adds a checksum filter of type `kind` to the end of the filter list.
+    ///
+    /// The checksum is computed over the output of the preceding filters,
+    /// i.e. over the bytes which are actually stored, and is validated
+    /// when the data is read. See [crate::array::Array::verify_integrity].
+    pub fn add_checksum(self, kind: ChecksumType) -> TileDBResult<Self> {
+        self.add_filter_data(FilterData::Checksum(kind))
+    }
+
     pub fn build(self) -> FilterList {
         self.filter_list
     }
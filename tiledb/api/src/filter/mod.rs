@@ -298,7 +298,9 @@ impl Filter {
             }),
             FilterType::WebP => Ok(FilterData::WebP {
                 input_format: WebPFilterInputFormat::try_from(
-                    self.get_option::<u32>(FilterOption::WebPInputFormat)?,
+                    self.get_option::<std::ffi::c_uchar>(
+                        FilterOption::WebPInputFormat,
+                    )? as ffi::tiledb_filter_webp_format_t,
                 )?,
                 lossless: Some(
                     self.get_option::<std::ffi::c_uchar>(
@@ -673,6 +675,33 @@ mod tests {
         });
     }
 
+    /// Test that every option of an arbitrary filter list is read back
+    /// with the value it was set to
+    #[test]
+    fn filter_list_roundtrip() {
+        let ctx = Context::new().expect("Error creating context");
+
+        proptest!(|(pipeline_in in FilterPipelineStrategy::default())| {
+            let pipeline = pipeline_in.create(&ctx)
+                .expect("Error constructing arbitrary filter list");
+            let pipeline_out = Vec::<FilterData>::try_from(&pipeline)
+                .expect("Error reading filter list data");
+
+            // options which are not set are read back as their defaults
+            assert_option_subset!(pipeline_in, pipeline_out);
+
+            // and the data read back constructs an equivalent filter list
+            let pipeline_out_out = pipeline_out.create(&ctx)
+                .expect("Error constructing filter list from output");
+            assert_eq!(pipeline, pipeline_out_out);
+            assert_eq!(
+                pipeline_out,
+                Vec::<FilterData>::try_from(&pipeline_out_out)
+                    .expect("Error reading filter list data")
+            );
+        });
+    }
+
     #[test]
     fn filter_eq_reflexivity() {
         let ctx = Context::new().expect("Error creating context");
@@ -13,11 +13,15 @@ use crate::Result as TileDBResult;
 
 pub type QueryType = crate::array::Mode;
 
+/// Describes a member of a [Group].
 #[derive(Clone, Debug, PartialEq)]
 pub struct GroupInfo {
+    /// URI of the member.
     pub uri: String,
+    /// Whether the member is an array or a group.
     pub group_type: ObjectType,
-    pub name: String,
+    /// Name of the member, if it was added with one.
+    pub name: Option<String>,
 }
 
 pub(crate) struct RawGroup {
@@ -192,8 +196,8 @@ impl Group {
         Ok(())
     }
 
-    // Deletes a member of the group.
-    pub fn delete_member<S>(&mut self, name_or_uri: S) -> TileDBResult<()>
+    // Removes a member of the group.
+    pub fn remove_member<S>(&mut self, name_or_uri: S) -> TileDBResult<()>
     where
         S: AsRef<str>,
     {
@@ -209,6 +213,15 @@ impl Group {
         Ok(())
     }
 
+    // Removes a member of the group.
+    #[deprecated(note = "renamed to `remove_member`")]
+    pub fn delete_member<S>(&mut self, name_or_uri: S) -> TileDBResult<()>
+    where
+        S: AsRef<str>,
+    {
+        self.remove_member(name_or_uri)
+    }
+
     pub fn num_members(&self) -> TileDBResult<u64> {
         let c_group = self.capi();
         let mut c_count: u64 = out_ptr!();
@@ -222,7 +235,7 @@ impl Group {
         let c_group = self.capi();
         let mut tiledb_uri: *mut ffi::tiledb_string_t = out_ptr!();
         let mut tiledb_type: ffi::tiledb_object_t = out_ptr!();
-        let name: String = match key {
+        let name: Option<String> = match key {
             LookupKey::Index(index) => {
                 let mut tiledb_name: *mut ffi::tiledb_string_t = out_ptr!();
                 self.capi_call(|ctx| unsafe {
@@ -235,12 +248,16 @@ impl Group {
                         &mut tiledb_name as *mut *mut ffi::tiledb_string_t,
                     )
                 })?;
-                let name = TDBString {
-                    raw: RawTDBString::Owned(tiledb_name),
+                if tiledb_name.is_null() {
+                    // the member was added without a name
+                    Ok(None) as TileDBResult<Option<String>>
+                } else {
+                    let name = TDBString {
+                        raw: RawTDBString::Owned(tiledb_name),
+                    }
+                    .to_string()?;
+                    Ok(Some(name))
                 }
-                .to_string();
-                let name = name?;
-                Ok(name) as TileDBResult<String>
             }
             LookupKey::Name(name) => {
                 let c_name = cstring!(name.as_ref() as &str);
@@ -253,7 +270,7 @@ impl Group {
                         &mut tiledb_type,
                     )
                 })?;
-                Ok(name.to_owned())
+                Ok(Some(name.to_owned()))
             }
         }?;
 
@@ -271,6 +288,13 @@ impl Group {
         })
     }
 
+    /// Returns a description of each member of the group, in index order.
+    pub fn members(&self) -> TileDBResult<Vec<GroupInfo>> {
+        (0..self.num_members()?)
+            .map(|i| self.member(LookupKey::Index(i as usize)))
+            .collect()
+    }
+
     pub fn is_relative_uri<S>(&self, name: S) -> TileDBResult<bool>
     where
         S: AsRef<str>,
@@ -411,11 +435,7 @@ impl Group {
             }
         }?;
         let datatype = Datatype::try_from(c_datatype)?;
-        Ok(metadata::metadata_from_ffi(
-            name,
-            datatype,
-            (vec_size, vec_ptr),
-        ))
+        metadata::metadata_from_ffi(name, datatype, (vec_size, vec_ptr))
     }
 
     pub fn has_metadata_key<S>(&self, name: S) -> TileDBResult<Option<Datatype>>
@@ -508,7 +528,7 @@ mod tests {
         context::Context,
         datatype::Datatype,
         error::Error,
-        group::{Group, QueryType},
+        group::{Group, GroupInfo, QueryType},
         key::LookupKey,
         metadata::{self, Metadata},
     };
@@ -704,7 +724,7 @@ mod tests {
         {
             let mut group_write =
                 Group::open(&tdb, &group_uri, QueryType::Write, None)?;
-            group_write.delete_member("bb")?;
+            group_write.remove_member("bb")?;
         }
 
         {
@@ -721,12 +741,12 @@ mod tests {
 
             let member_aa =
                 group_read.member(LookupKey::Name("aa".to_owned()))?;
-            assert_eq!(member_aa.name, "aa".to_owned());
+            assert_eq!(member_aa.name, Some("aa".to_owned()));
             assert_eq!(member_aa.group_type, ObjectType::Array);
             assert_eq!(group_uri.clone() + "/aa", member_aa.uri);
 
             let member_cc = group_read.member(LookupKey::Index(1))?;
-            assert_eq!(member_cc.name, "cc".to_owned());
+            assert_eq!(member_cc.name, Some("cc".to_owned()));
             assert_eq!(member_cc.group_type, ObjectType::Array);
             assert_eq!(group_uri.clone() + "/cc", member_cc.uri);
 
@@ -737,6 +757,71 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_group_members() -> TileDBResult<()> {
+        let test_uri = uri::get_uri_generator()
+            .map_err(|e| Error::Other(e.to_string()))?;
+
+        let tdb = Context::new()?;
+        let group_uri = test_uri
+            .with_path("group3")
+            .map_err(|e| Error::Other(e.to_string()))?;
+        Group::create(&tdb, &group_uri)?;
+
+        {
+            let group_read =
+                Group::open(&tdb, &group_uri, QueryType::Read, None)?;
+            assert!(group_read.members()?.is_empty());
+        }
+
+        create_array(group_uri.to_owned() + "/dense", ArrayType::Dense)?;
+        create_array(group_uri.to_owned() + "/sparse", ArrayType::Sparse)?;
+
+        {
+            let mut group_write =
+                Group::open(&tdb, &group_uri, QueryType::Write, None)?;
+            group_write.add_member("dense", true, Some("d"))?;
+            group_write.add_member("sparse", true, None::<&str>)?;
+        }
+
+        {
+            let group_read =
+                Group::open(&tdb, &group_uri, QueryType::Read, None)?;
+            let members = group_read.members()?;
+            assert_eq!(
+                vec![
+                    GroupInfo {
+                        uri: group_uri.clone() + "/dense",
+                        group_type: ObjectType::Array,
+                        name: Some("d".to_owned()),
+                    },
+                    GroupInfo {
+                        uri: group_uri.clone() + "/sparse",
+                        group_type: ObjectType::Array,
+                        name: None,
+                    },
+                ],
+                members
+            );
+        }
+
+        {
+            let mut group_write =
+                Group::open(&tdb, &group_uri, QueryType::Write, None)?;
+            group_write.remove_member("d")?;
+        }
+
+        {
+            let group_read =
+                Group::open(&tdb, &group_uri, QueryType::Read, None)?;
+            let members = group_read.members()?;
+            assert_eq!(1, members.len());
+            assert_eq!(group_uri.clone() + "/sparse", members[0].uri);
+        }
+
+        test_uri.close().map_err(|e| Error::Other(e.to_string()))
+    }
+
     #[test]
     fn test_group_config() -> TileDBResult<()> {
         let test_uri = uri::get_uri_generator()
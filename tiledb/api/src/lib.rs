@@ -47,6 +47,7 @@ pub mod filter;
 pub mod group;
 pub mod metadata;
 pub mod query;
+pub mod serialization;
 pub mod stats;
 pub mod string;
 pub mod vfs;
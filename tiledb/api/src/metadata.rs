@@ -1,6 +1,10 @@
+use anyhow::anyhow;
 use tiledb_common::datatype::Datatype;
 use tiledb_common::physical_type_go;
 
+use crate::error::{DatatypeError, Error};
+use crate::Result as TileDBResult;
+
 pub use tiledb_common::metadata::*;
 pub use tiledb_common::metadata_value_go;
 
@@ -20,7 +24,7 @@ pub(crate) fn metadata_from_ffi(
     key: String,
     datatype: Datatype,
     ffi: (u32, *const std::ffi::c_void),
-) -> Metadata {
+) -> TileDBResult<Metadata> {
     let value = physical_type_go!(datatype, DT, {
         let slice = {
             let vec_ptr = if ffi.0 == 0 {
@@ -36,9 +40,117 @@ pub(crate) fn metadata_from_ffi(
         Value::from(slice.to_vec())
     });
 
-    Metadata {
+    let value = match value {
+        Value::UInt8Value(bytes) if datatype == Datatype::StringUtf8 => {
+            Value::StringUtf8(String::from_utf8(bytes).map_err(|e| {
+                Error::Deserialization(
+                    format!("metadata '{}'", key),
+                    anyhow!(e),
+                )
+            })?)
+        }
+        value => value,
+    };
+
+    Ok(Metadata {
         key,
         datatype,
         value,
+    })
+}
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// A Rust type which the values of a metadata item can be read as directly.
+/// See `Array::metadata_value`.
+pub trait MetadataValue: private::Sealed + Sized {
+    /// Returns the values of `metadata` as `Self`, or `Err` if the datatype
+    /// of `metadata` is not compatible with `Self`.
+    fn from_metadata(metadata: Metadata) -> TileDBResult<Vec<Self>>;
+}
+
+macro_rules! metadata_value_physical {
+    ($($ty:ty: $variant:ident),+) => {
+        $(
+            impl private::Sealed for $ty {}
+
+            impl MetadataValue for $ty {
+                fn from_metadata(
+                    metadata: Metadata,
+                ) -> TileDBResult<Vec<Self>> {
+                    match metadata.value {
+                        Value::$variant(values)
+                            if metadata.datatype.is_compatible_type::<$ty>() =>
+                        {
+                            Ok(values)
+                        }
+                        _ => Err(DatatypeError::physical_type_incompatible::<
+                            $ty,
+                        >(metadata.datatype))?,
+                    }
+                }
+            }
+        )+
+    };
+}
+
+metadata_value_physical!(
+    u16: UInt16Value,
+    u32: UInt32Value,
+    u64: UInt64Value,
+    i8: Int8Value,
+    i16: Int16Value,
+    i32: Int32Value,
+    i64: Int64Value,
+    f32: Float32Value,
+    f64: Float64Value
+);
+
+impl private::Sealed for u8 {}
+
+/// The bytes of a string metadata item can be read as `u8`.
+impl MetadataValue for u8 {
+    fn from_metadata(metadata: Metadata) -> TileDBResult<Vec<Self>> {
+        match metadata.value {
+            Value::UInt8Value(values)
+                if metadata.datatype.is_compatible_type::<u8>() =>
+            {
+                Ok(values)
+            }
+            Value::StringUtf8(s) => Ok(s.into_bytes()),
+            _ => Err(DatatypeError::physical_type_incompatible::<u8>(
+                metadata.datatype,
+            ))?,
+        }
+    }
+}
+
+impl private::Sealed for String {}
+
+/// A string metadata item is read as a single `String`.
+impl MetadataValue for String {
+    fn from_metadata(metadata: Metadata) -> TileDBResult<Vec<Self>> {
+        match metadata.value {
+            Value::StringUtf8(s) => Ok(vec![s]),
+            Value::UInt8Value(bytes)
+                if matches!(
+                    metadata.datatype,
+                    Datatype::StringAscii | Datatype::StringUtf8
+                ) =>
+            {
+                let s = String::from_utf8(bytes).map_err(|e| {
+                    Error::Deserialization(
+                        format!("metadata '{}'", metadata.key),
+                        anyhow!(e),
+                    )
+                })?;
+                Ok(vec![s])
+            }
+            _ => Err(DatatypeError::physical_type_incompatible::<String>(
+                metadata.datatype,
+            ))?,
+        }
     }
 }
@@ -1,18 +1,18 @@
-use std::num::TryFromIntError;
 use std::sync::Arc;
 
 use arrow::array::{
     Array as ArrowArray, FixedSizeListArray, GenericListArray,
     LargeBinaryArray, PrimitiveArray,
 };
-use arrow::buffer::{NullBuffer, OffsetBuffer, ScalarBuffer};
+use arrow::buffer::{BooleanBuffer, NullBuffer, OffsetBuffer, ScalarBuffer};
 use arrow::datatypes::{ArrowNativeType, Field};
 
 use crate::array::CellValNum;
 use crate::datatype::arrow::ArrowPrimitiveTypeNative;
 use crate::query::buffer::{
-    Buffer, BufferMut, QueryBuffers, QueryBuffersCellStructureFixed,
-    QueryBuffersCellStructureSingle, QueryBuffersCellStructureVar,
+    pack_validity, unpack_validity, Buffer, BufferMut, QueryBuffers,
+    QueryBuffersCellStructureFixed, QueryBuffersCellStructureSingle,
+    QueryBuffersCellStructureVar,
 };
 
 type PrimitiveArrayAlias<C> =
@@ -34,6 +34,11 @@ impl<B> From<(usize, B)> for Celled<B> {
     }
 }
 
+/// Converts the first `ncells` values of a buffer into an arrow buffer.
+///
+/// An owned buffer is moved into the arrow buffer without copying.
+/// A borrowed buffer must be copied since arrow buffers are not
+/// bound by a lifetime.
 impl<C> From<Celled<Buffer<'_, C>>> for ScalarBuffer<C>
 where
     C: ArrowNativeType,
@@ -74,34 +79,33 @@ where
     }
 }
 
+/// Converts the first `ncells` values of a validity buffer
+/// into an arrow null buffer.
+///
+/// tiledb uses one byte per value and arrow uses one bit per value,
+/// so the validity is always repacked into a new allocation.
 impl From<Celled<Buffer<'_, u8>>> for NullBuffer {
     fn from(value: Celled<Buffer<'_, u8>>) -> Self {
         let Celled(ncells, validity) = value;
 
-        let validity = match validity {
-            Buffer::Empty => vec![],
-            Buffer::Owned(v) => {
-                let mut v = v.into_vec();
-                v.truncate(ncells);
-                v
-            }
-            Buffer::Borrowed(v) => v[0..ncells].to_vec(),
-        };
-        validity
-            .into_iter()
-            .map(|v| v != 0)
-            .collect::<arrow::buffer::NullBuffer>()
+        let validity = &validity[0..std::cmp::min(ncells, validity.len())];
+        NullBuffer::new(BooleanBuffer::new(
+            pack_validity(validity).into(),
+            0,
+            validity.len(),
+        ))
     }
 }
 
 impl From<&NullBuffer> for Buffer<'_, u8> {
     fn from(value: &NullBuffer) -> Self {
-        value
-            .iter()
-            .map(|b| if b { 1 } else { 0 })
-            .collect::<Vec<u8>>()
-            .into_boxed_slice()
-            .into()
+        let bits = value.inner();
+        let validity = if bits.offset() % 8 == 0 {
+            unpack_validity(&bits.values()[bits.offset() / 8..], bits.len())
+        } else {
+            value.iter().map(u8::from).collect::<Vec<u8>>()
+        };
+        validity.into_boxed_slice().into()
     }
 }
 
@@ -260,12 +264,17 @@ where
                 }
             };
 
-            // convert u64 byte offsets to i64 element offsets
-            let offsets = offsets
-                .into_iter()
-                .map(i64::try_from)
-                .collect::<Result<Vec<i64>, TryFromIntError>>()?;
-            OffsetBuffer::<i64>::new(ScalarBuffer::<i64>::from(offsets))
+            // u64 offsets which fit in i64 have the same representation,
+            // so once they are checked the allocation can be re-used as-is
+            for offset in offsets.iter() {
+                i64::try_from(*offset)?;
+            }
+            let noffsets = offsets.len();
+            OffsetBuffer::<i64>::new(ScalarBuffer::<i64>::new(
+                arrow::buffer::Buffer::from_vec(offsets),
+                0,
+                noffsets,
+            ))
         };
 
         let values = ScalarBuffer::<C>::from(Celled(
@@ -6,6 +6,9 @@ use crate::array::CellValNum;
 
 #[cfg(feature = "arrow")]
 pub mod arrow;
+pub mod validity;
+
+pub use self::validity::{pack_validity, unpack_validity};
 
 #[derive(Debug)]
 pub enum Buffer<'data, T = u8> {
@@ -520,6 +523,16 @@ impl<'data> TypedQueryBuffers<'data> {
         crate::typed_query_buffers_go!(self, _DT, ref qb, qb.validity.as_ref())
     }
 
+    /// Returns whether `other` has the same physical type, cell structure,
+    /// and nullability as `self`, i.e. whether it may be used in place of
+    /// `self` as the buffers for the same field.
+    pub fn is_compatible(&self, other: &TypedQueryBuffers) -> bool {
+        std::mem::discriminant(self) == std::mem::discriminant(other)
+            && self.cell_structure().as_cell_val_num()
+                == other.cell_structure().as_cell_val_num()
+            && self.validity().is_some() == other.validity().is_some()
+    }
+
     pub fn borrow<'this>(&'this self) -> TypedQueryBuffers<'data>
     where
         'this: 'data,
@@ -0,0 +1,110 @@
+//! Conversions between the validity representations of tiledb and arrow.
+//!
+//! tiledb validity buffers use one byte per value, where zero means null
+//! and any other value means valid. Arrow validity bitmaps use one bit
+//! per value, least significant bit first, where a set bit means valid.
+
+/// Packs a byte-per-value validity buffer into a bit-per-value bitmap.
+///
+/// The returned bitmap has `bytes.len().div_ceil(8)` bytes. Bits beyond
+/// `bytes.len()` in the final byte are zero.
+pub fn pack_validity(bytes: &[u8]) -> Vec<u8> {
+    let mut bits = vec![0u8; bytes.len().div_ceil(8)];
+    for (i, v) in bytes.iter().enumerate() {
+        if *v != 0 {
+            bits[i / 8] |= 1 << (i % 8);
+        }
+    }
+    bits
+}
+
+/// Unpacks the first `len` values of a bit-per-value bitmap into a
+/// byte-per-value validity buffer containing `0` or `1` for each value.
+///
+/// # Panics
+///
+/// Panics if `bits` has fewer than `len` bits.
+pub fn unpack_validity(bits: &[u8], len: usize) -> Vec<u8> {
+    assert!(
+        len <= bits.len() * 8,
+        "Validity bitmap of {} bytes cannot hold {} values",
+        bits.len(),
+        len
+    );
+    (0..len).map(|i| (bits[i / 8] >> (i % 8)) & 1).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn extremes() {
+        for len in [0, 1, 7, 8, 9, 15, 16, 17, 64, 100] {
+            let valid = vec![1u8; len];
+            let packed = pack_validity(&valid);
+            assert_eq!(len.div_ceil(8), packed.len());
+            assert_eq!(
+                len,
+                packed
+                    .iter()
+                    .map(|b| b.count_ones() as usize)
+                    .sum::<usize>()
+            );
+            assert_eq!(valid, unpack_validity(&packed, len));
+
+            let null = vec![0u8; len];
+            let packed = pack_validity(&null);
+            assert_eq!(vec![0u8; len.div_ceil(8)], packed);
+            assert_eq!(null, unpack_validity(&packed, len));
+        }
+    }
+
+    #[test]
+    fn bit_order() {
+        assert_eq!(vec![0b0000_0101], pack_validity(&[1, 0, 1]));
+        assert_eq!(
+            vec![0b1000_0000, 0b0000_0001],
+            pack_validity(&[0, 0, 0, 0, 0, 0, 0, 1, 1])
+        );
+        assert_eq!(vec![1, 0, 1, 0], unpack_validity(&[0b1111_0101], 4));
+    }
+
+    #[test]
+    #[should_panic]
+    fn unpack_too_short() {
+        unpack_validity(&[0xFF], 9);
+    }
+
+    proptest! {
+        #[test]
+        fn pack_unpack(bytes in proptest::collection::vec(any::<u8>(), 0..=256)) {
+            let packed = pack_validity(&bytes);
+            assert_eq!(bytes.len().div_ceil(8), packed.len());
+
+            let expect = bytes.iter().map(|v| u8::from(*v != 0)).collect::<Vec<u8>>();
+            assert_eq!(expect, unpack_validity(&packed, bytes.len()));
+        }
+
+        #[test]
+        fn unpack_pack(bits in proptest::collection::vec(any::<u8>(), 0..=32), len in 0..=256usize) {
+            let len = std::cmp::min(len, bits.len() * 8);
+            let unpacked = unpack_validity(&bits, len);
+            assert!(unpacked.iter().all(|v| *v <= 1));
+
+            let repacked = pack_validity(&unpacked);
+            for i in 0..len {
+                assert_eq!(
+                    (bits[i / 8] >> (i % 8)) & 1,
+                    (repacked[i / 8] >> (i % 8)) & 1
+                );
+            }
+            // trailing bits are cleared
+            if len % 8 != 0 {
+                assert_eq!(0, repacked[len / 8] >> (len % 8));
+            }
+        }
+    }
+}
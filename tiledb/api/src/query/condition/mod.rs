@@ -1,6 +1,8 @@
+mod parse;
 #[cfg(feature = "proptest-strategies")]
 pub mod strategy;
 
+use std::cmp::Ordering;
 use std::fmt::{Display, Formatter, Result as FmtResult};
 use std::hash::{Hash, Hasher};
 use std::ops::{BitAnd, BitOr, Deref, Not};
@@ -10,11 +12,17 @@ use anyhow::anyhow;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::array::schema::Field as SchemaField;
+use crate::array::{Array, CellValNum, Domain, Schema};
 use crate::context::Context;
 use crate::datatype::physical::{BitsEq, BitsHash};
+use crate::datatype::Datatype;
 use crate::error::Error;
+use crate::physical_type_go;
 use crate::Result as TileDBResult;
 
+pub use self::parse::{ParseError, QueryConditionSchema};
+
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 pub enum EqualityOp {
@@ -27,6 +35,15 @@ pub enum EqualityOp {
 }
 
 impl EqualityOp {
+    /// Returns whether this operator compares the order of values
+    /// rather than only whether they are equal.
+    pub fn is_relational(&self) -> bool {
+        matches!(
+            self,
+            Self::Less | Self::LessEqual | Self::GreaterEqual | Self::Greater
+        )
+    }
+
     pub(crate) fn capi_enum(&self) -> ffi::tiledb_query_condition_op_t {
         match self {
             Self::Less => ffi::tiledb_query_condition_op_t_TILEDB_LT,
@@ -148,7 +165,13 @@ pub enum Literal {
 }
 
 impl Literal {
-    fn to_bytes(&self) -> Vec<u8> {
+    /// Returns the raw bytes of this value, as stored in a cell.
+    ///
+    /// For a value of an enumerated attribute, [Enumeration::index_of]
+    /// finds the key of the value.
+    ///
+    /// [Enumeration::index_of]: crate::array::Enumeration::index_of
+    pub fn to_bytes(&self) -> Vec<u8> {
         match self {
             Self::UInt8(val) => val.to_le_bytes().to_vec(),
             Self::UInt16(val) => val.to_le_bytes().to_vec(),
@@ -163,6 +186,127 @@ impl Literal {
             Self::String(val) => val.as_bytes().to_vec(),
         }
     }
+
+    /// Returns the literal whose raw bytes are `bytes`, for a cell of a field
+    /// with `datatype` and `cell_val_num`. This is the inverse of
+    /// [Literal::to_bytes].
+    ///
+    /// A var-sized cell is a [Literal::String], and a fixed-size cell is a
+    /// literal of the physical type of `datatype`. Returns `None` if
+    /// a var-sized cell is not valid UTF-8, if a fixed-size cell has more than
+    /// one value, or if `bytes` is not the size of a single value.
+    pub fn from_bytes(
+        datatype: Datatype,
+        cell_val_num: CellValNum,
+        bytes: &[u8],
+    ) -> Option<Self> {
+        match cell_val_num {
+            CellValNum::Var => {
+                String::from_utf8(bytes.to_vec()).ok().map(Self::String)
+            }
+            CellValNum::Fixed(nz) if nz.get() == 1 => {
+                physical_type_go!(datatype, DT, {
+                    let bytes =
+                        <[u8; std::mem::size_of::<DT>()]>::try_from(bytes)
+                            .ok()?;
+                    Some(Self::from(DT::from_le_bytes(bytes)))
+                })
+            }
+            CellValNum::Fixed(_) => None,
+        }
+    }
+
+    /// Returns a literal of the physical type of `datatype` with value `value`.
+    ///
+    /// Returns `Error::InvalidArgument` if `datatype` is not an integer type,
+    /// or if `value` is outside of the range of its physical type.
+    pub fn cast_integer(datatype: Datatype, value: i128) -> TileDBResult<Self> {
+        fn cast<T>(value: i128) -> Option<Literal>
+        where
+            T: TryFrom<i128>,
+            Literal: From<T>,
+        {
+            T::try_from(value).ok().map(Literal::from)
+        }
+
+        if !is_integer_datatype(datatype) {
+            return Err(Error::InvalidArgument(anyhow!(
+                "Cannot convert {} to non-integer datatype {}",
+                value,
+                datatype
+            )));
+        }
+
+        let literal = if datatype.is_compatible_type::<u8>() {
+            cast::<u8>(value)
+        } else if datatype.is_compatible_type::<u16>() {
+            cast::<u16>(value)
+        } else if datatype.is_compatible_type::<u32>() {
+            cast::<u32>(value)
+        } else if datatype.is_compatible_type::<u64>() {
+            cast::<u64>(value)
+        } else if datatype.is_compatible_type::<i8>() {
+            cast::<i8>(value)
+        } else if datatype.is_compatible_type::<i16>() {
+            cast::<i16>(value)
+        } else if datatype.is_compatible_type::<i32>() {
+            cast::<i32>(value)
+        } else {
+            cast::<i64>(value)
+        };
+        literal.ok_or_else(|| {
+            Error::InvalidArgument(anyhow!(
+                "Value {} is out of range for datatype {}",
+                value,
+                datatype
+            ))
+        })
+    }
+
+    /// Returns the value of this literal if it is an integer.
+    fn to_i128(&self) -> Option<i128> {
+        match self {
+            Self::UInt8(val) => Some(*val as i128),
+            Self::UInt16(val) => Some(*val as i128),
+            Self::UInt32(val) => Some(*val as i128),
+            Self::UInt64(val) => Some(*val as i128),
+            Self::Int8(val) => Some(*val as i128),
+            Self::Int16(val) => Some(*val as i128),
+            Self::Int32(val) => Some(*val as i128),
+            Self::Int64(val) => Some(*val as i128),
+            Self::Float32(_) | Self::Float64(_) | Self::String(_) => None,
+        }
+    }
+
+    /// Compares the values of two literals of the same type.
+    /// Returns `None` if the literals have different types
+    /// or if either is a floating-point NaN.
+    fn compare(&self, other: &Self) -> Option<Ordering> {
+        use self::Literal::*;
+        match (self, other) {
+            (UInt8(mine), UInt8(theirs)) => mine.partial_cmp(theirs),
+            (UInt16(mine), UInt16(theirs)) => mine.partial_cmp(theirs),
+            (UInt32(mine), UInt32(theirs)) => mine.partial_cmp(theirs),
+            (UInt64(mine), UInt64(theirs)) => mine.partial_cmp(theirs),
+            (Int8(mine), Int8(theirs)) => mine.partial_cmp(theirs),
+            (Int16(mine), Int16(theirs)) => mine.partial_cmp(theirs),
+            (Int32(mine), Int32(theirs)) => mine.partial_cmp(theirs),
+            (Int64(mine), Int64(theirs)) => mine.partial_cmp(theirs),
+            (Float32(mine), Float32(theirs)) => mine.partial_cmp(theirs),
+            (Float64(mine), Float64(theirs)) => mine.partial_cmp(theirs),
+            (String(mine), String(theirs)) => mine.partial_cmp(theirs),
+            _ => None,
+        }
+    }
+}
+
+/// Returns whether the values of `datatype` are integers,
+/// i.e. whether an integer literal can be converted to it.
+fn is_integer_datatype(datatype: Datatype) -> bool {
+    datatype.is_integral_type()
+        || datatype.is_datetime_type()
+        || datatype.is_time_type()
+        || datatype == Datatype::Char
 }
 
 impl Display for Literal {
@@ -341,6 +485,76 @@ impl SetMembers {
         }
     }
 
+    /// Returns the values of these members if they are integers.
+    fn to_i128(&self) -> Option<Vec<i128>> {
+        fn widen<T>(members: &[T]) -> Option<Vec<i128>>
+        where
+            T: Copy + Into<i128>,
+        {
+            Some(members.iter().map(|value| (*value).into()).collect())
+        }
+
+        match self {
+            Self::UInt8(val) => widen(val),
+            Self::UInt16(val) => widen(val),
+            Self::UInt32(val) => widen(val),
+            Self::UInt64(val) => widen(val),
+            Self::Int8(val) => widen(val),
+            Self::Int16(val) => widen(val),
+            Self::Int32(val) => widen(val),
+            Self::Int64(val) => widen(val),
+            Self::Float32(_) | Self::Float64(_) | Self::String(_) => None,
+        }
+    }
+
+    /// Returns members of the physical type of `datatype` with `values`.
+    ///
+    /// Returns `Error::InvalidArgument` if `datatype` is not an integer type,
+    /// or if any value is outside of the range of its physical type.
+    fn cast_integer(datatype: Datatype, values: &[i128]) -> TileDBResult<Self> {
+        fn cast<T>(values: &[i128]) -> Result<Vec<T>, i128>
+        where
+            T: TryFrom<i128>,
+        {
+            values
+                .iter()
+                .map(|value| T::try_from(*value).map_err(|_| *value))
+                .collect()
+        }
+
+        if !is_integer_datatype(datatype) {
+            return Err(Error::InvalidArgument(anyhow!(
+                "Cannot convert set members to non-integer datatype {}",
+                datatype
+            )));
+        }
+
+        let members = if datatype.is_compatible_type::<u8>() {
+            cast::<u8>(values).map(Self::UInt8)
+        } else if datatype.is_compatible_type::<u16>() {
+            cast::<u16>(values).map(Self::UInt16)
+        } else if datatype.is_compatible_type::<u32>() {
+            cast::<u32>(values).map(Self::UInt32)
+        } else if datatype.is_compatible_type::<u64>() {
+            cast::<u64>(values).map(Self::UInt64)
+        } else if datatype.is_compatible_type::<i8>() {
+            cast::<i8>(values).map(Self::Int8)
+        } else if datatype.is_compatible_type::<i16>() {
+            cast::<i16>(values).map(Self::Int16)
+        } else if datatype.is_compatible_type::<i32>() {
+            cast::<i32>(values).map(Self::Int32)
+        } else {
+            cast::<i64>(values).map(Self::Int64)
+        };
+        members.map_err(|value| {
+            Error::InvalidArgument(anyhow!(
+                "Value {} is out of range for datatype {}",
+                value,
+                datatype
+            ))
+        })
+    }
+
     fn display<T>(f: &mut Formatter, members: &[T]) -> FmtResult
     where
         T: Display,
@@ -474,6 +688,20 @@ pub struct EqualityPredicate {
 }
 
 impl EqualityPredicate {
+    /// Returns the inclusive bounds of the integers which satisfy this
+    /// predicate, or `None` if they are not a single range.
+    fn integer_bounds(&self) -> Option<(i128, i128)> {
+        let value = self.value.to_i128()?;
+        match self.op {
+            EqualityOp::Less => Some((i128::MIN, value - 1)),
+            EqualityOp::LessEqual => Some((i128::MIN, value)),
+            EqualityOp::Equal => Some((value, value)),
+            EqualityOp::NotEqual => None,
+            EqualityOp::GreaterEqual => Some((value, i128::MAX)),
+            EqualityOp::Greater => Some((value + 1, i128::MAX)),
+        }
+    }
+
     fn build(&self, ctx: &Context) -> TileDBResult<RawQueryCondition> {
         let mut c_cond: *mut ffi::tiledb_query_condition_t = out_ptr!();
         ctx.capi_call(|ctx| unsafe {
@@ -670,6 +898,15 @@ pub enum Predicate {
 }
 
 impl Predicate {
+    /// Returns the name of the field which this predicate applies to.
+    fn field(&self) -> &str {
+        match self {
+            Self::Equality(pred) => &pred.field,
+            Self::SetMembership(pred) => &pred.field,
+            Self::Nullness(pred) => &pred.field,
+        }
+    }
+
     fn build(&self, ctx: &Context) -> TileDBResult<RawQueryCondition> {
         match self {
             Self::Equality(pred) => pred.build(ctx),
@@ -744,6 +981,39 @@ impl Field {
         }))
     }
 
+    /// Returns a condition which is satisfied by values of the field
+    /// which are greater than or equal to `lo` and less than or equal to `hi`.
+    ///
+    /// This is equivalent to `field.ge(lo) & field.le(hi)`.
+    /// Returns `Error::InvalidArgument` if `lo` and `hi` cannot be
+    /// compared, or if `lo` is greater than `hi`.
+    pub fn between<V: Into<Literal>>(
+        self,
+        lo: V,
+        hi: V,
+    ) -> TileDBResult<QueryConditionExpr> {
+        let (lo, hi) = (lo.into(), hi.into());
+        match lo.compare(&hi) {
+            Some(Ordering::Less | Ordering::Equal) => {
+                Ok(self.clone().ge(lo) & self.le(hi))
+            }
+            Some(Ordering::Greater) => Err(Error::InvalidArgument(anyhow!(
+                "Invalid range for field '{}': lower bound {} is greater \
+                 than upper bound {}",
+                self.field,
+                lo,
+                hi
+            ))),
+            None => Err(Error::InvalidArgument(anyhow!(
+                "Invalid range for field '{}': bounds {} and {} \
+                 cannot be compared",
+                self.field,
+                lo,
+                hi
+            ))),
+        }
+    }
+
     pub fn is_in<V: Into<SetMembers>>(self, value: V) -> QueryConditionExpr {
         QueryConditionExpr::Cond(Predicate::SetMembership(
             SetMembershipPredicate {
@@ -798,6 +1068,203 @@ impl QueryConditionExpr {
         }
     }
 
+    /// Returns an error if this condition applies a relational operator
+    /// to an attribute of `array` whose enumeration is not ordered.
+    ///
+    /// The variants of an unordered enumeration are a set, so only
+    /// equality and set membership are meaningful for its values.
+    pub(crate) fn check_enumerations(&self, array: &Array) -> TileDBResult<()> {
+        match self {
+            Self::Cond(Predicate::Equality(pred)) => {
+                if !pred.op.is_relational() {
+                    return Ok(());
+                }
+                let schema = array.schema()?;
+                let Ok(SchemaField::Attribute(attr)) =
+                    schema.field(pred.field.as_str())
+                else {
+                    return Ok(());
+                };
+                let Some(enumeration) = attr.enumeration_name()? else {
+                    return Ok(());
+                };
+                if array.get_enumeration(&enumeration)?.ordered()? {
+                    Ok(())
+                } else {
+                    Err(Error::InvalidArgument(anyhow!(
+                        "Cannot apply '{}' to field '{}' because its \
+                         enumeration '{}' is not ordered",
+                        pred.op,
+                        pred.field,
+                        enumeration
+                    )))
+                }
+            }
+            Self::Cond(_) => Ok(()),
+            Self::Comb { lhs, rhs, .. } => {
+                lhs.check_enumerations(array)?;
+                rhs.check_enumerations(array)
+            }
+            Self::Negate(expr) => expr.check_enumerations(array),
+        }
+    }
+
+    /// Converts each integer literal and set of integer members of this
+    /// condition which is compared with an integer field of `schema`
+    /// to the physical type of that field.
+    ///
+    /// Returns `Error::InvalidArgument` if a literal is outside of the range
+    /// of the type of its field, rather than letting it be truncated.
+    /// Literals compared with attributes which have an enumeration are not
+    /// converted, as those compare with the enumeration values instead.
+    pub(crate) fn cast_integers(self, schema: &Schema) -> TileDBResult<Self> {
+        /// Returns the datatype of `field` if its literals should be cast.
+        fn integer_datatype(
+            schema: &Schema,
+            field: &str,
+        ) -> TileDBResult<Option<Datatype>> {
+            let Ok(field) = schema.field(field) else {
+                return Ok(None);
+            };
+            if let SchemaField::Attribute(ref attr) = field {
+                if attr.enumeration_name()?.is_some() {
+                    return Ok(None);
+                }
+            }
+            let datatype = field.datatype()?;
+            Ok(Some(datatype).filter(|dt| is_integer_datatype(*dt)))
+        }
+
+        match self {
+            Self::Cond(Predicate::Equality(mut pred)) => {
+                let Some(value) = pred.value.to_i128() else {
+                    return Ok(Self::Cond(Predicate::Equality(pred)));
+                };
+                if let Some(datatype) =
+                    integer_datatype(schema, pred.field.as_str())?
+                {
+                    pred.value = Literal::cast_integer(datatype, value)
+                        .map_err(|e| {
+                            Error::InvalidArgument(anyhow!(
+                                "Cannot apply '{}': {}",
+                                pred,
+                                e
+                            ))
+                        })?;
+                }
+                Ok(Self::Cond(Predicate::Equality(pred)))
+            }
+            Self::Cond(Predicate::SetMembership(mut pred)) => {
+                let Some(values) = pred.members.to_i128() else {
+                    return Ok(Self::Cond(Predicate::SetMembership(pred)));
+                };
+                if let Some(datatype) =
+                    integer_datatype(schema, pred.field.as_str())?
+                {
+                    pred.members = SetMembers::cast_integer(datatype, &values)
+                        .map_err(|e| {
+                            Error::InvalidArgument(anyhow!(
+                                "Cannot apply '{}': {}",
+                                pred,
+                                e
+                            ))
+                        })?;
+                }
+                Ok(Self::Cond(Predicate::SetMembership(pred)))
+            }
+            Self::Cond(_) => Ok(self),
+            Self::Comb { lhs, rhs, op } => Ok(Self::Comb {
+                lhs: Box::new(lhs.cast_integers(schema)?),
+                rhs: Box::new(rhs.cast_integers(schema)?),
+                op,
+            }),
+            Self::Negate(expr) => {
+                Ok(Self::Negate(Box::new(expr.cast_integers(schema)?)))
+            }
+        }
+    }
+
+    /// Returns whether any predicate of this condition applies to `field`.
+    fn has_field(&self, field: &str) -> bool {
+        match self {
+            Self::Cond(pred) => pred.field() == field,
+            Self::Comb { lhs, rhs, .. } => {
+                lhs.has_field(field) || rhs.has_field(field)
+            }
+            Self::Negate(expr) => expr.has_field(field),
+        }
+    }
+
+    /// Returns the terms of this condition which are combined using `AND`.
+    fn into_conjuncts(self) -> Vec<Self> {
+        match self {
+            Self::Comb {
+                lhs,
+                rhs,
+                op: CombinationOp::And,
+            } => {
+                let mut conjuncts = lhs.into_conjuncts();
+                conjuncts.extend(rhs.into_conjuncts());
+                conjuncts
+            }
+            _ => vec![self],
+        }
+    }
+
+    /// Separates this condition into bounds on the dimensions of `domain`
+    /// and a condition on the remaining fields.
+    ///
+    /// Dense arrays do not support query conditions on dimensions.
+    /// However, a term of the condition which compares a dimension
+    /// with an integer and which is combined with the rest of the condition
+    /// using `AND` selects cells in the same way as a subarray range.
+    /// Each such term is returned as the index of its dimension together
+    /// with the inclusive bounds of the values which satisfy it.
+    ///
+    /// Returns `Error::InvalidArgument` if any other term of the condition
+    /// applies to a dimension.
+    #[allow(clippy::type_complexity)]
+    pub(crate) fn split_dimension_bounds(
+        self,
+        domain: &Domain,
+    ) -> TileDBResult<(Vec<(usize, i128, i128)>, Option<Self>)> {
+        let dimension_names = (0..domain.num_dimensions()?)
+            .map(|d| domain.dimension(d)?.name())
+            .collect::<TileDBResult<Vec<String>>>()?;
+
+        let mut bounds = vec![];
+        let mut rest: Option<Self> = None;
+        for conjunct in self.into_conjuncts() {
+            let Some(d) =
+                dimension_names.iter().position(|d| conjunct.has_field(d))
+            else {
+                rest = Some(match rest {
+                    Some(rest) => rest & conjunct,
+                    None => conjunct,
+                });
+                continue;
+            };
+            let dimension_bounds = match conjunct {
+                Self::Cond(Predicate::Equality(ref pred)) => {
+                    pred.integer_bounds()
+                }
+                _ => None,
+            };
+            let Some((lo, hi)) = dimension_bounds else {
+                return Err(Error::InvalidArgument(anyhow!(
+                    "Cannot apply '{}' to a dense array: conditions on \
+                     dimension '{}' must compare it with an integer using \
+                     one of <, <=, =, >=, > and be combined with the rest \
+                     of the condition using AND",
+                    conjunct,
+                    dimension_names[d]
+                )));
+            };
+            bounds.push((d, lo, hi));
+        }
+        Ok((bounds, rest))
+    }
+
     pub(crate) fn build(
         &self,
         ctx: &Context,
@@ -914,6 +1381,453 @@ mod tests {
     use super::QueryConditionExpr as QC;
     use super::*;
 
+    /// Test that relational operators are only allowed on
+    /// attributes whose enumeration is ordered.
+    #[test]
+    fn enumeration_ordered() -> TileDBResult<()> {
+        use tiledb_pod::array::enumeration::EnumerationData;
+
+        use crate::query::{QueryBuilder, ReadBuilder};
+        use crate::tests::examples::quickstart;
+        use crate::tests::prelude::*;
+
+        let enumeration = |name: &str, ordered: bool| EnumerationData {
+            name: name.to_owned(),
+            datatype: Datatype::StringAscii,
+            cell_val_num: Some(CellValNum::Var),
+            ordered: Some(ordered),
+            data: "smallmediumlarge".as_bytes().into(),
+            offsets: Some(vec![0u64, 5, 11].into_boxed_slice()),
+        };
+        let attribute = |name: &str, enumeration: &str| AttributeData {
+            name: name.to_owned(),
+            datatype: Datatype::UInt8,
+            enumeration: Some(enumeration.to_owned()),
+            ..Default::default()
+        };
+
+        let array = TestArray::new("enumeration_ordered", {
+            let mut schema =
+                quickstart::Builder::new(ArrayType::Sparse).build();
+            schema.enumerations.push(enumeration("sizes", true));
+            schema.enumerations.push(enumeration("labels", false));
+            schema.attributes.push(attribute("size", "sizes"));
+            schema.attributes.push(attribute("label", "labels"));
+            std::rc::Rc::new(schema)
+        })?;
+
+        let with_condition = |qc: QC| {
+            ReadBuilder::new(array.for_read()?)?
+                .query_condition(qc)
+                .map(|_| ())
+        };
+
+        assert!(with_condition(QC::field("size").lt("medium")).is_ok());
+        assert!(with_condition(QC::field("label").eq("medium")).is_ok());
+        assert!(matches!(
+            with_condition(QC::field("label").lt("medium")),
+            Err(Error::InvalidArgument(_))
+        ));
+        assert!(matches!(
+            with_condition(
+                QC::field("size").lt("medium")
+                    & !QC::field("label").ge("medium")
+            ),
+            Err(Error::InvalidArgument(_))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn between() {
+        assert_eq!(
+            QC::field("x").ge(1) & QC::field("x").le(5),
+            QC::field("x").between(1, 5).unwrap()
+        );
+        assert_eq!(
+            QC::field("x").ge(1) & QC::field("x").le(1),
+            QC::field("x").between(1, 1).unwrap()
+        );
+        assert_eq!(
+            QC::field("s").ge("bar") & QC::field("s").le("foo"),
+            QC::field("s").between("bar", "foo").unwrap()
+        );
+
+        assert!(matches!(
+            QC::field("x").between(5, 1),
+            Err(Error::InvalidArgument(_))
+        ));
+        assert!(matches!(
+            QC::field("x").between(Literal::Int32(1), Literal::UInt32(5)),
+            Err(Error::InvalidArgument(_))
+        ));
+        assert!(matches!(
+            QC::field("x").between(f64::NAN, 1.0),
+            Err(Error::InvalidArgument(_))
+        ));
+    }
+
+    #[test]
+    fn literal_from_bytes() {
+        let single = CellValNum::single();
+        for literal in [
+            Literal::UInt8(7),
+            Literal::Int16(-300),
+            Literal::UInt64(u64::MAX),
+            Literal::Float64(1.5),
+        ] {
+            let datatype = match literal {
+                Literal::UInt8(_) => Datatype::UInt8,
+                Literal::Int16(_) => Datatype::Int16,
+                Literal::UInt64(_) => Datatype::UInt64,
+                _ => Datatype::Float64,
+            };
+            assert_eq!(
+                Some(literal.clone()),
+                Literal::from_bytes(datatype, single, &literal.to_bytes())
+            );
+        }
+
+        assert_eq!(
+            Some(Literal::from("wilma")),
+            Literal::from_bytes(
+                Datatype::StringUtf8,
+                CellValNum::Var,
+                "wilma".as_bytes()
+            )
+        );
+        assert_eq!(
+            None,
+            Literal::from_bytes(Datatype::StringUtf8, CellValNum::Var, &[0xff])
+        );
+        assert_eq!(None, Literal::from_bytes(Datatype::Int32, single, &[1, 2]));
+        assert_eq!(
+            None,
+            Literal::from_bytes(
+                Datatype::Int32,
+                CellValNum::try_from(2).unwrap(),
+                &[0; 8]
+            )
+        );
+    }
+
+    #[test]
+    fn cast_integer() {
+        fn check<T>(datatype: Datatype, min: T, max: T)
+        where
+            T: Into<i128> + Into<Literal> + Copy,
+        {
+            let (lo, hi): (i128, i128) = (min.into(), max.into());
+            assert_eq!(
+                Into::<Literal>::into(min),
+                Literal::cast_integer(datatype, lo).unwrap()
+            );
+            assert_eq!(
+                Into::<Literal>::into(max),
+                Literal::cast_integer(datatype, hi).unwrap()
+            );
+            assert!(matches!(
+                Literal::cast_integer(datatype, lo - 1),
+                Err(Error::InvalidArgument(_))
+            ));
+            assert!(matches!(
+                Literal::cast_integer(datatype, hi + 1),
+                Err(Error::InvalidArgument(_))
+            ));
+        }
+
+        check(Datatype::UInt8, u8::MIN, u8::MAX);
+        check(Datatype::UInt16, u16::MIN, u16::MAX);
+        check(Datatype::UInt32, u32::MIN, u32::MAX);
+        check(Datatype::UInt64, u64::MIN, u64::MAX);
+        check(Datatype::Int8, i8::MIN, i8::MAX);
+        check(Datatype::Int16, i16::MIN, i16::MAX);
+        check(Datatype::Int32, i32::MIN, i32::MAX);
+        check(Datatype::Int64, i64::MIN, i64::MAX);
+        check(Datatype::Boolean, u8::MIN, u8::MAX);
+        check(Datatype::Char, i8::MIN, i8::MAX);
+        check(Datatype::DateTimeSecond, i64::MIN, i64::MAX);
+
+        for datatype in [
+            Datatype::Float32,
+            Datatype::Float64,
+            Datatype::StringAscii,
+            Datatype::Blob,
+        ] {
+            assert!(matches!(
+                Literal::cast_integer(datatype, 0),
+                Err(Error::InvalidArgument(_))
+            ));
+        }
+    }
+
+    /// Test that integer literals are converted to the type of their field
+    /// when the condition is added to a query.
+    #[test]
+    fn cast_integers_read() -> TileDBResult<()> {
+        use crate::query::{
+            QueryBuilder, ReadBuilder, ReadQuery, ReadQueryBuilder,
+            WriteBuilder,
+        };
+        use crate::tests::examples::quickstart;
+        use crate::tests::prelude::*;
+
+        let mut array = TestArray::new("cast_integers_read", {
+            let mut b = quickstart::Builder::new(ArrayType::Sparse);
+            b.attribute().datatype = Datatype::UInt8;
+            std::rc::Rc::new(b.build())
+        })?;
+        {
+            let rows = vec![1, 2, 3, 4];
+            let cols = vec![1, 2, 3, 4];
+            let atts = vec![0u8, 100, 200, 255];
+            let w = WriteBuilder::new(array.for_write()?)?
+                .data_typed("rows", &rows)?
+                .data_typed("cols", &cols)?
+                .data_typed("a", &atts)?
+                .build();
+            w.submit()?;
+            w.finalize()?;
+        }
+
+        let read = |qc: QC| -> TileDBResult<Vec<u8>> {
+            let mut q = ReadBuilder::new(array.for_read()?)?
+                .register_constructor::<_, Vec<u8>>("a", Default::default())?
+                .query_condition(qc)?
+                .build();
+            Ok(q.execute()?.0)
+        };
+
+        assert_eq!(vec![200, 255], read(QC::field("a").ge(150i32))?);
+        assert_eq!(vec![0, 100], read(QC::field("a").lt(200i64))?);
+        assert_eq!(
+            vec![255],
+            read(QC::field("rows").ge(2u64) & QC::field("a").eq(255u32))?
+        );
+
+        assert!(matches!(
+            read(QC::field("a").lt(256)),
+            Err(Error::InvalidArgument(_))
+        ));
+        assert!(matches!(
+            read(QC::field("a").ge(-1)),
+            Err(Error::InvalidArgument(_))
+        ));
+
+        // set members are converted too
+        assert_eq!(
+            vec![100, 255],
+            read(QC::field("a").is_in(&[100i64, 255, 7][..]))?
+        );
+        assert_eq!(
+            vec![0, 200],
+            read(QC::field("a").not_in(&[100u32, 255][..]))?
+        );
+        assert!(matches!(
+            read(QC::field("a").is_in(&[100i32, 256][..])),
+            Err(Error::InvalidArgument(_))
+        ));
+
+        Ok(())
+    }
+
+    /// Test that reading with `between` produces the same results as
+    /// reading with the equivalent composition of predicates.
+    #[test]
+    fn between_read() -> TileDBResult<()> {
+        use proptest::prelude::*;
+
+        use crate::query::{
+            QueryBuilder, QueryLayout, ReadBuilder, ReadQuery,
+            ReadQueryBuilder, WriteBuilder,
+        };
+        use crate::tests::examples::quickstart;
+        use crate::tests::prelude::*;
+
+        let mut array = TestArray::new(
+            "between_read",
+            std::rc::Rc::new(
+                quickstart::Builder::new(ArrayType::Sparse).build(),
+            ),
+        )?;
+
+        {
+            let (mut rows, mut cols, mut atts) = (vec![], vec![], vec![]);
+            for r in 1..=4 {
+                for c in 1..=4 {
+                    rows.push(r);
+                    cols.push(c);
+                    atts.push((r - 1) * 4 + c);
+                }
+            }
+            let w = WriteBuilder::new(array.for_write()?)?
+                .data_typed("rows", &rows)?
+                .data_typed("cols", &cols)?
+                .data_typed("a", &atts)?
+                .build();
+            w.submit()?;
+            w.finalize()?;
+        }
+
+        let read = |qc: QC| -> TileDBResult<Vec<i32>> {
+            let mut q = ReadBuilder::new(array.for_read()?)?
+                .layout(QueryLayout::RowMajor)?
+                .query_condition(qc)?
+                .register_constructor::<_, Vec<i32>>("a", Default::default())?
+                .build();
+            Ok(q.execute()?.0)
+        };
+
+        proptest!(|(lo in 0..=18i32, hi in 0..=18i32)| {
+            let between = QC::field("a").between(lo, hi);
+            if lo > hi {
+                assert!(matches!(between, Err(Error::InvalidArgument(_))));
+            } else {
+                let expect =
+                    read(QC::field("a").ge(lo) & QC::field("a").le(hi))
+                        .expect("Error reading with composition");
+                let between = read(between.unwrap())
+                    .expect("Error reading with between");
+                assert_eq!(expect, between);
+                assert_eq!(
+                    (lo..=hi).filter(|a| (1..=16).contains(a)).count(),
+                    between.len()
+                );
+            }
+        });
+
+        Ok(())
+    }
+
+    /// Test conditions on the dimensions of sparse and dense arrays.
+    #[test]
+    fn dimension_condition() -> TileDBResult<()> {
+        use crate::query::{
+            QueryBuilder, QueryLayout, ReadBuilder, ReadQuery,
+            ReadQueryBuilder, WriteBuilder,
+        };
+        use crate::tests::examples::quickstart;
+        use crate::tests::prelude::*;
+
+        // the value of `a` in each cell is its position in row-major order
+        let atts = (1..=16).collect::<Vec<i32>>();
+
+        let mut sparse = TestArray::new(
+            "dimension_condition_sparse",
+            std::rc::Rc::new(
+                quickstart::Builder::new(ArrayType::Sparse).build(),
+            ),
+        )?;
+        {
+            let rows = (1..=4).flat_map(|r| [r; 4]).collect::<Vec<i32>>();
+            let cols = (1..=4).cycle().take(16).collect::<Vec<i32>>();
+            let w = WriteBuilder::new(sparse.for_write()?)?
+                .data_typed("rows", &rows)?
+                .data_typed("cols", &cols)?
+                .data_typed("a", &atts)?
+                .build();
+            w.submit()?;
+            w.finalize()?;
+        }
+
+        let mut dense = TestArray::new(
+            "dimension_condition_dense",
+            std::rc::Rc::new(
+                quickstart::Builder::new(ArrayType::Dense).build(),
+            ),
+        )?;
+        {
+            let w = WriteBuilder::new(dense.for_write()?)?
+                .layout(QueryLayout::RowMajor)?
+                .start_subarray()?
+                .add_range("rows", &[1, 4])?
+                .add_range("cols", &[1, 4])?
+                .finish_subarray()?
+                .data_typed("a", &atts)?
+                .build();
+            w.submit()?;
+            w.finalize()?;
+        }
+
+        let read = |array: &TestArray, qc: QC| -> TileDBResult<Vec<i32>> {
+            let mut q = ReadBuilder::new(array.for_read()?)?
+                .layout(QueryLayout::RowMajor)?
+                .query_condition(qc)?
+                .register_constructor::<_, Vec<i32>>("a", Default::default())?
+                .build();
+            Ok(q.execute()?.0)
+        };
+
+        assert_eq!(
+            (9..=16).collect::<Vec<i32>>(),
+            read(&sparse, QC::field("rows").ge(3))?
+        );
+        assert_eq!(
+            vec![6, 7, 10, 11],
+            read(
+                &sparse,
+                QC::field("rows").between(2, 3)?
+                    & QC::field("cols").between(2, 3)?
+            )?
+        );
+        assert_eq!(
+            vec![9, 10, 11],
+            read(&sparse, QC::field("rows").gt(2) & QC::field("a").lt(12))?
+        );
+        assert_eq!(
+            vec![1, 3, 6, 8, 9, 11, 14, 16],
+            read(
+                &sparse,
+                (QC::field("rows").eq(1) | QC::field("rows").eq(3))
+                    & QC::field("cols").ne(2)
+                    & QC::field("cols").ne(4)
+                    | (QC::field("rows").ne(1) & QC::field("rows").ne(3))
+                        & (QC::field("cols").eq(2) | QC::field("cols").eq(4))
+            )?
+        );
+
+        // the dense array supports bounds on dimensions, which are
+        // applied using the subarray
+        assert_eq!(atts, read(&dense, QC::field("a").ge(1))?);
+        assert_eq!(
+            (9..=16).collect::<Vec<i32>>(),
+            read(&dense, QC::field("rows").ge(3))?
+        );
+        assert_eq!(
+            vec![6, 7, 10, 11],
+            read(
+                &dense,
+                QC::field("rows").between(2, 3)?
+                    & QC::field("cols").between(2, 3)?
+            )?
+        );
+        assert_eq!(vec![3, 7, 11, 15], read(&dense, QC::field("cols").eq(3))?);
+        // cells of a dense array which do not satisfy the attribute terms
+        // may be read as fill values
+        assert_eq!(
+            vec![9, 10, 11],
+            read(&dense, QC::field("rows").gt(2) & QC::field("a").lt(12))?
+                .into_iter()
+                .filter(|a| *a >= 9)
+                .collect::<Vec<i32>>()
+        );
+        // a bound which excludes the whole subarray reads nothing
+        assert_eq!(Vec::<i32>::new(), read(&dense, QC::field("rows").lt(1))?);
+
+        // other terms on dimensions of the dense array cannot be ranges
+        for qc in [
+            QC::field("rows").ne(2),
+            QC::field("rows").eq(1) | QC::field("rows").eq(3),
+            QC::field("rows").eq(1) | QC::field("a").eq(10),
+            !QC::field("cols").eq(1),
+        ] {
+            assert!(matches!(read(&dense, qc), Err(Error::InvalidArgument(_))));
+        }
+
+        Ok(())
+    }
+
     #[test]
     fn basic_op_test() -> TileDBResult<()> {
         let qc1 = QC::field("field").lt(5);
@@ -0,0 +1,579 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use super::*;
+use crate::array::Schema;
+use crate::datatype::Datatype;
+use crate::physical_type_go;
+
+/// Provides the type information needed to parse a [QueryConditionExpr].
+pub trait QueryConditionSchema {
+    /// Returns the datatype of the field named `field`,
+    /// or `None` if there is no such field.
+    fn field_datatype(&self, field: &str) -> TileDBResult<Option<Datatype>>;
+}
+
+impl QueryConditionSchema for Schema {
+    fn field_datatype(&self, field: &str) -> TileDBResult<Option<Datatype>> {
+        let domain = self.domain()?;
+        if domain.has_dimension(field)? {
+            return Ok(Some(domain.dimension(field)?.datatype()?));
+        }
+
+        if self.has_attribute(field)? {
+            Ok(Some(self.attribute(field)?.datatype()?))
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+#[cfg(any(test, feature = "pod"))]
+impl QueryConditionSchema for tiledb_pod::array::schema::SchemaData {
+    fn field_datatype(&self, field: &str) -> TileDBResult<Option<Datatype>> {
+        Ok(self.field(field).map(|f| f.datatype()))
+    }
+}
+
+/// An error encountered while parsing a [QueryConditionExpr].
+#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+#[error("{message} at byte offset {offset}")]
+pub struct ParseError {
+    /// Byte offset into the input at which the error was found.
+    pub offset: usize,
+    pub message: String,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token<'a> {
+    Word(&'a str),
+    String(String),
+    Op(EqualityOp),
+    LParen,
+    RParen,
+    Comma,
+}
+
+impl Display for Token<'_> {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        match self {
+            Self::Word(w) => write!(f, "'{}'", w),
+            Self::String(s) => write!(f, "string '{}'", s),
+            Self::Op(op) => write!(f, "'{}'", op),
+            Self::LParen => write!(f, "'('"),
+            Self::RParen => write!(f, "')'"),
+            Self::Comma => write!(f, "','"),
+        }
+    }
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == '.'
+}
+
+fn tokenize(input: &str) -> Result<Vec<(usize, Token<'_>)>, ParseError> {
+    let mut tokens = vec![];
+    let mut chars = input.char_indices().peekable();
+
+    while let Some((offset, c)) = chars.next() {
+        let token = match c {
+            c if c.is_whitespace() => continue,
+            '(' => Token::LParen,
+            ')' => Token::RParen,
+            ',' => Token::Comma,
+            '<' | '>' | '=' | '!' => {
+                let next = chars.peek().map(|(_, c)| *c);
+                let (op, consume) = match (c, next) {
+                    ('<', Some('=')) => (EqualityOp::LessEqual, true),
+                    ('<', Some('>')) => (EqualityOp::NotEqual, true),
+                    ('<', _) => (EqualityOp::Less, false),
+                    ('>', Some('=')) => (EqualityOp::GreaterEqual, true),
+                    ('>', _) => (EqualityOp::Greater, false),
+                    ('=', Some('=')) => (EqualityOp::Equal, true),
+                    ('=', _) => (EqualityOp::Equal, false),
+                    ('!', Some('=')) => (EqualityOp::NotEqual, true),
+                    _ => {
+                        return Err(ParseError {
+                            offset,
+                            message: "Expected '!='".to_owned(),
+                        })
+                    }
+                };
+                if consume {
+                    chars.next();
+                }
+                Token::Op(op)
+            }
+            '\'' | '"' => {
+                let mut value = String::new();
+                loop {
+                    match chars.next() {
+                        None => {
+                            return Err(ParseError {
+                                offset,
+                                message: "Unterminated string literal"
+                                    .to_owned(),
+                            })
+                        }
+                        Some((_, q)) if q == c => break,
+                        Some((escape, '\\')) => {
+                            value.push(unescape(escape, &mut chars)?)
+                        }
+                        Some((_, other)) => value.push(other),
+                    }
+                }
+                Token::String(value)
+            }
+            c if is_word_char(c) || c == '-' || c == '+' => {
+                // words are identifiers, keywords, and numbers,
+                // including exponents such as `1e-5`
+                let mut end = offset + c.len_utf8();
+                let mut prev = c;
+                while let Some((i, c)) = chars.peek().copied() {
+                    let is_exponent_sign =
+                        (c == '-' || c == '+') && (prev == 'e' || prev == 'E');
+                    if !(is_word_char(c) || is_exponent_sign) {
+                        break;
+                    }
+                    chars.next();
+                    end = i + c.len_utf8();
+                    prev = c;
+                }
+                Token::Word(&input[offset..end])
+            }
+            c => {
+                return Err(ParseError {
+                    offset,
+                    message: format!("Unexpected character '{}'", c),
+                })
+            }
+        };
+        tokens.push((offset, token));
+    }
+
+    Ok(tokens)
+}
+
+/// Reads the remainder of an escape sequence beginning at `offset`.
+/// This is the inverse of [escape_string_literal].
+fn unescape(
+    offset: usize,
+    chars: &mut impl Iterator<Item = (usize, char)>,
+) -> Result<char, ParseError> {
+    let invalid = || ParseError {
+        offset,
+        message: "Invalid escape sequence".to_owned(),
+    };
+    match chars.next().ok_or_else(invalid)?.1 {
+        '\\' => Ok('\\'),
+        '\'' => Ok('\''),
+        '"' => Ok('"'),
+        'n' => Ok('\n'),
+        'r' => Ok('\r'),
+        't' => Ok('\t'),
+        '0' => Ok('\0'),
+        'u' => {
+            if chars.next().map(|(_, c)| c) != Some('{') {
+                return Err(invalid());
+            }
+            let mut hex = String::new();
+            loop {
+                match chars.next().ok_or_else(invalid)?.1 {
+                    '}' => break,
+                    c => hex.push(c),
+                }
+            }
+            u32::from_str_radix(&hex, 16)
+                .ok()
+                .and_then(char::from_u32)
+                .ok_or_else(invalid)
+        }
+        _ => Err(invalid()),
+    }
+}
+
+struct Parser<'a, S: ?Sized> {
+    tokens: Vec<(usize, Token<'a>)>,
+    cursor: usize,
+    end: usize,
+    schema: &'a S,
+}
+
+impl<'a, S> Parser<'a, S>
+where
+    S: QueryConditionSchema + ?Sized,
+{
+    fn offset(&self) -> usize {
+        self.tokens
+            .get(self.cursor)
+            .map(|(offset, _)| *offset)
+            .unwrap_or(self.end)
+    }
+
+    fn error<T>(&self, message: String) -> TileDBResult<T> {
+        Err(Error::InvalidArgument(anyhow!(ParseError {
+            offset: self.offset(),
+            message,
+        })))
+    }
+
+    fn peek(&self) -> Option<&Token<'a>> {
+        self.tokens.get(self.cursor).map(|(_, t)| t)
+    }
+
+    fn peek_keyword(&self, keyword: &str) -> bool {
+        matches!(self.peek(), Some(Token::Word(w)) if w.eq_ignore_ascii_case(keyword))
+    }
+
+    fn next_keyword(&mut self, keyword: &str) -> bool {
+        let is_keyword = self.peek_keyword(keyword);
+        if is_keyword {
+            self.cursor += 1;
+        }
+        is_keyword
+    }
+
+    fn expect(&mut self, token: Token) -> TileDBResult<()> {
+        if self.peek() == Some(&token) {
+            self.cursor += 1;
+            Ok(())
+        } else {
+            self.error(format!("Expected {}", token))
+        }
+    }
+
+    fn expect_keyword(&mut self, keyword: &str) -> TileDBResult<()> {
+        if self.next_keyword(keyword) {
+            Ok(())
+        } else {
+            self.error(format!("Expected '{}'", keyword))
+        }
+    }
+
+    fn expr(&mut self) -> TileDBResult<QueryConditionExpr> {
+        let mut expr = self.conjunction()?;
+        while self.next_keyword("OR") {
+            expr = expr | self.conjunction()?;
+        }
+        Ok(expr)
+    }
+
+    fn conjunction(&mut self) -> TileDBResult<QueryConditionExpr> {
+        let mut expr = self.unary()?;
+        while self.next_keyword("AND") {
+            expr = expr & self.unary()?;
+        }
+        Ok(expr)
+    }
+
+    fn unary(&mut self) -> TileDBResult<QueryConditionExpr> {
+        if self.next_keyword("NOT") {
+            Ok(!self.unary()?)
+        } else if self.peek() == Some(&Token::LParen) {
+            self.cursor += 1;
+            let expr = self.expr()?;
+            self.expect(Token::RParen)?;
+            Ok(expr)
+        } else {
+            self.predicate()
+        }
+    }
+
+    fn predicate(&mut self) -> TileDBResult<QueryConditionExpr> {
+        let name = match self.peek() {
+            Some(Token::Word(w)) => *w,
+            _ => return self.error("Expected field name".to_owned()),
+        };
+        let Some(datatype) = self.schema.field_datatype(name)? else {
+            return self.error(format!("No such field '{}'", name));
+        };
+        self.cursor += 1;
+
+        let field = QueryConditionExpr::field(name);
+
+        if let Some(Token::Op(op)) = self.peek() {
+            let op = *op;
+            self.cursor += 1;
+            let value = self.literal(datatype)?;
+            return Ok(match op {
+                EqualityOp::Less => field.lt(value),
+                EqualityOp::LessEqual => field.le(value),
+                EqualityOp::Equal => field.eq(value),
+                EqualityOp::NotEqual => field.ne(value),
+                EqualityOp::GreaterEqual => field.ge(value),
+                EqualityOp::Greater => field.gt(value),
+            });
+        }
+
+        if self.next_keyword("IS") {
+            let negate = self.next_keyword("NOT");
+            self.expect_keyword("NULL")?;
+            Ok(if negate {
+                field.not_null()
+            } else {
+                field.is_null()
+            })
+        } else if self.next_keyword("IN") {
+            Ok(field.is_in(self.set_members(datatype)?))
+        } else if self.peek_keyword("NOT") {
+            self.cursor += 1;
+            self.expect_keyword("IN")?;
+            Ok(field.not_in(self.set_members(datatype)?))
+        } else {
+            self.error(
+                "Expected comparison operator, 'IN', 'NOT IN', or 'IS'"
+                    .to_owned(),
+            )
+        }
+    }
+
+    fn string(&mut self) -> TileDBResult<String> {
+        match self.peek() {
+            Some(Token::String(s)) => {
+                let s = s.clone();
+                self.cursor += 1;
+                Ok(s)
+            }
+            _ => self.error("Expected string literal".to_owned()),
+        }
+    }
+
+    fn value<T>(&mut self, datatype: Datatype) -> TileDBResult<T>
+    where
+        T: FromStr,
+        <T as FromStr>::Err: Display,
+    {
+        match self.peek() {
+            Some(Token::Word(w)) => match w.parse::<T>() {
+                Ok(value) => {
+                    self.cursor += 1;
+                    Ok(value)
+                }
+                Err(e) => self.error(format!(
+                    "Invalid {} literal '{}': {}",
+                    datatype, w, e
+                )),
+            },
+            _ => self.error(format!("Expected {} literal", datatype)),
+        }
+    }
+
+    fn literal(&mut self, datatype: Datatype) -> TileDBResult<Literal> {
+        if datatype.is_string_type() {
+            Ok(Literal::from(self.string()?))
+        } else {
+            physical_type_go!(datatype, DT, {
+                Ok(Literal::from(self.value::<DT>(datatype)?))
+            })
+        }
+    }
+
+    fn set_members(&mut self, datatype: Datatype) -> TileDBResult<SetMembers> {
+        self.expect(Token::LParen)?;
+        if datatype.is_string_type() {
+            let members = self.list(|p| p.string())?;
+            Ok(SetMembers::from(members.as_slice()))
+        } else {
+            physical_type_go!(datatype, DT, {
+                let members = self.list(|p| p.value::<DT>(datatype))?;
+                Ok(SetMembers::from(members.as_slice()))
+            })
+        }
+    }
+
+    /// Parses a comma-separated list of items terminated by `)`.
+    fn list<T, F>(&mut self, mut item: F) -> TileDBResult<Vec<T>>
+    where
+        F: FnMut(&mut Self) -> TileDBResult<T>,
+    {
+        let mut items = vec![];
+        if self.peek() != Some(&Token::RParen) {
+            items.push(item(self)?);
+            while self.peek() == Some(&Token::Comma) {
+                self.cursor += 1;
+                items.push(item(self)?);
+            }
+        }
+        self.expect(Token::RParen)?;
+        Ok(items)
+    }
+}
+
+impl QueryConditionExpr {
+    /// Parses a query condition from a SQL-like predicate expression,
+    /// such as `(a < 5 AND b IN (1, 2, 3)) OR NOT c IS NULL`.
+    ///
+    /// Predicates have the forms `field OP literal` (where `OP` is one of
+    /// `<`, `<=`, `=`, `<>`, `!=`, `>=`, `>`), `field [NOT] IN (...)`, and
+    /// `field IS [NOT] NULL`. These are combined using `NOT`, `AND`, and `OR`,
+    /// in order of decreasing precedence, and grouped using parentheses.
+    /// Keywords are case-insensitive.
+    ///
+    /// The datatype of each field is looked up in `schema` to determine
+    /// how to parse its literals. String literals are quoted with `'` or `"`.
+    /// This accepts the output of the [Display] implementation.
+    ///
+    /// If the input is malformed, returns `Error::InvalidArgument`
+    /// wrapping a [ParseError] which identifies the location of the problem.
+    pub fn parse<S>(s: &str, schema: &S) -> TileDBResult<QueryConditionExpr>
+    where
+        S: QueryConditionSchema + ?Sized,
+    {
+        let tokens =
+            tokenize(s).map_err(|e| Error::InvalidArgument(anyhow!(e)))?;
+        let mut parser = Parser {
+            tokens,
+            cursor: 0,
+            end: s.len(),
+            schema,
+        };
+        let expr = parser.expr()?;
+        if parser.cursor < parser.tokens.len() {
+            return parser.error(format!(
+                "Unexpected {}",
+                parser.tokens[parser.cursor].1
+            ));
+        }
+        Ok(expr)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::QueryConditionExpr as QC;
+    use super::*;
+
+    struct TestSchema(HashMap<&'static str, Datatype>);
+
+    impl QueryConditionSchema for TestSchema {
+        fn field_datatype(
+            &self,
+            field: &str,
+        ) -> TileDBResult<Option<Datatype>> {
+            Ok(self.0.get(field).copied())
+        }
+    }
+
+    fn schema() -> TestSchema {
+        TestSchema(HashMap::from([
+            ("x", Datatype::Int32),
+            ("y", Datatype::UInt64),
+            ("f", Datatype::Float64),
+            ("s", Datatype::StringUtf8),
+        ]))
+    }
+
+    fn parse_error(s: &str) -> ParseError {
+        match QC::parse(s, &schema()) {
+            Err(Error::InvalidArgument(e)) => {
+                e.downcast::<ParseError>().unwrap()
+            }
+            other => panic!("Expected parse error, found {:?}", other),
+        }
+    }
+
+    #[test]
+    fn parse_predicates() -> TileDBResult<()> {
+        let schema = schema();
+
+        assert_eq!(QC::field("x").lt(5), QC::parse("x < 5", &schema)?);
+        assert_eq!(QC::field("x").le(-5), QC::parse("x<=-5", &schema)?);
+        assert_eq!(QC::field("y").eq(5u64), QC::parse("y = 5", &schema)?);
+        assert_eq!(QC::field("y").eq(5u64), QC::parse("y == 5", &schema)?);
+        assert_eq!(QC::field("x").ne(5), QC::parse("x <> 5", &schema)?);
+        assert_eq!(QC::field("x").ne(5), QC::parse("x != 5", &schema)?);
+        assert_eq!(
+            QC::field("f").ge(1.5e-3),
+            QC::parse("f >= 1.5e-3", &schema)?
+        );
+        assert_eq!(QC::field("f").gt(-2.0), QC::parse("f > -2", &schema)?);
+        assert_eq!(QC::field("s").eq("foo"), QC::parse("s = 'foo'", &schema)?);
+        assert_eq!(
+            QC::field("s").eq("foo"),
+            QC::parse("s = \"foo\"", &schema)?
+        );
+
+        assert_eq!(
+            QC::field("x").is_in(&[1, 2, 3][..]),
+            QC::parse("x IN (1, 2, 3)", &schema)?
+        );
+        assert_eq!(
+            QC::field("s").not_in(&["a", "b"][..]),
+            QC::parse("s not in ('a','b')", &schema)?
+        );
+        assert_eq!(QC::field("x").is_null(), QC::parse("x IS NULL", &schema)?);
+        assert_eq!(
+            QC::field("x").not_null(),
+            QC::parse("x is not null", &schema)?
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_precedence() -> TileDBResult<()> {
+        let schema = schema();
+        let x = QC::field("x").lt(5);
+        let y = QC::field("y").eq(1u64);
+        let s = QC::field("s").is_null();
+
+        assert_eq!(
+            x.clone() | (y.clone() & s.clone()),
+            QC::parse("x < 5 OR y = 1 AND s IS NULL", &schema)?
+        );
+        assert_eq!(
+            (x.clone() | y.clone()) & s.clone(),
+            QC::parse("(x < 5 OR y = 1) AND s IS NULL", &schema)?
+        );
+        assert_eq!(
+            (!x.clone() & y.clone()) | s.clone(),
+            QC::parse("NOT x < 5 AND y = 1 OR s IS NULL", &schema)?
+        );
+        assert_eq!(
+            (x.clone() & y.clone()) & s.clone(),
+            QC::parse("x < 5 AND y = 1 AND s IS NULL", &schema)?
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_display_roundtrip() -> TileDBResult<()> {
+        let schema = schema();
+
+        let atom = QC::field("x").lt(5);
+        let strings = QC::field("s").is_in(&["it's", "a \"quote\"", "\\"][..]);
+        let exprs = vec![
+            atom.clone(),
+            strings.clone(),
+            QC::field("s").ne("tab\there\u{7f}"),
+            QC::field("f").le(f64::NEG_INFINITY),
+            (atom.clone() | strings.clone())
+                & (!atom.clone() | !(atom.clone() & QC::field("y").not_null())),
+        ];
+
+        for expr in exprs {
+            assert_eq!(expr, QC::parse(&expr.to_string(), &schema)?);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(9, parse_error("x < 5 AND").offset);
+        assert_eq!(0, parse_error("z < 5").offset);
+        assert_eq!(4, parse_error("x < 'foo'").offset);
+        assert_eq!(4, parse_error("x < 5.5").offset);
+        assert_eq!(4, parse_error("s = 5").offset);
+        assert_eq!(6, parse_error("(x < 5").offset);
+        assert_eq!(5, parse_error("x < 5)").offset);
+        assert_eq!(2, parse_error("x ! 5").offset);
+        assert_eq!(4, parse_error("s = 'foo").offset);
+        assert_eq!(8, parse_error("s = 'foo\\q'").offset);
+        assert_eq!(2, parse_error("x NULL").offset);
+        assert_eq!(8, parse_error("x IN (1 2)").offset);
+    }
+}
@@ -0,0 +1,120 @@
+use super::*;
+
+/// A query which deletes the cells of an array which satisfy a query condition.
+///
+/// The array must be opened with `Mode::Delete`.
+pub struct DeleteQuery {
+    base: QueryBase,
+}
+
+impl ContextBound for DeleteQuery {
+    fn context(&self) -> Context {
+        self.base.context()
+    }
+}
+
+impl Query for DeleteQuery {
+    fn base(&self) -> &QueryBase {
+        self.base.base()
+    }
+
+    fn finalize(self) -> TileDBResult<Array> {
+        self.base.finalize()
+    }
+}
+
+impl DeleteQuery {
+    pub fn submit(&self) -> TileDBResult<()> {
+        self.base.do_submit()
+    }
+}
+
+/// Constructs a `DeleteQuery`.
+///
+/// Cells to delete are selected using `QueryBuilder::query_condition`.
+pub struct DeleteBuilder {
+    base: BuilderBase,
+}
+
+impl ContextBound for DeleteBuilder {
+    fn context(&self) -> Context {
+        self.base.context()
+    }
+}
+
+impl QueryBuilder for DeleteBuilder {
+    type Query = DeleteQuery;
+
+    fn base(&self) -> &BuilderBase {
+        &self.base
+    }
+
+    fn build(self) -> Self::Query {
+        DeleteQuery {
+            base: self.base.build(),
+        }
+    }
+}
+
+impl DeleteBuilder {
+    pub fn new(array: Array) -> TileDBResult<Self> {
+        Ok(DeleteBuilder {
+            base: BuilderBase::new(array, QueryType::Delete)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::query::{ReadBuilder, ReadQuery, ReadQueryBuilder};
+    use crate::tests::examples::quickstart;
+    use crate::tests::prelude::*;
+
+    #[test]
+    fn delete_query_condition() -> TileDBResult<()> {
+        let mut array = TestArray::new(
+            "delete_query_condition",
+            std::rc::Rc::new(
+                quickstart::Builder::new(ArrayType::Sparse).build(),
+            ),
+        )?;
+
+        {
+            let rows = vec![1, 2, 3, 4];
+            let cols = vec![4, 3, 2, 1];
+            let a = vec![10, 20, 30, 40];
+            let w = WriteBuilder::new(array.for_write()?)?
+                .layout(QueryLayout::Unordered)?
+                .data_typed("rows", &rows)?
+                .data_typed("cols", &cols)?
+                .data_typed("a", &a)?
+                .build();
+            w.submit()?;
+            w.finalize()?;
+        }
+
+        {
+            let d = DeleteBuilder::new(array.for_delete()?)?
+                .query_condition(
+                    QueryConditionExpr::field("a").eq(20)
+                        | QueryConditionExpr::field("a").ge(40),
+                )?
+                .build();
+            d.submit()?;
+            d.finalize()?;
+        }
+
+        let mut q = ReadBuilder::new(array.for_read()?)?
+            .layout(QueryLayout::RowMajor)?
+            .register_constructor::<_, Vec<i32>>("rows", Default::default())?
+            .register_constructor::<_, Vec<i32>>("a", Default::default())?
+            .build();
+        let (a, (rows, ())) = q.execute()?;
+
+        assert_eq!(vec![1, 3], rows);
+        assert_eq!(vec![10, 30], a);
+
+        Ok(())
+    }
+}
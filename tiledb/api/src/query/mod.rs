@@ -1,18 +1,32 @@
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::num::NonZeroUsize;
 use std::ops::Deref;
 
+use anyhow::anyhow;
+
+use crate::array::schema::Field;
+use crate::array::ArrayType;
+use crate::config::{Config, RawConfig};
 use crate::context::{CApiInterface, Context, ContextBound};
 use crate::error::Error;
+use crate::query::read::output::FieldScratchAllocator;
+use crate::range::Range;
+use crate::serialization::{RawBuffer, RawBufferList, SerializationType};
 use crate::{array::RawArray, Array, Result as TileDBResult};
 
 pub mod buffer;
 pub mod condition;
+pub mod delete;
 pub mod read;
 pub mod subarray;
 pub mod write;
 
 pub use self::condition::QueryConditionExpr;
+pub use self::delete::{DeleteBuilder, DeleteQuery};
 pub use self::read::{
-    ReadBuilder, ReadQuery, ReadQueryBuilder, ReadStepOutput, TypedReadBuilder,
+    ParallelReader, QueryStatus, ReadBuilder, ReadQuery, ReadQueryBuilder,
+    ReadStepOutput, TypedReadBuilder,
 };
 pub use self::subarray::{Builder as SubarrayBuilder, Subarray};
 pub use self::write::{WriteBuilder, WriteQuery};
@@ -92,11 +106,100 @@ pub trait Query {
             RawSubarray::Owned(c_subarray),
         ))
     }
+
+    /// Returns the ranges of each dimension which are applied by this query.
+    ///
+    /// The ranges are read back from tiledb and so reflect any
+    /// adjustments which it has made, such as defaulting
+    /// dimensions with no requested ranges to the dimension domain.
+    #[cfg(any(test, feature = "pod"))]
+    fn subarray_ranges(
+        &self,
+    ) -> TileDBResult<tiledb_pod::query::subarray::SubarrayData> {
+        Ok(tiledb_pod::query::subarray::SubarrayData {
+            dimension_ranges: self.subarray()?.ranges()?,
+        })
+    }
+
+    /// Returns the estimated size of the results of this query for `field`.
+    ///
+    /// This can be called before the query is submitted, once its
+    /// subarray is set, to determine how much space to allocate
+    /// for the query results.
+    fn est_result_size(&self, field: &str) -> TileDBResult<EstResultSize> {
+        self.base().est_result_size(field)
+    }
+
+    /// Returns the names of the fields which have buffers attached
+    /// to this query.
+    ///
+    /// Buffers are attached to a read query when it is submitted,
+    /// and to a query deserialized by [QueryBuilder::deserialize]
+    /// for each field which has buffers in the serialized query.
+    fn buffer_fields(&self) -> TileDBResult<Vec<String>> {
+        let c_query = *self.base().raw;
+        let mut fields = vec![];
+        for field in self.base().array().schema()?.field_names()? {
+            let c_name = cstring!(field.as_str());
+            let mut c_data: *mut std::ffi::c_void = out_ptr!();
+            let mut c_size: *mut u64 = out_ptr!();
+            self.base().capi_call(|ctx| unsafe {
+                ffi::tiledb_query_get_data_buffer(
+                    ctx,
+                    c_query,
+                    c_name.as_ptr(),
+                    &mut c_data,
+                    &mut c_size,
+                )
+            })?;
+            if !c_size.is_null() {
+                fields.push(field);
+            }
+        }
+        Ok(fields)
+    }
+
+    /// Returns the serialized representation of this query in `format`,
+    /// as sent by a client to a server which executes the query.
+    ///
+    /// This includes the array, layout, subarray, and query condition,
+    /// as well as the sizes of the buffers which are attached to the query.
+    fn serialize(&self, format: SerializationType) -> TileDBResult<Vec<u8>> {
+        let c_query = *self.base().raw;
+        let c_format = ffi::tiledb_serialization_type_t::from(format);
+        let mut c_buffer_list: *mut ffi::tiledb_buffer_list_t = out_ptr!();
+        self.base().capi_call(|ctx| unsafe {
+            ffi::tiledb_serialize_query(
+                ctx,
+                c_query,
+                c_format,
+                1,
+                &mut c_buffer_list,
+            )
+        })?;
+
+        RawBufferList::Owned(c_buffer_list).to_vec(&self.base().context())
+    }
+}
+
+/// Estimated size of the results of a query for a single field.
+/// See `Query::est_result_size`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct EstResultSize {
+    /// Estimated size of the data buffer, in bytes.
+    pub data_bytes: u64,
+    /// Estimated size of the offsets buffer in bytes, if the field is var-sized.
+    pub offsets_bytes: Option<u64>,
+    /// Estimated size of the validity buffer in bytes, if the field is nullable.
+    pub validity_bytes: Option<u64>,
 }
 
 pub struct QueryBase {
     array: Array,
     raw: RawQuery,
+    /// Whether the query is known to have no results without submitting it.
+    /// See [QueryBuilder::with_full_domain].
+    empty_result: Cell<bool>,
 }
 
 impl ContextBound for QueryBase {
@@ -119,6 +222,133 @@ impl QueryBase {
         Ok(())
     }
 
+    /// Sets the size of each buffer attached to this query to zero,
+    /// as tiledb does when a read query completes with no results.
+    fn clear_buffer_sizes(&self) -> TileDBResult<()> {
+        let c_query = **self.cquery();
+        let schema = self.array().schema()?;
+        for field in schema.field_names()? {
+            let (is_var, is_nullable) = {
+                let field = schema.field(field.as_str())?;
+                (field.cell_val_num()?.is_var_sized(), field.nullability()?)
+            };
+            let c_name = cstring!(field.as_str());
+
+            let mut c_data: *mut std::ffi::c_void = out_ptr!();
+            let mut c_size: *mut u64 = out_ptr!();
+            self.capi_call(|ctx| unsafe {
+                ffi::tiledb_query_get_data_buffer(
+                    ctx,
+                    c_query,
+                    c_name.as_ptr(),
+                    &mut c_data,
+                    &mut c_size,
+                )
+            })?;
+            if c_size.is_null() {
+                continue;
+            }
+            unsafe { *c_size = 0 };
+
+            if is_var {
+                let mut c_offsets: *mut u64 = out_ptr!();
+                let mut c_offsets_size: *mut u64 = out_ptr!();
+                self.capi_call(|ctx| unsafe {
+                    ffi::tiledb_query_get_offsets_buffer(
+                        ctx,
+                        c_query,
+                        c_name.as_ptr(),
+                        &mut c_offsets,
+                        &mut c_offsets_size,
+                    )
+                })?;
+                if !c_offsets_size.is_null() {
+                    unsafe { *c_offsets_size = 0 };
+                }
+            }
+            if is_nullable {
+                let mut c_validity: *mut u8 = out_ptr!();
+                let mut c_validity_size: *mut u64 = out_ptr!();
+                self.capi_call(|ctx| unsafe {
+                    ffi::tiledb_query_get_validity_buffer(
+                        ctx,
+                        c_query,
+                        c_name.as_ptr(),
+                        &mut c_validity,
+                        &mut c_validity_size,
+                    )
+                })?;
+                if !c_validity_size.is_null() {
+                    unsafe { *c_validity_size = 0 };
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the estimated size of the results of this query for `field`.
+    fn est_result_size(&self, field: &str) -> TileDBResult<EstResultSize> {
+        let (is_var, is_nullable) = {
+            let schema = self.array().schema()?;
+            let field = schema.field(field)?;
+            (field.cell_val_num()?.is_var_sized(), field.nullability()?)
+        };
+
+        let c_query = *self.raw;
+        let c_name = cstring!(field);
+
+        let mut data_bytes: u64 = 0;
+        let mut offsets_bytes: u64 = 0;
+        let mut validity_bytes: u64 = 0;
+
+        self.capi_call(|ctx| unsafe {
+            match (is_var, is_nullable) {
+                (false, false) => ffi::tiledb_query_get_est_result_size(
+                    ctx,
+                    c_query,
+                    c_name.as_ptr(),
+                    &mut data_bytes,
+                ),
+                (true, false) => ffi::tiledb_query_get_est_result_size_var(
+                    ctx,
+                    c_query,
+                    c_name.as_ptr(),
+                    &mut offsets_bytes,
+                    &mut data_bytes,
+                ),
+                (false, true) => {
+                    ffi::tiledb_query_get_est_result_size_nullable(
+                        ctx,
+                        c_query,
+                        c_name.as_ptr(),
+                        &mut data_bytes,
+                        &mut validity_bytes,
+                    )
+                }
+                (true, true) => {
+                    ffi::tiledb_query_get_est_result_size_var_nullable(
+                        ctx,
+                        c_query,
+                        c_name.as_ptr(),
+                        &mut offsets_bytes,
+                        &mut data_bytes,
+                        &mut validity_bytes,
+                    )
+                }
+            }
+        })?;
+
+        Ok(EstResultSize {
+            data_bytes,
+            offsets_bytes: if is_var { Some(offsets_bytes) } else { None },
+            validity_bytes: if is_nullable {
+                Some(validity_bytes)
+            } else {
+                None
+            },
+        })
+    }
+
     /// Returns the ffi status of the last submit()
     fn capi_status(&self) -> TileDBResult<ffi::tiledb_query_status_t> {
         let c_query = **self.cquery();
@@ -156,6 +386,15 @@ impl ReadQuery for QueryBase {
     fn step(
         &mut self,
     ) -> TileDBResult<ReadStepOutput<Self::Intermediate, Self::Final>> {
+        if self.empty_result.get() {
+            /*
+             * tiledb has no way to express an empty subarray,
+             * so do not submit and instead report that no cells were read
+             */
+            self.clear_buffer_sizes()?;
+            return Ok(ReadStepOutput::Final(()));
+        }
+
         self.do_submit()?;
 
         match self.capi_status()? {
@@ -210,6 +449,23 @@ pub trait QueryBuilder: Sized {
         Ok(self)
     }
 
+    /// Sets configuration parameters for the query.
+    ///
+    /// Queries require the `sm.var_offsets.bitsize`, `sm.var_offsets.mode`
+    /// and `sm.var_offsets.extra_element` parameters to have specific values
+    /// in order to manage variable-length data. These are applied on top of
+    /// `config`, so parameters which `config` leaves at their defaults
+    /// are replaced with the required values. Returns
+    /// `Error::InvalidArgument` if `config` changes one of them from its
+    /// default to a value other than the required value.
+    fn config(self, config: &Config) -> TileDBResult<Self>
+    where
+        Self: Sized,
+    {
+        self.base().set_config(Some(config))?;
+        Ok(self)
+    }
+
     /// Get the in-progress subarray for this query.
     ///
     /// The returned `Subarray` is tied to the lifetime of `self`.
@@ -234,14 +490,205 @@ pub trait QueryBuilder: Sized {
         SubarrayBuilder::for_query(self)
     }
 
-    fn query_condition(self, qc: QueryConditionExpr) -> TileDBResult<Self> {
-        let raw = qc.build(&self.base().context())?;
+    /// Sets the subarray of the query to the non-empty domain of the array.
+    ///
+    /// If the array has not been written to then it has no non-empty domain,
+    /// and the query produces no cells without being submitted.
+    /// This differs from reading a dense array with no data using any
+    /// other subarray, which produces the fill values of its attributes.
+    fn with_full_domain(self) -> TileDBResult<Self> {
+        let Some(domain) = self.base().array().nonempty_domain()? else {
+            self.base().query.empty_result.set(true);
+            return Ok(self);
+        };
+        self.start_subarray()?
+            .dimension_ranges(
+                domain
+                    .iter()
+                    .map(|typed_range| vec![typed_range.range.clone()])
+                    .collect::<Vec<Vec<Range>>>(),
+            )?
+            .finish_subarray()
+    }
+
+    /// Sets the number of records of `field` which the scratch space
+    /// of a read query holds when it is first allocated.
+    ///
+    /// This applies to fields which are registered after this call
+    /// with scratch space managed by the query, such as
+    /// [read::ScratchStrategy::AttributeDefault]. The space for the data of
+    /// a var-sized field is sized from the estimated result size of
+    /// the query, see [Query::est_result_size], so the subarray should be
+    /// set first. A read whose results fit within `records` can complete
+    /// in a single step.
+    ///
+    /// Returns `Error::InvalidArgument` if `field` is not a field of the
+    /// array, if `records` is zero, or if this is not a read query.
+    fn with_buffer_capacity(
+        self,
+        field: &str,
+        records: usize,
+    ) -> TileDBResult<Self> {
+        if self.base().array().query_type()? != QueryType::Read {
+            return Err(Error::InvalidArgument(anyhow!(
+                "Buffer capacity can only be set for read queries"
+            )));
+        }
+        let name = self.base().array().schema()?.field(field)?.name()?;
+        let Some(records) = NonZeroUsize::new(records) else {
+            return Err(Error::InvalidArgument(anyhow!(
+                "Buffer capacity for field '{}' must not be zero",
+                name
+            )));
+        };
+        self.base()
+            .buffer_capacity
+            .borrow_mut()
+            .insert(name, records);
+        Ok(self)
+    }
+
+    /// Returns an estimate of the total number of bytes of the buffers
+    /// which are needed to read `fields`, or all fields if `fields` is empty,
+    /// from the subarray which is set so far.
+    ///
+    /// This sums the data, offsets, and validity estimates of
+    /// [Query::est_result_size] over each field, and includes the extra
+    /// element which each offsets buffer has.
+    fn estimate_memory(&self, fields: &[&str]) -> TileDBResult<usize> {
+        let fields = if fields.is_empty() {
+            self.base().array().schema()?.field_names()?
+        } else {
+            fields.iter().map(|f| f.to_string()).collect()
+        };
+        let mut total = 0;
+        for field in fields.iter() {
+            let est = self.base().query.est_result_size(field)?;
+            total += est.data_bytes;
+            if let Some(offsets_bytes) = est.offsets_bytes {
+                total += offsets_bytes + std::mem::size_of::<u64>() as u64;
+            }
+            total += est.validity_bytes.unwrap_or(0);
+        }
+        Ok(total as usize)
+    }
+
+    /// Updates the query under construction with the state of the
+    /// serialized query `data`, such as the output of [Query::serialize],
+    /// for execution on behalf of the client which serialized it.
+    ///
+    /// The query must be of the same type and over the same array
+    /// as the serialized query. For each field which has buffers in the
+    /// serialized query, buffers of the same size are allocated by and
+    /// owned by the query; see [Query::buffer_fields].
+    fn deserialize(
+        self,
+        data: &[u8],
+        format: SerializationType,
+    ) -> TileDBResult<Self> {
+        let context = self.base().context();
+        let buffer = RawBuffer::borrowed(&context, data)?;
+        let c_buffer = *buffer;
         let c_query = **self.base().cquery();
+        let c_format = ffi::tiledb_serialization_type_t::from(format);
+        context.capi_call(|ctx| unsafe {
+            ffi::tiledb_deserialize_query(ctx, c_buffer, c_format, 0, c_query)
+        })?;
+        Ok(self)
+    }
+
+    /// Checks the ranges of the subarray which this builder has set so far
+    /// against the dimensions of the array.
+    ///
+    /// Most errors are reported by the builder method which causes them.
+    /// This finds subarray errors which tiledb would otherwise only report
+    /// when the query is submitted, such as point ranges which lie
+    /// outside the domain of their dimension. Such ranges are errors only
+    /// for write queries, or for read queries which set
+    /// `sm.read_range_oob` to `error`.
+    ///
+    /// The layout, buffers, and condition of the query are not checked.
+    fn validate_subarray(&self) -> TileDBResult<()> {
+        let domain = self.base().array().schema()?.domain()?;
+        let check_domain = self.base().rejects_out_of_domain_ranges()?;
+        for (d, ranges) in self.subarray()?.ranges()?.iter().enumerate() {
+            let dim = domain.dimension(d)?;
+            for range in ranges.iter() {
+                subarray::check_dimension_range(&dim, range, check_domain)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Sets a condition which cells must satisfy to be read.
+    ///
+    /// Returns an error if `qc` applies a relational operator such as
+    /// `<` to an attribute whose enumeration is not ordered.
+    ///
+    /// The supported conditions depend on the array type:
+    ///
+    /// | Array type | Attributes | Dimensions |
+    /// | ---------- | ---------- | ---------- |
+    /// | Sparse     | any        | any        |
+    /// | Dense      | any        | integer bounds combined using `AND` |
+    ///
+    /// Dense arrays do not support conditions on dimensions, so for a dense
+    /// array each term of `qc` which compares a dimension with an integer
+    /// using `<`, `<=`, `=`, `>=` or `>`, including
+    /// [condition::Field::between], and which is combined with the rest
+    /// of `qc` using `AND`, is instead applied by intersecting the subarray
+    /// of the query with the values which satisfy it. If the intersection
+    /// is empty then the query reads no cells. Returns
+    /// `Error::InvalidArgument` if `qc` has any other term on a dimension
+    /// of a dense array, such as `!=`, or a term under `OR` or `NOT`.
+    /// The subarray should be set before the condition, since
+    /// [QueryBuilder::start_subarray] replaces the subarray.
+    ///
+    /// Integer literals compared with integer fields are converted to
+    /// the type of the field, as if by [condition::Literal::cast_integer].
+    /// Returns `Error::InvalidArgument` if a literal is out of range
+    /// for its field.
+    fn query_condition(self, qc: QueryConditionExpr) -> TileDBResult<Self> {
+        qc.check_enumerations(self.base().array())?;
+
+        let schema = self.base().array().schema()?;
+        let qc = qc.cast_integers(&schema)?;
+        let (b, qc) = if schema.array_type()? == ArrayType::Dense {
+            let domain = schema.domain()?;
+            let (bounds, qc) = qc.split_dimension_bounds(&domain)?;
+            let b = if bounds.is_empty() {
+                self
+            } else {
+                let mut ranges = self.subarray()?.ranges()?;
+                for (d, lo, hi) in bounds {
+                    ranges[d] = ranges[d]
+                        .iter()
+                        .filter_map(|r| subarray::intersect_bounds(r, lo, hi))
+                        .collect::<Vec<Range>>();
+                    if ranges[d].is_empty() {
+                        self.base().query.empty_result.set(true);
+                        return Ok(self);
+                    }
+                }
+                self.start_subarray()?
+                    .dimension_ranges(ranges)?
+                    .finish_subarray()?
+            };
+            let Some(qc) = qc else {
+                return Ok(b);
+            };
+            (b, qc)
+        } else {
+            (self, qc)
+        };
+
+        let raw = qc.build(&b.base().context())?;
+        let c_query = **b.base().cquery();
         let c_cond = *raw;
-        self.base().capi_call(|ctx| unsafe {
+        b.base().capi_call(|ctx| unsafe {
             ffi::tiledb_query_set_condition(ctx, c_query, c_cond)
         })?;
-        Ok(self)
+        Ok(b)
     }
 
     fn build(self) -> Self::Query;
@@ -249,6 +696,7 @@ pub trait QueryBuilder: Sized {
 
 pub struct BuilderBase {
     query: QueryBase,
+    buffer_capacity: RefCell<HashMap<String, NonZeroUsize>>,
 }
 
 impl ContextBound for BuilderBase {
@@ -257,6 +705,26 @@ impl ContextBound for BuilderBase {
     }
 }
 
+/// Configuration which every query requires, so that the offsets of
+/// variable-length data have the arrow-like layout which the query
+/// buffers expect.
+const REQUIRED_CONFIG: [(&str, &str); 3] = [
+    ("sm.var_offsets.bitsize", "64"),
+    ("sm.var_offsets.mode", "elements"),
+    ("sm.var_offsets.extra_element", "true"),
+];
+
+/// Returns a configuration with the parameters which every query requires,
+/// to which other query parameters can be added.
+/// See [QueryBuilder::config].
+pub fn required_config() -> TileDBResult<Config> {
+    let mut config = Config::new()?;
+    for (key, value) in REQUIRED_CONFIG.iter() {
+        config.set(key, value)?;
+    }
+    Ok(config)
+}
+
 impl BuilderBase {
     fn carray(&self) -> &RawArray {
         self.query.array.capi()
@@ -268,6 +736,116 @@ impl BuilderBase {
     pub fn array(&self) -> &Array {
         &self.query.array
     }
+
+    /// Returns an allocator for the scratch space of `field` which is
+    /// managed by the query.
+    ///
+    /// The allocator holds the number of records set by
+    /// [QueryBuilder::with_buffer_capacity] if there is one,
+    /// and otherwise is [Field::query_scratch_allocator].
+    fn scratch_allocator(
+        &self,
+        field: &Field,
+    ) -> TileDBResult<FieldScratchAllocator> {
+        let records =
+            self.buffer_capacity.borrow().get(&field.name()?).copied();
+        match records {
+            Some(records) => self.scratch_allocator_for_records(field, records),
+            None => field.query_scratch_allocator(None),
+        }
+    }
+
+    /// Returns an allocator whose initial scratch space holds `records`
+    /// records of `field`.
+    ///
+    /// The data of a var-sized field is sized using the average number
+    /// of values per record of the estimated result size of the query.
+    fn scratch_allocator_for_records(
+        &self,
+        field: &Field,
+        records: NonZeroUsize,
+    ) -> TileDBResult<FieldScratchAllocator> {
+        let mut alloc = field.query_scratch_allocator_for_records(records)?;
+        if field.cell_val_num()?.is_var_sized() {
+            let est = self.query.est_result_size(&field.name()?)?;
+            let est_records = est.offsets_bytes.unwrap_or(0)
+                / std::mem::size_of::<u64>() as u64;
+            if est_records > 0 {
+                let est_values =
+                    est.data_bytes / field.datatype()?.size() as u64;
+                alloc =
+                    alloc.with_var_values_per_record(
+                        NonZeroUsize::new(
+                            est_values.div_ceil(est_records) as usize
+                        )
+                        .unwrap_or(NonZeroUsize::MIN),
+                    );
+            }
+        }
+        Ok(alloc)
+    }
+
+    /// Returns the configuration which is currently set for the query.
+    fn config(&self) -> TileDBResult<Config> {
+        let c_query = **self.cquery();
+        let mut c_cfg: *mut ffi::tiledb_config_t = out_ptr!();
+        self.capi_call(|ctx| unsafe {
+            ffi::tiledb_query_get_config(ctx, c_query, &mut c_cfg)
+        })?;
+        Ok(Config::from_raw(RawConfig::Owned(c_cfg)))
+    }
+
+    /// Returns whether tiledb rejects subarray ranges which extend outside
+    /// of the domain of their dimension.
+    ///
+    /// Write queries always reject them. Read queries reject them only if
+    /// `sm.read_range_oob` is `error`, and otherwise crop them to the domain.
+    fn rejects_out_of_domain_ranges(&self) -> TileDBResult<bool> {
+        if self.array().query_type()? != QueryType::Read {
+            return Ok(true);
+        }
+        Ok(
+            self.config()?.get("sm.read_range_oob")?.as_deref()
+                == Some("error"),
+        )
+    }
+
+    /// Sets the configuration of the query to the parameters of `config`
+    /// which differ from the defaults, together with the required
+    /// configuration.
+    ///
+    /// Returns `Error::InvalidArgument` if `config` changes a required
+    /// parameter to a value other than its required value.
+    fn set_config(&self, config: Option<&Config>) -> TileDBResult<()> {
+        let mut query_config = required_config()?;
+        if let Some(config) = config {
+            for (key, value) in Config::new()?.diff(config) {
+                if let Some((_, required)) =
+                    REQUIRED_CONFIG.iter().find(|(k, _)| *k == key)
+                {
+                    if value != *required {
+                        return Err(Error::InvalidArgument(anyhow!(
+                            "Query config cannot override '{}': found '{}', required '{}'",
+                            key,
+                            value,
+                            required
+                        )));
+                    }
+                }
+            }
+            query_config.merge(config)?;
+        }
+
+        let c_query = **self.cquery();
+        self.capi_call(|c_context| unsafe {
+            ffi::tiledb_query_set_config(
+                c_context,
+                c_query,
+                query_config.capi(),
+            )
+        })?;
+        Ok(())
+    }
 }
 
 impl QueryBuilder for BuilderBase {
@@ -295,7 +873,9 @@ impl BuilderBase {
             query: QueryBase {
                 array,
                 raw: RawQuery::Owned(c_query),
+                empty_result: Cell::new(false),
             },
+            buffer_capacity: RefCell::new(HashMap::new()),
         })
     }
 }
@@ -649,6 +649,27 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn quickstart_aggregate_sum_subarray() -> TileDBResult<()> {
+        let a = quickstart_init("sum_subarray")?;
+        let a = a.for_read()?;
+
+        let mut q = ReadBuilder::new(a)?
+            .start_subarray()?
+            .add_range("rows", &[2, 3])?
+            .add_range("cols", &[6, 7])?
+            .finish_subarray()?
+            .count()?
+            .sum::<i64>("a")?
+            .build();
+
+        let (sum_a, (count, _)) = q.execute()?;
+        assert_eq!(Some(4), count);
+        assert_eq!(Some(21 + 22 + 25 + 26), sum_a);
+
+        Ok(())
+    }
+
     #[test]
     fn quickstart_aggregate_queries_multi_function() -> TileDBResult<()> {
         let a = quickstart_init("multi_function")?;
@@ -1,23 +1,29 @@
 use super::*;
 
 use std::cell::RefCell;
+use std::num::NonZeroUsize;
 use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
 
 use paste::paste;
 
-use crate::config::Config;
+use crate::array::schema::Field;
 use crate::query::buffer::{BufferMut, QueryBuffersMut};
 use crate::query::read::output::ScratchAllocator;
 use crate::Result as TileDBResult;
 
 pub mod aggregate;
 mod callback;
+mod ordered;
 pub mod output;
+mod parallel;
 mod raw;
 mod typed;
 
 pub use aggregate::*;
 pub use callback::*;
+pub use ordered::*;
+pub use parallel::*;
 pub use raw::*;
 pub use typed::*;
 
@@ -167,12 +173,27 @@ impl<U> ReadStepOutput<U, U> {
     }
 }
 
+/// The outcome of running a query which can be cancelled.
+/// See [ReadQuery::submit_interruptible].
+#[derive(Clone, Debug)]
+pub enum QueryStatus<F> {
+    /// The query ran to completion and produced this result.
+    Completed(F),
+    /// The query was cancelled before it completed.
+    Cancelled,
+}
+
 #[derive(Default)]
 pub enum ScratchStrategy<'data, C> {
     #[default]
     AttributeDefault,
     RawBuffers(&'data RefCell<QueryBuffersMut<'data, C>>),
     CustomAllocator(Box<dyn ScratchAllocator<C> + 'data>),
+    /// Allocate space for a fixed number of records of the field.
+    /// This enables a read of a known number of records to complete
+    /// in a single step. The data of a var-sized field is sized
+    /// as for [QueryBuilder::with_buffer_capacity].
+    RecordCapacity(NonZeroUsize),
 }
 
 impl<'data, C> From<&'data RefCell<QueryBuffersMut<'data, C>>>
@@ -183,6 +204,43 @@ impl<'data, C> From<&'data RefCell<QueryBuffersMut<'data, C>>>
     }
 }
 
+/// Returns an error if `scratch` does not have the buffers which are
+/// required to read `field`: an offsets buffer if the field is var-sized,
+/// and a validity buffer if the field is nullable.
+fn check_scratch_buffers<C>(
+    field: &Field,
+    scratch: &RefCell<QueryBuffersMut<'_, C>>,
+) -> TileDBResult<()> {
+    let name = field.name()?;
+    let scratch = scratch.borrow();
+    let is_var = field.cell_val_num()?.is_var_sized();
+    if is_var != scratch.cell_structure.is_var() {
+        return Err(Error::InvalidArgument(anyhow!(if is_var {
+            format!(
+                "Field '{}' is var-sized but no offsets buffer was provided",
+                name
+            )
+        } else {
+            format!("Field '{}' is not var-sized but an offsets buffer was provided", name)
+        })));
+    }
+    let is_nullable = field.nullability()?;
+    if is_nullable != scratch.validity.is_some() {
+        return Err(Error::InvalidArgument(anyhow!(if is_nullable {
+            format!(
+                "Field '{}' is nullable but no validity buffer was provided",
+                name
+            )
+        } else {
+            format!(
+                "Field '{}' is not nullable but a validity buffer was provided",
+                name
+            )
+        })));
+    }
+    Ok(())
+}
+
 /// Trait for runnable read queries.
 pub trait ReadQuery: Query {
     type Intermediate;
@@ -204,6 +262,28 @@ pub trait ReadQuery: Query {
         })
     }
 
+    /// Run the query to completion unless `cancel` is set first.
+    ///
+    /// `cancel` is checked before each step of the query, and if it is set
+    /// then the query stops and returns [QueryStatus::Cancelled]. This allows
+    /// another thread, or a callback of the query itself, to interrupt a
+    /// query which requires several steps to read all of its results.
+    /// A step which is already running in libtiledb cannot be preempted,
+    /// so cancellation only takes effect between steps.
+    fn submit_interruptible(
+        &mut self,
+        cancel: &AtomicBool,
+    ) -> TileDBResult<QueryStatus<Self::Final>> {
+        Ok(loop {
+            if cancel.load(Ordering::Relaxed) {
+                break QueryStatus::Cancelled;
+            }
+            if let ReadStepOutput::Final(result) = self.step()? {
+                break QueryStatus::Completed(result);
+            }
+        })
+    }
+
     /// Convert this query into an iterator which yields an item
     /// for each step of the query.
     fn into_iter(self) -> ReadQueryIterator<Self::Intermediate, Self::Final>
@@ -242,17 +322,23 @@ macro_rules! fn_register_callback {
                         let metadata = FieldMetadata::try_from(&field)?;
                         match [< scratch_ $U:snake >] {
                             ScratchStrategy::AttributeDefault => {
-                                let alloc : Box<dyn ScratchAllocator<<T as $Callback>::$U> + 'data> = Box::new(field.query_scratch_allocator(None)?);
+                                let alloc : Box<dyn ScratchAllocator<<T as $Callback>::$U> + 'data> = Box::new(self.base().scratch_allocator(&field)?);
                                 let managed = ManagedBuffer::from(alloc);
                                 RawReadHandle::managed(metadata, managed)
                             },
                             ScratchStrategy::RawBuffers(qb) => {
+                                check_scratch_buffers(&field, qb)?;
                                 RawReadHandle::new(metadata, qb)
                             },
                             ScratchStrategy::CustomAllocator(a) => {
                                 let managed = ManagedBuffer::from(a);
                                 RawReadHandle::managed(metadata, managed)
                             }
+                            ScratchStrategy::RecordCapacity(records) => {
+                                let alloc : Box<dyn ScratchAllocator<<T as $Callback>::$U> + 'data> = Box::new(self.base().scratch_allocator_for_records(&field, records)?);
+                                let managed = ManagedBuffer::from(alloc);
+                                RawReadHandle::managed(metadata, managed)
+                            }
                         }
                     };
                 )+
@@ -276,6 +362,11 @@ macro_rules! fn_register_callback {
 /// callbacks, or strongly-typed objects.
 pub trait ReadQueryBuilder<'data>: QueryBuilder {
     /// Register a raw memory location to read query results into.
+    ///
+    /// The query borrows `scratch` for its lifetime. Returns an error if
+    /// `scratch` lacks an offsets buffer for a var-sized field or a validity
+    /// buffer for a nullable field, or has either one for a field which
+    /// does not use it.
     fn register_raw<S, C>(
         self,
         field: S,
@@ -289,6 +380,7 @@ pub trait ReadQueryBuilder<'data>: QueryBuilder {
         let metadata = {
             let schema = self.base().array().schema()?;
             let field = schema.field(field.as_ref())?;
+            check_scratch_buffers(&field, scratch)?;
             FieldMetadata {
                 name: field.name()?,
                 datatype: field.datatype()?,
@@ -363,6 +455,66 @@ pub trait ReadQueryBuilder<'data>: QueryBuilder {
         })
     }
 
+    /// Register `callback` to receive the coordinates of the query results,
+    /// that is, the values of every dimension of the array and of no attributes.
+    ///
+    /// The callback receives one output per dimension, in the order of the
+    /// dimensions in the array domain. Scratch space for each dimension
+    /// is managed by the query, as if by [Field::query_scratch_allocator],
+    /// and is sized using [QueryBuilder::with_buffer_capacity] if it was
+    /// called for the dimension.
+    fn coordinates_only<T>(
+        self,
+        callback: T,
+    ) -> TileDBResult<CallbackVarArgReadBuilder<'data, T, Self>>
+    where
+        Self: Sized,
+        T: ReadCallbackVarArg,
+    {
+        let handles = {
+            let schema = self.base().array().schema()?;
+            let num_dimensions = schema.domain()?.num_dimensions()?;
+            let dimensions = (0..num_dimensions)
+                .map(|d| schema.field(d))
+                .collect::<TileDBResult<Vec<Field>>>()?;
+            managed_read_handles(self.base(), &dimensions)?
+        };
+        self.register_callback_var(handles, callback)
+    }
+
+    /// Reads every field of the array into memory, and then sorts the cells
+    /// of the result by the values of the dimensions `keys`.
+    ///
+    /// Cells are ordered lexicographically by the dimensions in `keys`,
+    /// and cells which are equal on those dimensions retain the order
+    /// in which they were read. This is useful to obtain cells in a
+    /// specific dimension order from arrays whose cell order is not,
+    /// such as arrays with `CellOrder::Hilbert`.
+    ///
+    /// Sorting requires the results of every step of the query to be
+    /// materialized in memory before any can be returned, and then
+    /// copied once more in sorted order. See [OutputOrderCallback].
+    ///
+    /// Returns `Error::InvalidArgument` if any key in `keys`
+    /// is not a dimension of the array.
+    fn with_output_order(
+        self,
+        keys: Vec<String>,
+    ) -> TileDBResult<CallbackVarArgReadBuilder<'data, OutputOrderCallback, Self>>
+    where
+        Self: Sized,
+    {
+        let fields = self
+            .base()
+            .array()
+            .schema()?
+            .fields()?
+            .collect::<TileDBResult<Vec<Field>>>()?;
+        let (handles, callback) =
+            output_order_handles(self.base(), &fields, &keys)?;
+        self.register_callback_var(handles, callback)
+    }
+
     /// Register a typed result to be constructed from the query results.
     /// Intermediate raw results are written into the provided scratch space.
     fn register_constructor<S, T>(
@@ -387,6 +539,64 @@ pub trait ReadQueryBuilder<'data>: QueryBuilder {
     }
 }
 
+/// Returns a read handle for each of `fields` with scratch space
+/// which is managed by the query under construction by `base`.
+/// See [QueryBuilder::with_buffer_capacity].
+pub(crate) fn managed_read_handles<'data>(
+    base: &BuilderBase,
+    fields: &[Field],
+) -> TileDBResult<Vec<TypedReadHandle<'data>>> {
+    fields
+        .iter()
+        .map(|field| {
+            let metadata = FieldMetadata::try_from(field)?;
+            Ok(crate::physical_type_go!(field.datatype()?, DT, {
+                let managed: ManagedBuffer<DT> =
+                    ManagedBuffer::new(base.scratch_allocator(field)?);
+                TypedReadHandle::from(RawReadHandle::managed(metadata, managed))
+            }))
+        })
+        .collect()
+}
+
+/// Returns a read handle for each of `fields` as if by [managed_read_handles],
+/// and a callback which sorts the cells read from them by the dimensions
+/// `keys`. See [ReadQueryBuilder::with_output_order].
+///
+/// Returns `Error::InvalidArgument` if any key in `keys` is not
+/// a dimension of the array, or is not one of `fields`.
+pub(crate) fn output_order_handles<'data>(
+    base: &BuilderBase,
+    fields: &[Field],
+    keys: &[String],
+) -> TileDBResult<(Vec<TypedReadHandle<'data>>, OutputOrderCallback)> {
+    let names = fields
+        .iter()
+        .map(|f| f.name())
+        .collect::<TileDBResult<Vec<String>>>()?;
+    let domain = base.array().schema()?.domain()?;
+    let key_positions = keys
+        .iter()
+        .map(|key| {
+            if !domain.has_dimension(key.as_str())? {
+                Err(Error::InvalidArgument(anyhow!(
+                    "Cannot order by '{}': not a dimension",
+                    key
+                )))
+            } else if let Some(p) = names.iter().position(|n| n == key) {
+                Ok(p)
+            } else {
+                Err(Error::InvalidArgument(anyhow!(
+                    "Cannot order by '{}': dimension is not read",
+                    key
+                )))
+            }
+        })
+        .collect::<TileDBResult<Vec<usize>>>()?;
+    let handles = managed_read_handles(base, fields)?;
+    Ok((handles, OutputOrderCallback::new(names, key_positions)))
+}
+
 pub struct ReadBuilder {
     base: BuilderBase,
 }
@@ -402,22 +612,7 @@ impl ReadBuilder {
         let base = BuilderBase::new(array, QueryType::Read)?;
 
         /* configure the query to always use arrow-like output */
-        {
-            let mut config = Config::new()?;
-            config.set("sm.var_offsets.bitsize", "64")?;
-            config.set("sm.var_offsets.mode", "elements")?;
-            config.set("sm.var_offsets.extra_element", "true")?;
-
-            /*
-             * TODO: make sure that users can't override this somehow,
-             * else we will be very very sad
-             */
-            let c_query = **base.cquery();
-
-            base.capi_call(|c_context| unsafe {
-                ffi::tiledb_query_set_config(c_context, c_query, config.capi())
-            })?;
-        }
+        base.set_config(None)?;
 
         Ok(ReadBuilder { base })
     }
@@ -456,3 +651,624 @@ impl<I, F> Iterator for ReadQueryIterator<I, F> {
 }
 
 impl<I, F> std::iter::FusedIterator for ReadQueryIterator<I, F> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::array::tests::create_quickstart_sparse_string;
+    use crate::query::buffer::{CellStructureMut, TypedQueryBuffers};
+    use crate::query::read::output::{
+        CellStructureSingleIterator, RawReadOutput, TypedRawReadOutput,
+        VarDataIterator,
+    };
+    use crate::serialization::SerializationType;
+    use crate::tests::examples::quickstart;
+    use crate::tests::prelude::*;
+
+    /// Test that a read whose scratch space is sized for its
+    /// results completes in a single step.
+    #[test]
+    fn scratch_record_capacity() -> TileDBResult<()> {
+        let mut array = TestArray::new(
+            "scratch_record_capacity",
+            std::rc::Rc::new(
+                quickstart::Builder::new(ArrayType::Sparse).build(),
+            ),
+        )?;
+
+        {
+            let rows = vec![1, 2, 3];
+            let cols = vec![4, 3, 2];
+            let a = vec![10, 20, 30];
+            let w = WriteBuilder::new(array.for_write()?)?
+                .layout(QueryLayout::Unordered)?
+                .data_typed("rows", &rows)?
+                .data_typed("cols", &cols)?
+                .data_typed("a", &a)?
+                .build();
+            w.submit()?;
+            w.finalize()?;
+        }
+
+        let read = |capacity: usize| {
+            let mut q = ReadBuilder::new(array.for_read()?)?
+                .layout(QueryLayout::RowMajor)?
+                .register_constructor::<_, Vec<i32>>(
+                    "a",
+                    ScratchStrategy::RecordCapacity(
+                        NonZeroUsize::new(capacity).unwrap(),
+                    ),
+                )?
+                .build();
+            q.step()
+        };
+
+        let step = read(3)?;
+        assert!(step.is_final());
+        let (a, ()) = step.unwrap_final();
+        assert_eq!(vec![10, 20, 30], a);
+
+        let step = read(1)?;
+        assert!(step.is_intermediate());
+
+        Ok(())
+    }
+
+    /// Test that a read with buffer capacity for its results completes
+    /// in a single step, including for a var-sized field whose values
+    /// are longer than the default estimate.
+    #[test]
+    fn with_buffer_capacity() -> TileDBResult<()> {
+        let mut array = TestArray::new("with_buffer_capacity", {
+            let mut schema =
+                quickstart::Builder::new(ArrayType::Sparse).build();
+            schema.attributes.push(AttributeData {
+                name: "b".to_owned(),
+                datatype: Datatype::StringAscii,
+                cell_val_num: Some(CellValNum::Var),
+                ..Default::default()
+            });
+            std::rc::Rc::new(schema)
+        })?;
+
+        let b = ["x", "y", "z"]
+            .iter()
+            .map(|s| s.repeat(200))
+            .collect::<Vec<String>>();
+        {
+            let rows = vec![1, 2, 3];
+            let cols = vec![4, 3, 2];
+            let a = vec![10, 20, 30];
+            let w = WriteBuilder::new(array.for_write()?)?
+                .layout(QueryLayout::Unordered)?
+                .data_typed("rows", &rows)?
+                .data_typed("cols", &cols)?
+                .data_typed("a", &a)?
+                .data_typed("b", &b)?
+                .build();
+            w.submit()?;
+            w.finalize()?;
+        }
+
+        let mut q = ReadBuilder::new(array.for_read()?)?
+            .layout(QueryLayout::RowMajor)?
+            .with_buffer_capacity("b", 3)?
+            .register_constructor::<_, Vec<String>>("b", Default::default())?
+            .build();
+        let step = q.step()?;
+        assert!(step.is_final());
+        let (b_out, ()) = step.unwrap_final();
+        assert_eq!(b, b_out);
+
+        assert!(ReadBuilder::new(array.for_read()?)?
+            .with_buffer_capacity("c", 3)
+            .is_err());
+        assert!(matches!(
+            ReadBuilder::new(array.for_read()?)?.with_buffer_capacity("b", 0),
+            Err(Error::InvalidArgument(_))
+        ));
+        assert!(matches!(
+            WriteBuilder::new(array.for_write()?)?.with_buffer_capacity("b", 3),
+            Err(Error::InvalidArgument(_))
+        ));
+
+        Ok(())
+    }
+
+    /// Test that a multi-step read can be cancelled between steps.
+    #[test]
+    fn submit_interruptible() -> TileDBResult<()> {
+        let mut array = TestArray::new(
+            "submit_interruptible",
+            std::rc::Rc::new(
+                quickstart::Builder::new(ArrayType::Sparse).build(),
+            ),
+        )?;
+
+        {
+            let rows = vec![1, 2, 3];
+            let cols = vec![4, 3, 2];
+            let a = vec![10, 20, 30];
+            let w = WriteBuilder::new(array.for_write()?)?
+                .layout(QueryLayout::Unordered)?
+                .data_typed("rows", &rows)?
+                .data_typed("cols", &cols)?
+                .data_typed("a", &a)?
+                .build();
+            w.submit()?;
+            w.finalize()?;
+        }
+
+        let cancel = AtomicBool::new(false);
+        let seen = std::cell::Cell::new(0);
+
+        // read one record per step, and cancel after the second
+        let callback = FnMutAdapter::new(|_: i32| {
+            seen.set(seen.get() + 1);
+            if seen.get() == 2 {
+                cancel.store(true, Ordering::Relaxed);
+            }
+        });
+        let mut q = ReadBuilder::new(array.for_read()?)?
+            .layout(QueryLayout::RowMajor)?
+            .register_callback(
+                (
+                    "a",
+                    ScratchStrategy::RecordCapacity(
+                        NonZeroUsize::new(1).unwrap(),
+                    ),
+                ),
+                callback,
+            )?
+            .build();
+
+        let status = q.submit_interruptible(&cancel)?;
+        assert!(matches!(status, QueryStatus::Cancelled));
+        assert_eq!(2, seen.get());
+
+        // without cancellation the query runs to completion
+        cancel.store(false, Ordering::Relaxed);
+        let mut q = ReadBuilder::new(array.for_read()?)?
+            .layout(QueryLayout::RowMajor)?
+            .register_constructor::<_, Vec<i32>>("a", Default::default())?
+            .build();
+        let status = q.submit_interruptible(&cancel)?;
+        let QueryStatus::Completed((a, ())) = status else {
+            unreachable!("Expected completed query")
+        };
+        assert_eq!(vec![10, 20, 30], a);
+
+        Ok(())
+    }
+
+    /// Test that the subarray and fields of a read query survive
+    /// serialization to and from a query over the same array.
+    #[test]
+    fn serialize() -> TileDBResult<()> {
+        let mut array = TestArray::new(
+            "serialize",
+            std::rc::Rc::new(
+                quickstart::Builder::new(ArrayType::Sparse).build(),
+            ),
+        )?;
+
+        {
+            let rows = vec![1, 2, 3];
+            let cols = vec![4, 3, 2];
+            let a = vec![10, 20, 30];
+            let w = WriteBuilder::new(array.for_write()?)?
+                .layout(QueryLayout::Unordered)?
+                .data_typed("rows", &rows)?
+                .data_typed("cols", &cols)?
+                .data_typed("a", &a)?
+                .build();
+            w.submit()?;
+            w.finalize()?;
+        }
+
+        // buffers are attached when the query is submitted, so read one
+        // record of several to serialize a query which has buffers
+        let mut client = ReadBuilder::new(array.for_read()?)?
+            .layout(QueryLayout::RowMajor)?
+            .start_subarray()?
+            .add_range("rows", &[1, 2])?
+            .finish_subarray()?
+            .register_callback(
+                (
+                    "a",
+                    ScratchStrategy::RecordCapacity(
+                        NonZeroUsize::new(1).unwrap(),
+                    ),
+                ),
+                FnMutAdapter::new(|_: i32| ()),
+            )?
+            .build();
+        assert!(matches!(client.step()?, ReadStepOutput::Intermediate(_)));
+        assert_eq!(vec!["a".to_owned()], client.buffer_fields()?);
+
+        let json = client.serialize(SerializationType::Json)?;
+
+        let server = ReadBuilder::new(array.for_read()?)?;
+        assert!(server.build().buffer_fields()?.is_empty());
+
+        let server = ReadBuilder::new(array.for_read()?)?
+            .deserialize(&json, SerializationType::Json)?
+            .build();
+        assert_eq!(client.subarray_ranges()?, server.subarray_ranges()?);
+        assert_eq!(client.buffer_fields()?, server.buffer_fields()?);
+
+        // not a serialized query
+        assert!(ReadBuilder::new(array.for_read()?)?
+            .deserialize(b"{}", SerializationType::Json)
+            .is_err());
+
+        Ok(())
+    }
+
+    /// Test that a query config cannot change the layout of offsets,
+    /// and that the required values replace the defaults.
+    #[test]
+    fn config_var_offsets() -> TileDBResult<()> {
+        let array = TestArray::new(
+            "config_var_offsets",
+            std::rc::Rc::new(
+                quickstart::Builder::new(ArrayType::Sparse).build(),
+            ),
+        )?;
+
+        let with_config = |key: &str, value: &str| {
+            let mut config = required_config()?;
+            config.set(key, value)?;
+            ReadBuilder::new(array.for_read()?)?.config(&config)
+        };
+
+        assert!(matches!(
+            with_config("sm.var_offsets.bitsize", "32"),
+            Err(Error::InvalidArgument(_))
+        ));
+
+        // the tiledb defaults are not the required values,
+        // but they are replaced with the required values
+        let required = |b: ReadBuilder| -> TileDBResult<bool> {
+            let config = b.base().config()?;
+            for (key, value) in REQUIRED_CONFIG.iter() {
+                if config.get(key)?.as_deref() != Some(*value) {
+                    return Ok(false);
+                }
+            }
+            Ok(true)
+        };
+        assert!(required(with_config("sm.var_offsets.mode", "bytes")?)?);
+        assert!(required(with_config(
+            "sm.var_offsets.extra_element",
+            "false"
+        )?)?);
+        assert!(required(
+            ReadBuilder::new(array.for_read()?)?.config(&Config::new()?)?
+        )?);
+
+        // the required values and unrelated parameters are allowed
+        assert!(with_config("sm.var_offsets.mode", "elements").is_ok());
+        assert!(with_config("sm.memory_budget", "1000000").is_ok());
+
+        Ok(())
+    }
+
+    /// Test that `validate_subarray` finds a subarray error which
+    /// `build` defers until the query is submitted.
+    #[test]
+    fn validate_subarray() -> TileDBResult<()> {
+        let array = TestArray::new(
+            "validate_subarray",
+            std::rc::Rc::new(
+                quickstart::Builder::new(ArrayType::Sparse).build(),
+            ),
+        )?;
+
+        let config = required_config()?.with("sm.read_range_oob", "error")?;
+        let with_points = |points: &[i32]| {
+            ReadBuilder::new(array.for_read()?)?
+                .config(&config)?
+                .start_subarray()?
+                .add_point_ranges("rows", points)?
+                .finish_subarray()
+        };
+
+        assert!(with_points(&[1, 3])?.validate_subarray().is_ok());
+
+        // the point 10 is outside of the domain [1, 4]
+        let _ = with_points(&[1, 10])?.build();
+        assert!(matches!(
+            with_points(&[1, 10])?.validate_subarray(),
+            Err(Error::InvalidArgument(_))
+        ));
+
+        Ok(())
+    }
+
+    /// Test that user-supplied buffers must match the structure of the field.
+    #[test]
+    fn register_raw_buffers() -> TileDBResult<()> {
+        let array = TestArray::new("register_raw_buffers", {
+            let mut schema =
+                quickstart::Builder::new(ArrayType::Sparse).build();
+            schema.attributes.push(AttributeData {
+                name: "b".to_owned(),
+                datatype: Datatype::StringAscii,
+                cell_val_num: Some(CellValNum::Var),
+                nullability: Some(true),
+                ..Default::default()
+            });
+            std::rc::Rc::new(schema)
+        })?;
+
+        let register = |field: &str, var: bool, nullable: bool| {
+            let scratch = RefCell::new(QueryBuffersMut {
+                data: BufferMut::Owned(vec![0u8; 64].into_boxed_slice()),
+                cell_structure: if var {
+                    CellStructureMut::Var(BufferMut::Owned(
+                        vec![0u64; 8].into_boxed_slice(),
+                    ))
+                } else {
+                    CellStructureMut::Fixed(std::num::NonZeroU32::MIN)
+                },
+                validity: if nullable {
+                    Some(BufferMut::Owned(vec![0u8; 8].into_boxed_slice()))
+                } else {
+                    None
+                },
+            });
+            ReadBuilder::new(array.for_read()?)?
+                .register_raw(field, &scratch)
+                .map(|_| ())
+        };
+
+        assert!(register("b", true, true).is_ok());
+        assert!(matches!(
+            register("b", false, true),
+            Err(Error::InvalidArgument(_))
+        ));
+        assert!(matches!(
+            register("b", true, false),
+            Err(Error::InvalidArgument(_))
+        ));
+        assert!(matches!(
+            register("a", true, false),
+            Err(Error::InvalidArgument(_))
+        ));
+        assert!(matches!(
+            register("a", false, true),
+            Err(Error::InvalidArgument(_))
+        ));
+
+        Ok(())
+    }
+
+    /// Test that the estimated result size is enough to hold the results.
+    #[test]
+    fn est_result_size() -> TileDBResult<()> {
+        let mut array = TestArray::new("est_result_size", {
+            let mut schema =
+                quickstart::Builder::new(ArrayType::Sparse).build();
+            schema.attributes.push(AttributeData {
+                name: "b".to_owned(),
+                datatype: Datatype::StringAscii,
+                cell_val_num: Some(CellValNum::Var),
+                ..Default::default()
+            });
+            std::rc::Rc::new(schema)
+        })?;
+
+        {
+            let rows = vec![1, 2, 3];
+            let cols = vec![4, 3, 2];
+            let a = vec![10, 20, 30];
+            let b = vec!["foo", "bar", "bazqux"];
+            let w = WriteBuilder::new(array.for_write()?)?
+                .layout(QueryLayout::Unordered)?
+                .data_typed("rows", &rows)?
+                .data_typed("cols", &cols)?
+                .data_typed("a", &a)?
+                .data_typed("b", &b)?
+                .build();
+            w.submit()?;
+            w.finalize()?;
+        }
+
+        let mut q = ReadBuilder::new(array.for_read()?)?
+            .layout(QueryLayout::RowMajor)?
+            .register_constructor::<_, Vec<i32>>("a", Default::default())?
+            .register_constructor::<_, Vec<String>>("b", Default::default())?
+            .start_subarray()?
+            .add_range("rows", &[1, 4])?
+            .add_range("cols", &[1, 4])?
+            .finish_subarray()?
+            .build();
+
+        let est_a = q.est_result_size("a")?;
+        let est_b = q.est_result_size("b")?;
+
+        let (b, (a, ())) = q.execute()?;
+
+        assert!(
+            est_a.data_bytes >= (a.len() * std::mem::size_of::<i32>()) as u64
+        );
+        assert_eq!(None, est_a.offsets_bytes);
+        assert_eq!(None, est_a.validity_bytes);
+
+        assert!(
+            est_b.data_bytes >= b.iter().map(|s| s.len()).sum::<usize>() as u64
+        );
+        assert!(
+            est_b.offsets_bytes.unwrap()
+                >= (b.len() * std::mem::size_of::<u64>()) as u64
+        );
+        assert_eq!(None, est_b.validity_bytes);
+
+        Ok(())
+    }
+
+    /// Test that the estimated memory is enough to hold the results
+    /// of all of the requested fields.
+    #[test]
+    fn estimate_memory() -> TileDBResult<()> {
+        use proptest::test_runner::TestRunner;
+
+        let mut array = TestArray::new("estimate_memory", {
+            let mut schema =
+                quickstart::Builder::new(ArrayType::Sparse).build();
+            schema.attributes.push(AttributeData {
+                name: "b".to_owned(),
+                datatype: Datatype::StringAscii,
+                cell_val_num: Some(CellValNum::Var),
+                ..Default::default()
+            });
+            std::rc::Rc::new(schema)
+        })?;
+        let input = array.arbitrary_input(&mut TestRunner::deterministic());
+        array.try_insert(&input)?;
+
+        let b = ReadBuilder::new(array.for_read()?)?
+            .start_subarray()?
+            .add_range("rows", &[1, 4])?
+            .add_range("cols", &[1, 4])?
+            .finish_subarray()?;
+        let est_memory = b.estimate_memory(&["a", "b"])?;
+        assert!(est_memory <= b.estimate_memory(&[])?);
+
+        // data and offsets of both fields, and the extra offset of `b`
+        let actual = array
+            .for_read()?
+            .read_all(&["a", "b"])?
+            .iter()
+            .map(|(_, output)| {
+                let offsets = if output.cell_structure().is_var() {
+                    (output.ncells + 1) * std::mem::size_of::<u64>()
+                } else {
+                    0
+                };
+                output.nvalues() * output.datatype.size() + offsets
+            })
+            .sum::<usize>();
+        assert!(est_memory >= actual);
+
+        Ok(())
+    }
+
+    /// Accumulates the coordinates of a quickstart array
+    /// with string rows from each step of a query.
+    #[derive(Default)]
+    struct CoordinatesCallback {
+        cells: Vec<(String, i32)>,
+    }
+
+    impl CoordinatesCallback {
+        fn append(
+            &mut self,
+            args: Vec<TypedRawReadOutput>,
+        ) -> TileDBResult<()> {
+            assert_eq!(2, args.len());
+
+            let TypedQueryBuffers::UInt8(ref rows) = args[0].buffers else {
+                unreachable!()
+            };
+            let rows = VarDataIterator::try_from(RawReadOutput {
+                ncells: args[0].ncells,
+                input: rows.borrow(),
+            })?
+            .map(|s| String::from_utf8(s.to_vec()).unwrap())
+            .collect::<Vec<String>>();
+
+            let TypedQueryBuffers::Int32(ref cols) = args[1].buffers else {
+                unreachable!()
+            };
+            let cols = CellStructureSingleIterator::try_from(RawReadOutput {
+                ncells: args[1].ncells,
+                input: cols.borrow(),
+            })?
+            .collect::<Vec<i32>>();
+
+            self.cells.extend(rows.into_iter().zip(cols));
+            Ok(())
+        }
+    }
+
+    impl ReadCallbackVarArg for CoordinatesCallback {
+        type Intermediate = ();
+        type Final = Vec<(String, i32)>;
+        type Error = Error;
+
+        fn intermediate_result(
+            &mut self,
+            args: Vec<TypedRawReadOutput>,
+        ) -> Result<Self::Intermediate, Self::Error> {
+            self.append(args)
+        }
+
+        fn final_result(
+            mut self,
+            args: Vec<TypedRawReadOutput>,
+        ) -> Result<Self::Final, Self::Error> {
+            self.append(args)?;
+            Ok(self.cells)
+        }
+    }
+
+    #[test]
+    fn coordinates_only() -> TileDBResult<()> {
+        let ctx = Context::new()?;
+        let test_uri = uri::get_uri_generator()
+            .map_err(|e| Error::Other(e.to_string()))?;
+        let array_uri = create_quickstart_sparse_string(&test_uri, &ctx)?;
+
+        let rows = vec!["foo", "bar", "baz"]
+            .into_iter()
+            .map(|s| s.to_string())
+            .collect::<Vec<String>>();
+        let cols = vec![1i32, 2, 3];
+        let atts = vec![10i32, 20, 30];
+        {
+            let array = Array::open(&ctx, &array_uri, Mode::Write)?;
+            let q = WriteBuilder::new(array)?
+                .data("rows", &rows)?
+                .data("cols", &cols)?
+                .data("a", &atts)?
+                .build();
+            q.submit()?;
+            q.finalize()?;
+        }
+
+        let array = Array::open(&ctx, &array_uri, Mode::Read)?;
+        let mut query = ReadBuilder::new(array)?
+            .with_full_domain()?
+            .coordinates_only(CoordinatesCallback::default())?
+            .build();
+        let (mut cells, ()) = query.execute()?;
+
+        cells.sort();
+        assert_eq!(
+            vec![
+                ("bar".to_owned(), 2),
+                ("baz".to_owned(), 3),
+                ("foo".to_owned(), 1)
+            ],
+            cells
+        );
+
+        // the scratch space of each dimension honors its buffer capacity,
+        // so reading one record at a time requires several steps
+        let array = Array::open(&ctx, &array_uri, Mode::Read)?;
+        let mut query = ReadBuilder::new(array)?
+            .with_full_domain()?
+            .with_buffer_capacity("rows", 1)?
+            .with_buffer_capacity("cols", 1)?
+            .coordinates_only(CoordinatesCallback::default())?
+            .build();
+        assert!(!query.step()?.is_final());
+        let (mut cells_stepped, ()) = query.execute()?;
+        cells_stepped.sort();
+        assert_eq!(cells, cells_stepped);
+
+        Ok(())
+    }
+}
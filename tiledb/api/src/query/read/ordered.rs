@@ -0,0 +1,191 @@
+use std::cmp::Ordering;
+
+use crate::array::read_all::ReadAllCallback;
+use crate::query::read::output::TypedRawReadOutput;
+use crate::query::read::ReadCallbackVarArg;
+
+/// Accumulates the results of each step of a read query into memory,
+/// and then sorts the cells of the final result by the values of some
+/// of its fields. See [ReadQueryBuilder::with_output_order].
+///
+/// The final result pairs the name of each field with its sorted values.
+///
+/// [ReadQueryBuilder::with_output_order]: crate::query::ReadQueryBuilder::with_output_order
+pub struct OutputOrderCallback {
+    fields: Vec<String>,
+    keys: Vec<usize>,
+    accumulate: ReadAllCallback,
+}
+
+impl OutputOrderCallback {
+    /// Returns a callback for a query which reads `fields`,
+    /// which sorts the cells by the fields at the positions `keys`.
+    pub(crate) fn new(fields: Vec<String>, keys: Vec<usize>) -> Self {
+        let accumulate = ReadAllCallback::new(fields.len());
+        OutputOrderCallback {
+            fields,
+            keys,
+            accumulate,
+        }
+    }
+}
+
+impl ReadCallbackVarArg for OutputOrderCallback {
+    type Intermediate = ();
+    type Final = Vec<(String, TypedRawReadOutput<'static>)>;
+    type Error = crate::error::Error;
+
+    fn intermediate_result(
+        &mut self,
+        args: Vec<TypedRawReadOutput>,
+    ) -> Result<Self::Intermediate, Self::Error> {
+        self.accumulate.intermediate_result(args)
+    }
+
+    fn final_result(
+        self,
+        args: Vec<TypedRawReadOutput>,
+    ) -> Result<Self::Final, Self::Error> {
+        let outputs = self.accumulate.final_result(args)?;
+
+        let ncells = outputs.first().map(|o| o.ncells).unwrap_or(0);
+        let mut idx = (0..ncells).collect::<Vec<usize>>();
+        idx.sort_by(|l, r| {
+            self.keys
+                .iter()
+                .map(|key| outputs[*key].cmp_cells(*l, *r))
+                .find(|o| o.is_ne())
+                .unwrap_or(Ordering::Equal)
+        });
+
+        Ok(self
+            .fields
+            .into_iter()
+            .zip(outputs.iter().map(|output| output.take(&idx)))
+            .collect())
+    }
+
+    fn cleared(&self) -> Option<Self> {
+        Some(Self::new(self.fields.clone(), self.keys.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use super::*;
+    use crate::error::Error;
+    use crate::query::buffer::TypedQueryBuffers;
+    use crate::query::read::output::{
+        CellStructureSingleIterator, RawReadOutput,
+    };
+    use crate::query::ReadQueryBuilder;
+    use crate::tests::examples::{quickstart, TestArray};
+    use crate::tests::prelude::*;
+    use crate::Result as TileDBResult;
+
+    #[test]
+    fn with_output_order_hilbert() -> TileDBResult<()> {
+        let schema = {
+            let mut b = quickstart::Builder::new(ArrayType::Sparse);
+            b.schema.cell_order = Some(CellOrder::Hilbert);
+            b.schema.tile_order = None;
+            Rc::new(b.build())
+        };
+        let mut array = TestArray::new("with_output_order_hilbert", schema)?;
+
+        let mut cells = vec![];
+        for r in 1..=4 {
+            for c in 1..=4 {
+                cells.push((r, c, r * 10 + c));
+            }
+        }
+        {
+            let rows = cells.iter().map(|(r, _, _)| *r).collect::<Vec<i32>>();
+            let cols = cells.iter().map(|(_, c, _)| *c).collect::<Vec<i32>>();
+            let atts = cells.iter().map(|(_, _, a)| *a).collect::<Vec<i32>>();
+            let q = WriteBuilder::new(array.for_write()?)?
+                .data("rows", &rows)?
+                .data("cols", &cols)?
+                .data("a", &atts)?
+                .build();
+            q.submit()?;
+            q.finalize()?;
+        }
+
+        let read_int32 = |output: &TypedRawReadOutput| -> Vec<i32> {
+            let TypedQueryBuffers::Int32(ref qb) = output.buffers else {
+                unreachable!()
+            };
+            CellStructureSingleIterator::try_from(RawReadOutput {
+                ncells: output.ncells,
+                input: qb.borrow(),
+            })
+            .unwrap()
+            .collect::<Vec<i32>>()
+        };
+
+        let read_ordered = |keys: &[&str]| {
+            let mut q = ReadBuilder::new(array.for_read()?)?
+                .with_output_order(
+                    keys.iter().map(|k| k.to_string()).collect(),
+                )?
+                .build();
+            let (outputs, _) = q.execute()?;
+            assert_eq!(
+                vec!["rows", "cols", "a"],
+                outputs.iter().map(|(f, _)| f.as_str()).collect::<Vec<_>>()
+            );
+            Ok::<_, Error>(
+                outputs
+                    .iter()
+                    .map(|(_, output)| read_int32(output))
+                    .collect::<Vec<Vec<i32>>>(),
+            )
+        };
+
+        // row-major
+        let outputs = read_ordered(&["rows", "cols"])?;
+        assert_eq!(
+            cells.iter().map(|(_, _, a)| *a).collect::<Vec<i32>>(),
+            outputs[2]
+        );
+
+        // the same order, reading only some fields
+        let outputs = array
+            .for_read()?
+            .read_all_ordered(&["a"], &["rows", "cols"])?;
+        assert_eq!(1, outputs.len());
+        assert_eq!(
+            cells.iter().map(|(_, _, a)| *a).collect::<Vec<i32>>(),
+            read_int32(&outputs[0].1)
+        );
+
+        // column-major
+        let outputs = read_ordered(&["cols", "rows"])?;
+        let mut expect = cells.clone();
+        expect.sort_by_key(|(r, c, _)| (*c, *r));
+        assert_eq!(
+            expect.iter().map(|(r, _, _)| *r).collect::<Vec<i32>>(),
+            outputs[0]
+        );
+        assert_eq!(
+            expect.iter().map(|(_, c, _)| *c).collect::<Vec<i32>>(),
+            outputs[1]
+        );
+        assert_eq!(
+            expect.iter().map(|(_, _, a)| *a).collect::<Vec<i32>>(),
+            outputs[2]
+        );
+
+        // only dimensions can be keys
+        assert!(matches!(
+            ReadBuilder::new(array.for_read()?)?
+                .with_output_order(vec!["a".to_owned()]),
+            Err(Error::InvalidArgument(_))
+        ));
+
+        Ok(())
+    }
+}
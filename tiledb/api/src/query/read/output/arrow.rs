@@ -148,6 +148,7 @@ mod tests {
     use proptest::prelude::*;
 
     use crate::array::CellValNum;
+    use crate::query::buffer::{Buffer, QueryBuffers};
     use crate::query::read::output::strategy::RawReadOutputParameters;
     use crate::query::read::output::CellStructure;
     use crate::Datatype;
@@ -425,4 +426,43 @@ mod tests {
             do_raw_read_to_record_batch(rr)
         });
     }
+
+    /// Owned read results are moved into the arrow array without
+    /// copying the data or offsets. Validity must be repacked.
+    #[test]
+    fn raw_read_to_arrow_zero_copy() {
+        let data = vec![1i32, 2, 3, 4, 5, 6, 0, 0].into_boxed_slice();
+        let offsets = vec![0u64, 2, 2, 6, 0].into_boxed_slice();
+        let data_ptr = data.as_ptr() as *const u8;
+        let offsets_ptr = offsets.as_ptr() as *const u8;
+
+        let rr = TypedRawReadOutput::new(
+            Datatype::Int32,
+            RawReadOutput {
+                ncells: 3,
+                input: QueryBuffers {
+                    data: Buffer::Owned(data),
+                    cell_structure: CellStructure::Var(Buffer::Owned(offsets)),
+                    validity: Some(Buffer::Owned(
+                        vec![1u8, 0, 1, 1].into_boxed_slice(),
+                    )),
+                },
+            },
+        );
+
+        let arrow = Arc::<dyn ArrowArray>::try_from(rr).unwrap();
+        let gl = arrow
+            .as_any()
+            .downcast_ref::<GenericListArray<i64>>()
+            .unwrap();
+
+        assert_eq!(data_ptr, gl.values().to_data().buffers()[0].as_ptr());
+        assert_eq!(offsets_ptr, gl.offsets().inner().inner().as_ptr());
+
+        assert_eq!(vec![0i64, 2, 2, 6], gl.offsets().to_vec());
+        assert_eq!(
+            vec![true, false, true],
+            gl.nulls().unwrap().iter().collect::<Vec<bool>>()
+        );
+    }
 }
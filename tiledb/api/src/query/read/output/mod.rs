@@ -1,8 +1,10 @@
+use std::cmp::Ordering;
 use std::fmt::{Debug, Formatter, Result as FmtResult};
 use std::iter::FusedIterator;
 use std::num::{NonZeroU32, NonZeroUsize};
 
 use anyhow::anyhow;
+use tiledb_common::datatype::physical::BitsOrd;
 
 use crate::array::CellValNum;
 use crate::datatype::PhysicalType;
@@ -43,6 +45,90 @@ impl<C> RawReadOutput<'_, C> {
         self.nvalues() * std::mem::size_of::<C>()
     }
 
+    /// Appends the cells of `other` to the cells of `self`.
+    ///
+    /// The values and validity of `other` are concatenated after
+    /// those of `self`, and the offsets of `other` are rebased to begin
+    /// after the last value of `self`. The buffers of `self` become owned.
+    ///
+    /// Returns an error if `self` and `other` have different cell structure
+    /// or nullability.
+    pub fn append(&mut self, other: &RawReadOutput<C>) -> TileDBResult<()>
+    where
+        C: Copy,
+    {
+        append_query_buffers(
+            (self.ncells, &mut self.input),
+            (other.ncells, &other.input),
+        )?;
+        self.ncells += other.ncells;
+        Ok(())
+    }
+
+    /// Returns the values of cell `cell`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `cell` is not less than `self.ncells`.
+    pub fn cell_values(&self, cell: usize) -> &[C] {
+        assert!(cell < self.ncells);
+        match self.input.cell_structure {
+            CellStructure::Fixed(nz) => {
+                let len = nz.get() as usize;
+                &self.input.data[cell * len..(cell + 1) * len]
+            }
+            CellStructure::Var(ref offsets) => {
+                &self.input.data
+                    [offsets[cell] as usize..offsets[cell + 1] as usize]
+            }
+        }
+    }
+
+    /// Returns a copy of the cells of `self` at the positions `cells`,
+    /// in that order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any position is not less than `self.ncells`.
+    pub fn take(&self, cells: &[usize]) -> RawReadOutput<'static, C>
+    where
+        C: Copy,
+    {
+        let mut data = Vec::with_capacity(self.nvalues());
+        let cell_structure = match self.input.cell_structure {
+            CellStructure::Fixed(nz) => {
+                for c in cells.iter() {
+                    data.extend_from_slice(self.cell_values(*c));
+                }
+                CellStructure::Fixed(nz)
+            }
+            CellStructure::Var(_) => {
+                let mut offsets = Vec::with_capacity(cells.len() + 1);
+                offsets.push(0u64);
+                for c in cells.iter() {
+                    data.extend_from_slice(self.cell_values(*c));
+                    offsets.push(data.len() as u64);
+                }
+                CellStructure::Var(offsets.into())
+            }
+        };
+        let validity = self.input.validity.as_ref().map(|validity| {
+            cells
+                .iter()
+                .map(|c| validity[*c])
+                .collect::<Vec<u8>>()
+                .into()
+        });
+        RawReadOutput {
+            ncells: cells.len(),
+            input: QueryBuffers {
+                data: data.into(),
+                cell_structure,
+                validity,
+            },
+        }
+    }
+
     #[cfg(feature = "serde")]
     pub fn to_json(&self) -> serde_json::value::Value
     where
@@ -122,6 +208,137 @@ impl<'data> TypedRawReadOutput<'data> {
     pub fn cell_structure(&self) -> &CellStructure<'data> {
         self.buffers.cell_structure()
     }
+
+    /// Appends the cells of `other` to the cells of `self`.
+    /// See [RawReadOutput::append].
+    ///
+    /// Returns an error if `self` and `other` have different datatypes,
+    /// cell structure, or nullability.
+    pub fn append(&mut self, other: &TypedRawReadOutput) -> TileDBResult<()> {
+        if self.datatype != other.datatype {
+            return Err(Error::InvalidArgument(anyhow!(
+                "Cannot append outputs with different datatypes: {} and {}",
+                self.datatype,
+                other.datatype
+            )));
+        }
+        let ncells = self.ncells;
+        typed_query_buffers_go!(self.buffers, DT, ref mut qb, {
+            let Ok(other_qb) =
+                QueryBuffers::<DT>::try_from(other.buffers.borrow())
+            else {
+                unreachable!("Physical type is determined by datatype")
+            };
+            append_query_buffers((ncells, qb), (other.ncells, &other_qb))
+        })?;
+        self.ncells += other.ncells;
+        Ok(())
+    }
+}
+
+impl TypedRawReadOutput<'_> {
+    /// Returns a copy of the cells of `self` at the positions `cells`,
+    /// in that order. See [RawReadOutput::take].
+    pub fn take(&self, cells: &[usize]) -> TypedRawReadOutput<'static> {
+        typed_query_buffers_go!(self.buffers, _DT, ref qb, {
+            let rr = RawReadOutput {
+                ncells: self.ncells,
+                input: qb.borrow(),
+            };
+            TypedRawReadOutput::new(self.datatype, rr.take(cells))
+        })
+    }
+
+    /// Compares the values of cells `a` and `b`.
+    ///
+    /// Values are compared by their physical type using
+    /// [BitsOrd], and cells with multiple values are compared
+    /// lexicographically. Validity is not considered.
+    pub fn cmp_cells(&self, a: usize, b: usize) -> Ordering {
+        typed_query_buffers_go!(self.buffers, _DT, ref qb, {
+            let rr = RawReadOutput {
+                ncells: self.ncells,
+                input: qb.borrow(),
+            };
+            rr.cell_values(a).bits_cmp(rr.cell_values(b))
+        })
+    }
+}
+
+/// Appends the first `src.0` cells of `src.1` after the first `dst.0` cells of `dst.1`.
+fn append_query_buffers<C>(
+    dst: (usize, &mut QueryBuffers<C>),
+    src: (usize, &QueryBuffers<C>),
+) -> TileDBResult<()>
+where
+    C: Copy,
+{
+    let ((ncells, dst), (src_ncells, src)) = (dst, src);
+
+    let (expected, found) = (
+        dst.cell_structure.as_cell_val_num(),
+        src.cell_structure.as_cell_val_num(),
+    );
+    if expected != found {
+        return Err(Error::UnexpectedCellStructure { expected, found });
+    }
+    if dst.validity.is_some() != src.validity.is_some() {
+        return Err(Error::InvalidArgument(anyhow!(
+            "Cannot append outputs with different nullability"
+        )));
+    }
+
+    let nvalues = RawReadOutput {
+        ncells,
+        input: dst.borrow(),
+    }
+    .nvalues();
+    let src_nvalues = RawReadOutput {
+        ncells: src_ncells,
+        input: src.borrow(),
+    }
+    .nvalues();
+
+    let mut data = into_vec(std::mem::replace(&mut dst.data, Buffer::Empty));
+    data.truncate(nvalues);
+    data.extend_from_slice(&src.data[0..src_nvalues]);
+    dst.data = data.into();
+
+    if let CellStructure::Var(ref mut offsets) = dst.cell_structure {
+        let mut rebased = into_vec(std::mem::replace(offsets, Buffer::Empty));
+        rebased.truncate(ncells);
+        if src_ncells == 0 {
+            rebased.push(nvalues as u64);
+        } else {
+            let src_offsets = src.cell_structure.offsets_ref().unwrap();
+            rebased.extend(
+                src_offsets[0..=src_ncells]
+                    .iter()
+                    .map(|o| nvalues as u64 + *o),
+            );
+        }
+        *offsets = rebased.into();
+    }
+
+    if let Some(ref mut validity) = dst.validity {
+        let mut concat = into_vec(std::mem::replace(validity, Buffer::Empty));
+        concat.truncate(ncells);
+        concat
+            .extend_from_slice(&src.validity.as_ref().unwrap()[0..src_ncells]);
+        *validity = concat.into();
+    }
+
+    Ok(())
+}
+
+fn into_vec<C>(buffer: Buffer<C>) -> Vec<C>
+where
+    C: Copy,
+{
+    match buffer {
+        Buffer::Owned(values) => values.into_vec(),
+        buffer => buffer.to_vec(),
+    }
 }
 
 /// Represents either a fixed number of values per cell,
@@ -508,11 +725,50 @@ where
 pub struct FieldScratchAllocator {
     pub cell_val_num: CellValNum,
     pub record_capacity: NonZeroUsize,
+    /// The number of values to allocate per record for the data
+    /// of a var-sized field.
+    var_values_per_record: NonZeroUsize,
     pub is_nullable: bool,
 }
 
 impl FieldScratchAllocator {
     pub const DEFAULT_MEMORY_LIMIT: usize = 64 * 1024 * 1024;
+
+    /// The default number of values per record of a var-sized field,
+    /// for when there is no better estimate.
+    pub const DEFAULT_VAR_VALUES_PER_RECORD: NonZeroUsize =
+        match NonZeroUsize::new(64) {
+            Some(n) => n,
+            None => unreachable!(),
+        };
+
+    /// Returns an allocator for `record_capacity` records of a field
+    /// with the given shape. The data of a var-sized field is allocated
+    /// using [Self::DEFAULT_VAR_VALUES_PER_RECORD].
+    pub fn new(
+        cell_val_num: CellValNum,
+        record_capacity: NonZeroUsize,
+        is_nullable: bool,
+    ) -> Self {
+        FieldScratchAllocator {
+            cell_val_num,
+            record_capacity,
+            var_values_per_record: Self::DEFAULT_VAR_VALUES_PER_RECORD,
+            is_nullable,
+        }
+    }
+
+    /// Sets the number of values to allocate per record for the data
+    /// of a var-sized field.
+    pub fn with_var_values_per_record(
+        self,
+        var_values_per_record: NonZeroUsize,
+    ) -> Self {
+        FieldScratchAllocator {
+            var_values_per_record,
+            ..self
+        }
+    }
 }
 
 impl<C> ScratchAllocator<C> for FieldScratchAllocator
@@ -530,9 +786,8 @@ where
                 )
             }
             CellValNum::Var => {
-                let values_per_record = 64; /* TODO: get some kind of hint from the schema */
-                let byte_capacity =
-                    self.record_capacity.get() * values_per_record;
+                let byte_capacity = self.record_capacity.get()
+                    * self.var_values_per_record.get();
                 (
                     byte_capacity,
                     ScratchCellStructure::Var(
@@ -923,6 +1178,122 @@ mod tests {
     use super::*;
     use crate::query::buffer::Buffer;
 
+    #[test]
+    fn raw_read_output_append_var() {
+        let mut lhs = RawReadOutput {
+            ncells: 2,
+            input: QueryBuffers {
+                data: Buffer::from(vec![1u8, 2, 3, 99]),
+                cell_structure: CellStructure::Var(vec![0u64, 1, 3].into()),
+                validity: Some(vec![1u8, 0, 7].into()),
+            },
+        };
+        let rhs_data = [4u8, 5, 6, 7, 99, 99];
+        let rhs_offsets = [0u64, 3, 4, 99];
+        let rhs_validity = [1u8, 1, 99];
+        let rhs = RawReadOutput {
+            ncells: 2,
+            input: QueryBuffers {
+                data: Buffer::Borrowed(&rhs_data),
+                cell_structure: CellStructure::Var(Buffer::Borrowed(
+                    &rhs_offsets,
+                )),
+                validity: Some(Buffer::Borrowed(&rhs_validity)),
+            },
+        };
+
+        lhs.append(&rhs).unwrap();
+        assert_eq!(4, lhs.ncells);
+        assert_eq!(7, lhs.nvalues());
+        assert_eq!(&[1u8, 2, 3, 4, 5, 6, 7], lhs.input.data.as_ref());
+        assert_eq!(
+            Some(&[0u64, 1, 3, 6, 7][..]),
+            lhs.input.cell_structure.offsets_ref()
+        );
+        assert_eq!(
+            &[1u8, 0, 1, 1],
+            lhs.input.validity.as_ref().unwrap().as_ref()
+        );
+
+        let cells = VarDataIterator::try_from(lhs)
+            .unwrap()
+            .map(|s| s.to_vec())
+            .collect::<Vec<Vec<u8>>>();
+        assert_eq!(vec![vec![1u8], vec![2, 3], vec![4, 5, 6], vec![7]], cells);
+    }
+
+    #[test]
+    fn raw_read_output_append_fixed() {
+        let mut lhs = RawReadOutput {
+            ncells: 0,
+            input: QueryBuffers {
+                data: Buffer::<u64>::Empty,
+                cell_structure: CellStructure::Fixed(
+                    NonZeroU32::new(2).unwrap(),
+                ),
+                validity: None,
+            },
+        };
+        for batch in [vec![1u64, 2, 3, 4, 99], vec![5, 6, 99, 99]] {
+            let ncells = (batch.len() - 1) / 2;
+            let rhs = RawReadOutput {
+                ncells,
+                input: QueryBuffers {
+                    data: Buffer::Owned(batch.into_boxed_slice()),
+                    cell_structure: CellStructure::Fixed(
+                        NonZeroU32::new(2).unwrap(),
+                    ),
+                    validity: None,
+                },
+            };
+            lhs.append(&rhs).unwrap();
+        }
+        assert_eq!(3, lhs.ncells);
+        assert_eq!(&[1u64, 2, 3, 4, 5, 6], lhs.input.data.as_ref());
+    }
+
+    #[test]
+    fn raw_read_output_append_mismatch() {
+        let output = |cell_structure, validity| RawReadOutput {
+            ncells: 1,
+            input: QueryBuffers {
+                data: Buffer::from(vec![1u64, 2]),
+                cell_structure,
+                validity,
+            },
+        };
+
+        let mut fixed = output(CellStructure::single(), None);
+        let var = output(CellStructure::Var(vec![0u64, 1].into()), None);
+        assert!(matches!(
+            fixed.append(&var),
+            Err(Error::UnexpectedCellStructure { .. })
+        ));
+
+        let nullable = output(CellStructure::single(), Some(vec![1u8].into()));
+        assert!(matches!(
+            fixed.append(&nullable),
+            Err(Error::InvalidArgument(_))
+        ));
+
+        let typed = |datatype, output: RawReadOutput<'static, u64>| {
+            TypedRawReadOutput::new(datatype, output)
+        };
+        let mut lhs =
+            typed(Datatype::UInt64, output(CellStructure::single(), None));
+        let rhs = typed(
+            Datatype::DateTimeSecond,
+            output(CellStructure::single(), None),
+        );
+        assert!(matches!(lhs.append(&rhs), Err(Error::InvalidArgument(_))));
+        assert_eq!(1, lhs.ncells);
+
+        let rhs =
+            typed(Datatype::UInt64, output(CellStructure::single(), None));
+        lhs.append(&rhs).unwrap();
+        assert_eq!(2, lhs.ncells);
+    }
+
     #[test]
     fn cell_val_num_single_iterator() {
         let bufs: QueryBuffers<u64> = QueryBuffers {
@@ -0,0 +1,240 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+
+use crate::array::{Array, ArrayOpener, Mode};
+use crate::config::Config;
+use crate::context::{Context, ContextBound};
+use crate::error::Error;
+use crate::query::read::output::TypedRawReadOutput;
+use crate::range::Range;
+use crate::Result as TileDBResult;
+
+/// The cells of a single partition of a [ParallelReader],
+/// in the same form as the result of [Array::read_all].
+pub type PartitionOutput = Vec<(String, TypedRawReadOutput<'static>)>;
+
+/// Reads several subarrays of an array concurrently.
+///
+/// Each partition is a subarray, given as a list of ranges for each
+/// dimension as in [Array::read_subarray], and is read in its own query
+/// on a worker thread. The results are returned in partition order.
+///
+/// A [Context] and the [Array]s opened with it cannot be shared between
+/// threads, so each worker allocates its own context from the configuration
+/// of the original array's context, and opens its own handle to the array
+/// at the same timestamps as the original array. Concurrent reads over
+/// separately opened handles to the same array at fixed timestamps are safe,
+/// and each worker observes the same fragments as the original array.
+/// Each query is submitted and completed by a single worker thread.
+///
+/// Each partition is read into memory in full, so this is best suited
+/// to partitions of modest size, such as those produced by
+/// [crate::array::Dimension::tile_partition].
+pub struct ParallelReader {
+    uri: String,
+    config: Vec<(String, String)>,
+    timestamps: (u64, u64),
+    fields: Vec<String>,
+    num_threads: usize,
+}
+
+impl ParallelReader {
+    /// Returns a reader of the values of `fields` from `array`,
+    /// or of all fields if `fields` is empty.
+    ///
+    /// By default the number of worker threads is the
+    /// available parallelism of the host.
+    pub fn new(array: &Array, fields: &[&str]) -> TileDBResult<Self> {
+        let config = array
+            .context()
+            .get_config()?
+            .into_iter()
+            .collect::<Vec<(String, String)>>();
+        Ok(ParallelReader {
+            uri: array.uri().to_owned(),
+            config,
+            timestamps: array.timestamp_range()?,
+            fields: fields.iter().map(|f| f.to_string()).collect(),
+            num_threads: thread::available_parallelism()
+                .map(|n| n.get())
+                .unwrap_or(1),
+        })
+    }
+
+    /// Sets the maximum number of partitions which are read at once.
+    pub fn num_threads(self, num_threads: usize) -> Self {
+        ParallelReader {
+            num_threads: std::cmp::max(1, num_threads),
+            ..self
+        }
+    }
+
+    /// Reads each of `partitions` and returns their cells in the same order.
+    ///
+    /// Returns the first error, in partition order, if any partition
+    /// could not be read.
+    pub fn read(
+        &self,
+        partitions: &[Vec<Vec<Range>>],
+    ) -> TileDBResult<Vec<PartitionOutput>> {
+        let next = AtomicUsize::new(0);
+        let results = Mutex::new(
+            (0..partitions.len())
+                .map(|_| None)
+                .collect::<Vec<Option<TileDBResult<PartitionOutput>>>>(),
+        );
+
+        thread::scope(|scope| {
+            let nworkers = std::cmp::min(self.num_threads, partitions.len());
+            for _ in 0..nworkers {
+                scope.spawn(|| {
+                    let array = match self.open() {
+                        Ok(array) => array,
+                        Err(e) => {
+                            // report the error for the next partition
+                            // and leave the rest to the other workers
+                            let p = next.fetch_add(1, Ordering::Relaxed);
+                            if p < partitions.len() {
+                                results.lock().unwrap()[p] = Some(Err(e));
+                            }
+                            return;
+                        }
+                    };
+                    let fields = self
+                        .fields
+                        .iter()
+                        .map(|f| f.as_str())
+                        .collect::<Vec<&str>>();
+                    loop {
+                        let p = next.fetch_add(1, Ordering::Relaxed);
+                        if p >= partitions.len() {
+                            break;
+                        }
+                        let result =
+                            array.read_subarray(&fields, partitions[p].clone());
+                        results.lock().unwrap()[p] = Some(result);
+                    }
+                });
+            }
+        });
+
+        results
+            .into_inner()
+            .unwrap()
+            .into_iter()
+            .map(|r| {
+                r.unwrap_or_else(|| {
+                    Err(Error::Internal(
+                        "Partition was not read by any worker".to_owned(),
+                    ))
+                })
+            })
+            .collect()
+    }
+
+    /// Opens a handle to the array for use on the current thread.
+    fn open(&self) -> TileDBResult<Array> {
+        let mut config = Config::new()?;
+        for (key, value) in self.config.iter() {
+            config.set(key, value)?;
+        }
+        let context = Context::from_config(&config)?;
+        ArrayOpener::new(&context, &self.uri, Mode::Read)?
+            .start_timestamp(self.timestamps.0)?
+            .end_timestamp(self.timestamps.1)?
+            .open()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use super::*;
+    use crate::array::ArrayType;
+    use crate::query::buffer::TypedQueryBuffers;
+    use crate::query::{Query, QueryBuilder, WriteBuilder};
+    use crate::tests::examples::quickstart::Builder as QuickstartBuilder;
+    use crate::tests::examples::TestArray;
+
+    /// Concurrent reads of disjoint partitions of a shared array
+    /// produce the same cells as serial reads of the same partitions.
+    #[test]
+    fn parallel_read() -> TileDBResult<()> {
+        let schema = {
+            let b = QuickstartBuilder::new(ArrayType::Sparse);
+            Rc::new(b.build())
+        };
+        let mut array = TestArray::new("parallel_read", schema)?;
+
+        let (mut rows, mut cols, mut atts) = (vec![], vec![], vec![]);
+        for r in 1..=4 {
+            for c in 1..=4 {
+                rows.push(r);
+                cols.push(c);
+                atts.push(r * 10 + c);
+            }
+        }
+        {
+            let q = WriteBuilder::new(array.for_write()?)?
+                .data("rows", &rows)?
+                .data("cols", &cols)?
+                .data("a", &atts)?
+                .build();
+            q.submit()?;
+            q.finalize()?;
+        }
+
+        let values = |output: &TypedRawReadOutput| -> Vec<i32> {
+            let TypedQueryBuffers::Int32(ref qb) = output.buffers else {
+                unreachable!()
+            };
+            qb.data[..output.ncells].to_vec()
+        };
+
+        let array = array.for_read()?;
+        let rows_dim = array.schema()?.domain()?.dimension(0)?;
+        let partitions = (1..=4)
+            .flat_map(|n| rows_dim.tile_partition(n).unwrap())
+            .map(|r| vec![vec![r], vec![]])
+            .collect::<Vec<Vec<Vec<Range>>>>();
+
+        let serial = partitions
+            .iter()
+            .map(|p| array.read_subarray(&["a"], p.clone()))
+            .collect::<TileDBResult<Vec<PartitionOutput>>>()?;
+
+        for num_threads in [1, 2, 8] {
+            let parallel = ParallelReader::new(&array, &["a"])?
+                .num_threads(num_threads)
+                .read(&partitions)?;
+            assert_eq!(serial.len(), parallel.len());
+            for (s, p) in serial.iter().zip(parallel.iter()) {
+                assert_eq!(1, p.len());
+                assert_eq!(s[0].0, p[0].0);
+                assert_eq!(s[0].1.ncells, p[0].1.ncells);
+                assert_eq!(values(&s[0].1), values(&p[0].1));
+            }
+        }
+
+        // each set of partitions covers every cell exactly once
+        let ncells = serial.iter().map(|p| p[0].1.ncells).sum::<usize>();
+        assert_eq!(4 * atts.len(), ncells);
+
+        Ok(())
+    }
+
+    #[test]
+    fn parallel_read_error() -> TileDBResult<()> {
+        let schema = Rc::new(QuickstartBuilder::new(ArrayType::Sparse).build());
+        let array = TestArray::new("parallel_read_error", schema)?;
+        let array = array.for_read()?;
+
+        let reader = ParallelReader::new(&array, &["not_a_field"])?;
+        assert!(reader.read(&[vec![vec![], vec![]]]).is_err());
+        assert!(reader.read(&[])?.is_empty());
+
+        Ok(())
+    }
+}
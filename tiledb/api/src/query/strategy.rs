@@ -1,6 +1,7 @@
 use std::collections::HashMap;
 use std::rc::Rc;
 
+use anyhow::anyhow;
 use cells::write::{
     DenseWriteInput, SparseWriteInput, WriteInput, WriteInputRef,
 };
@@ -11,8 +12,10 @@ use tiledb_pod::array::dimension::strategy::Requirements as DimensionRequirement
 use tiledb_pod::array::domain::strategy::Requirements as DomainRequirements;
 use tiledb_pod::array::schema::strategy::Requirements as SchemaRequirements;
 use tiledb_pod::filter::strategy::Requirements as FilterRequirements;
+use tiledb_pod::query::subarray::SubarrayData;
 
 use super::*;
+use crate::array::Schema;
 use crate::query::read::output::{
     CellStructureSingleIterator, FixedDataIterator, RawReadOutput,
     TypedRawReadOutput, VarDataIterator,
@@ -110,6 +113,25 @@ impl From<&TypedRawReadOutput<'_>> for FieldData {
     }
 }
 
+/// Returns a read handle with managed scratch space for each field in `field_order`.
+fn managed_read_handles<'data>(
+    schema: &Schema,
+    field_order: &[String],
+) -> TileDBResult<Vec<TypedReadHandle<'data>>> {
+    field_order
+        .iter()
+        .map(|name| {
+            let field = schema.field(name.as_str())?;
+            let metadata = FieldMetadata::try_from(&field)?;
+            Ok(physical_type_go!(field.datatype()?, DT, {
+                let managed: ManagedBuffer<DT> =
+                    ManagedBuffer::new(field.query_scratch_allocator(None)?);
+                TypedReadHandle::from(RawReadHandle::managed(metadata, managed))
+            }))
+        })
+        .collect::<TileDBResult<Vec<TypedReadHandle>>>()
+}
+
 impl ToReadQuery for Cells {
     type ReadBuilder<'data, B> =
         CallbackVarArgReadBuilder<'data, RawResultCallback, B>;
@@ -122,24 +144,8 @@ impl ToReadQuery for Cells {
         B: ReadQueryBuilder<'data>,
     {
         let field_order = self.fields().keys().cloned().collect::<Vec<_>>();
-        let handles = {
-            let schema = b.base().array().schema().unwrap();
-
-            field_order
-                .iter()
-                .map(|name| {
-                    let field = schema.field(name.clone()).unwrap();
-                    physical_type_go!(field.datatype().unwrap(), DT, {
-                        let managed: ManagedBuffer<DT> = ManagedBuffer::new(
-                            field.query_scratch_allocator(None).unwrap(),
-                        );
-                        let metadata = FieldMetadata::try_from(&field).unwrap();
-                        let rr = RawReadHandle::managed(metadata, managed);
-                        TypedReadHandle::from(rr)
-                    })
-                })
-                .collect::<Vec<TypedReadHandle>>()
-        };
+        let handles =
+            managed_read_handles(&b.base().array().schema()?, &field_order)?;
 
         b.register_callback_var(handles, RawResultCallback { field_order })
     }
@@ -298,6 +304,93 @@ impl ToWriteQuery for WriteInputRef<'_> {
     }
 }
 
+/// Writes `cells` into `array` using a single write query with `layout`,
+/// returning the array once the query is finalized.
+///
+/// Every attribute of the array must be present in `cells`.
+/// For sparse arrays every dimension must also be present.
+/// Dense writes do not set a subarray, so `cells` must cover
+/// the whole domain of a dense array.
+pub fn write_cells(
+    array: Array,
+    cells: &Cells,
+    layout: QueryLayout,
+) -> TileDBResult<Array> {
+    let missing = {
+        let schema = array.schema()?;
+        let is_sparse = schema.array_type()? == ArrayType::Sparse;
+        let mut missing = vec![];
+        for field in schema.fields()? {
+            let field = field?;
+            if !field.is_dimension() || is_sparse {
+                let name = field.name()?;
+                if !cells.fields().contains_key(&name) {
+                    missing.push(name);
+                }
+            }
+        }
+        missing
+    };
+    if !missing.is_empty() {
+        return Err(Error::InvalidArgument(anyhow!(
+            "Cells missing required fields: {}",
+            missing.join(", ")
+        )));
+    }
+
+    let w = cells
+        .attach_write(WriteBuilder::new(array)?.layout(layout)?)?
+        .build();
+    w.submit()?;
+    w.finalize()
+}
+
+/// Reads the values of `fields` from the cells of `array` which are
+/// selected by `subarray` into `Cells`, or of all fields if `fields` is empty.
+///
+/// If `subarray` is `None` then all cells within the non-empty domain
+/// of the array are read. The query is submitted until it is complete,
+/// and the results of each step are accumulated into the returned `Cells`.
+///
+/// The array is re-opened for reading at the same timestamps as `array`.
+pub fn read_cells(
+    array: &Array,
+    fields: &[&str],
+    subarray: Option<SubarrayData>,
+    layout: QueryLayout,
+) -> TileDBResult<Cells> {
+    let array = array.reopen_for_read()?;
+    let schema = array.schema()?;
+
+    let field_order = if fields.is_empty() {
+        schema.field_names()?
+    } else {
+        fields
+            .iter()
+            .map(|f| f.to_string())
+            .collect::<Vec<String>>()
+    };
+    let handles = managed_read_handles(&schema, &field_order)?;
+
+    let b = ReadBuilder::new(array)?.layout(layout)?;
+    let b = if let Some(subarray) = subarray {
+        b.start_subarray()?
+            .dimension_ranges(subarray.dimension_ranges)?
+            .finish_subarray()?
+    } else {
+        b.with_full_domain()?
+    };
+
+    let mut query = b
+        .register_callback_var(handles, RawResultCallback { field_order })?
+        .map(CellsConstructor::new())
+        .build();
+    let (cells, ()) = query.execute()?;
+    query.finalize()?;
+
+    Ok(cells)
+}
+
 // TODO: where should these go
 pub struct RawReadQueryResult(pub HashMap<String, FieldData>);
 
@@ -368,7 +461,9 @@ impl Map<RawReadQueryResult, RawReadQueryResult> for CellsConstructor {
 
 #[cfg(test)]
 mod tests {
-    use cells::write::strategy::{WriteParameters, WriteSequenceParameters};
+    use cells::write::strategy::{
+        prop_query_layout, WriteParameters, WriteSequenceParameters,
+    };
     use cells::write::{DenseWriteInput, SparseWriteInput, WriteSequence};
     use proptest::prelude::*;
     use tiledb_common::range::{NonEmptyDomain, Range};
@@ -745,6 +840,98 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn write_cells_readback() -> TileDBResult<()> {
+        use crate::tests::examples::{quickstart, TestArray};
+
+        let schema = quickstart::Builder::new(ArrayType::Sparse).build();
+        let mut test_array =
+            TestArray::new("write_cells_readback", Rc::new(schema))?;
+
+        let cells = Cells::new(HashMap::from([
+            ("rows".to_owned(), FieldData::from(vec![1i32, 2, 2, 4])),
+            ("cols".to_owned(), FieldData::from(vec![3i32, 1, 4, 2])),
+            ("a".to_owned(), FieldData::from(vec![10i32, 20, 30, 40])),
+        ]));
+        let _ = write_cells(
+            test_array.for_write()?,
+            &cells,
+            QueryLayout::Unordered,
+        )?;
+
+        let readback = Cells::new(
+            test_array
+                .for_read()?
+                .read_all(&[])?
+                .iter()
+                .map(|(name, output)| (name.clone(), FieldData::from(output)))
+                .collect::<HashMap<String, FieldData>>(),
+        );
+        let keys = vec!["rows".to_owned(), "cols".to_owned()];
+        assert_eq!(cells.sorted(&keys), readback.sorted(&keys));
+
+        // the attribute is required
+        let partial = cells.projection(&["rows", "cols"]).unwrap();
+        let result = write_cells(
+            test_array.for_write()?,
+            &partial,
+            QueryLayout::Unordered,
+        );
+        assert!(matches!(result, Err(Error::InvalidArgument(_))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn read_cells_subarray() -> TileDBResult<()> {
+        use tiledb_common::range::SingleValueRange;
+
+        use crate::tests::examples::{quickstart, TestArray};
+
+        let schema = quickstart::Builder::new(ArrayType::Sparse).build();
+        let mut test_array =
+            TestArray::new("read_cells_subarray", Rc::new(schema))?;
+
+        let cells = Cells::new(HashMap::from([
+            ("rows".to_owned(), FieldData::from(vec![1i32, 2, 2, 4])),
+            ("cols".to_owned(), FieldData::from(vec![3i32, 1, 4, 2])),
+            ("a".to_owned(), FieldData::from(vec![10i32, 20, 30, 40])),
+        ]));
+        let _ = write_cells(
+            test_array.for_write()?,
+            &cells,
+            QueryLayout::Unordered,
+        )?;
+
+        let array = test_array.for_read()?;
+        let keys = vec!["rows".to_owned(), "cols".to_owned()];
+
+        // all cells
+        let readback = read_cells(&array, &[], None, QueryLayout::RowMajor)?;
+        assert_eq!(cells.sorted(&keys), readback);
+
+        // some of the fields of some of the cells
+        let subarray = SubarrayData {
+            dimension_ranges: vec![
+                vec![Range::from(SingleValueRange::Int32(2, 2))],
+                vec![],
+            ],
+        };
+        let readback = read_cells(
+            &array,
+            &["cols", "a"],
+            Some(subarray),
+            QueryLayout::RowMajor,
+        )?;
+        let expect = Cells::new(HashMap::from([
+            ("cols".to_owned(), FieldData::from(vec![1i32, 4])),
+            ("a".to_owned(), FieldData::from(vec![20i32, 30])),
+        ]));
+        assert_eq!(expect, readback);
+
+        Ok(())
+    }
+
     /// Test that a single write can be read back correctly
     #[test]
     fn write_once_readback() -> TileDBResult<()> {
@@ -798,4 +985,44 @@ mod tests {
 
         Ok(())
     }
+
+    /// Test that each layout produced by `prop_query_layout` is accepted
+    /// by a query of the same mode against an array with a matching schema
+    #[test]
+    fn query_layout_accepted() -> TileDBResult<()> {
+        let ctx = Context::new().expect("Error creating context");
+
+        let schema_req = query_write_schema_requirements(None);
+
+        let strategy = (
+            any_with::<SchemaData>(Rc::new(schema_req)),
+            prop_oneof![Just(Mode::Read), Just(Mode::Write)],
+        )
+            .prop_flat_map(|(schema, mode)| {
+                let layout = prop_query_layout(&schema, mode);
+                (Just(Rc::new(schema)), Just(mode), layout)
+            });
+
+        proptest!(|((schema_spec, mode, layout) in strategy)| {
+            let test_uri = uri::get_uri_generator()
+                .map_err(|e| Error::Other(e.to_string()))?;
+            let uri = test_uri
+                .with_path("array")
+                .map_err(|e| Error::Other(e.to_string()))?;
+            Array::create(&ctx, &uri, schema_spec.create(&ctx)?)?;
+
+            let array = Array::open(&ctx, &uri, mode)?;
+            match mode {
+                Mode::Read => {
+                    let _ = ReadBuilder::new(array)?.layout(layout)?.build();
+                }
+                Mode::Write => {
+                    let _ = WriteBuilder::new(array)?.layout(layout)?.build();
+                }
+                _ => unreachable!(),
+            }
+        });
+
+        Ok(())
+    }
 }
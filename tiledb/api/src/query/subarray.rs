@@ -3,7 +3,7 @@ use std::ops::Deref;
 
 use anyhow::anyhow;
 
-use crate::array::Schema;
+use crate::array::{Dimension, Schema};
 use crate::context::{CApiInterface, Context, ContextBound};
 use crate::datatype::PhysicalType;
 use crate::error::{DatatypeError, Error};
@@ -12,6 +12,7 @@ use crate::query::QueryBuilder;
 use crate::range::{Range, SingleValueRange, TypedRange, VarValueRange};
 use crate::Result as TileDBResult;
 
+use tiledb_common::range::DimensionCompatibilityError;
 use tiledb_common::{
     physical_type_go, single_value_range_go, var_value_range_go,
 };
@@ -171,6 +172,61 @@ impl Subarray<'_> {
     }
 }
 
+/// Returns an error if `range` is not a valid range of the dimension `dim`.
+///
+/// A range which extends outside of the domain of `dim` is an error
+/// only if `check_domain` is set. Otherwise tiledb crops it to the domain.
+pub(crate) fn check_dimension_range(
+    dim: &Dimension,
+    range: &Range,
+    check_domain: bool,
+) -> TileDBResult<()> {
+    let datatype = dim.datatype()?;
+    let domain = physical_type_go!(datatype, DT, {
+        dim.domain::<DT>()?.map(|d| SingleValueRange::from(&d))
+    });
+    match range.check_dimension_domain(
+        datatype,
+        dim.cell_val_num()?,
+        domain.as_ref(),
+    ) {
+        Err(
+            DimensionCompatibilityError::StartOutOfDomain { .. }
+            | DimensionCompatibilityError::EndOutOfDomain { .. },
+        ) if !check_domain => Ok(()),
+        result => result.map_err(|e| {
+            Error::InvalidArgument(
+                anyhow!("Invalid range for dimension").context(e),
+            )
+        }),
+    }
+}
+
+/// Returns the part of `range` which lies within the inclusive bounds
+/// `lo` and `hi`, or `None` if there is no such part
+/// or if `range` is not a range of integers.
+pub(crate) fn intersect_bounds(
+    range: &Range,
+    lo: i128,
+    hi: i128,
+) -> Option<Range> {
+    let Range::Single(range) = range else {
+        return None;
+    };
+    if !range.is_integral() {
+        return None;
+    }
+    single_value_range_go!(range, DT, start, end, {
+        let start = std::cmp::max(*start as i128, lo);
+        let end = std::cmp::min(*end as i128, hi);
+        if start <= end {
+            Some(Range::from(&[start as DT, end as DT]))
+        } else {
+            None
+        }
+    })
+}
+
 pub struct Builder<Q>
 where
     Q: QueryBuilder + Sized,
@@ -219,13 +275,8 @@ where
         let dim = schema.domain()?.dimension(key.clone())?;
 
         let range = range.into();
-        range
-            .check_dimension_compatibility(dim.datatype()?, dim.cell_val_num()?)
-            .map_err(|e| {
-                Error::InvalidArgument(
-                    anyhow!("Invalid range variant for dimension").context(e),
-                )
-            })?;
+        let check_domain = self.query.base().rejects_out_of_domain_ranges()?;
+        check_dimension_range(&dim, &range, check_domain)?;
 
         let c_subarray = *self.raw;
 
@@ -314,7 +365,7 @@ where
         let dim_idx = schema.domain()?.dimension_index(key)?;
         let dim = schema.domain()?.dimension(dim_idx)?;
         let dtype = dim.datatype()?;
-        if dtype.is_compatible_type::<T>() {
+        if !dtype.is_compatible_type::<T>() {
             Err(DatatypeError::physical_type_incompatible::<T>(dtype))?;
         }
 
@@ -366,6 +417,7 @@ where
         self.query.base().capi_call(|ctx| unsafe {
             ffi::tiledb_query_set_subarray_t(ctx, c_query, c_subarray)
         })?;
+        self.query.base().query.empty_result.set(false);
         Ok(self.query)
     }
 }
@@ -434,6 +486,100 @@ mod tests {
         Ok(())
     }
 
+    /// Ranges which are not within the dimension domain are rejected
+    /// if the query is configured with `sm.read_range_oob=error`
+    #[test]
+    fn add_range_out_of_domain() -> TileDBResult<()> {
+        let ctx = Context::new().unwrap();
+
+        let test_uri = uri::get_uri_generator()
+            .map_err(|e| Error::Other(e.to_string()))?;
+        let test_uri =
+            crate::array::tests::create_quickstart_dense(&test_uri, &ctx)?;
+
+        let config = crate::query::required_config()?
+            .with("sm.read_range_oob", "error")?;
+
+        let a = Array::open(&ctx, test_uri, Mode::Read)?;
+        let Err(Error::InvalidArgument(e)) = ReadBuilder::new(a)?
+            .config(&config)?
+            .start_subarray()?
+            .add_range("cols", &[2, 5])
+        else {
+            unreachable!()
+        };
+        assert!(matches!(
+            e.downcast_ref::<DimensionCompatibilityError>(),
+            Some(DimensionCompatibilityError::EndOutOfDomain { .. })
+        ));
+
+        Ok(())
+    }
+
+    /// Ranges which are not within the dimension domain are cropped
+    /// to the domain by read queries with the default configuration
+    #[test]
+    fn add_range_out_of_domain_cropped() -> TileDBResult<()> {
+        let ctx = Context::new().unwrap();
+
+        let test_uri = uri::get_uri_generator()
+            .map_err(|e| Error::Other(e.to_string()))?;
+        let test_uri =
+            crate::array::tests::create_quickstart_dense(&test_uri, &ctx)?;
+
+        let a = Array::open(&ctx, test_uri, Mode::Read)?;
+        let b = ReadBuilder::new(a)?
+            .start_subarray()?
+            .add_range("cols", &[2, 5])?
+            .finish_subarray()?;
+
+        assert_eq!(
+            vec![Range::Single(SingleValueRange::Int32(2, 4))],
+            b.subarray()?.ranges()?[1]
+        );
+
+        // the start must still not exceed the end
+        assert!(matches!(
+            b.start_subarray()?.add_range("cols", &[3, 2]),
+            Err(Error::InvalidArgument(_))
+        ));
+
+        Ok(())
+    }
+
+    /// `Query::subarray_ranges` reads back fixed and var-sized ranges
+    #[test]
+    fn subarray_ranges() -> TileDBResult<()> {
+        let ctx = Context::new().unwrap();
+
+        let test_uri = uri::get_uri_generator()
+            .map_err(|e| Error::Other(e.to_string()))?;
+        let test_uri = crate::array::tests::create_quickstart_sparse_string(
+            &test_uri, &ctx,
+        )?;
+
+        let a = Array::open(&ctx, test_uri, Mode::Read)?;
+        let q = ReadBuilder::new(a)?
+            .start_subarray()?
+            .add_range("rows", ("bar", "foo"))?
+            .add_range("cols", &[2, 3])?
+            .finish_subarray()?
+            .build();
+
+        let subarray = q.subarray_ranges()?;
+        assert_eq!(
+            SubarrayData {
+                dimension_ranges: vec![
+                    vec![Range::from(("bar", "foo"))],
+                    vec![Range::Single(SingleValueRange::Int32(2, 3))]
+                ]
+            },
+            subarray
+        );
+
+        Ok(())
+    }
+
     /// The default subarray of a query with unconstrained dimension
     /// is anything goes. The array used here has one unconstrained
     /// string dimension and one constrained int dimension, so we
@@ -539,6 +685,61 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn with_full_domain() -> TileDBResult<()> {
+        let ctx = Context::new().unwrap();
+        let test_uri = uri::get_uri_generator()
+            .map_err(|e| Error::Other(e.to_string()))?;
+        let array_uri = create_array(&ctx, ArrayType::Sparse, &test_uri)?;
+
+        // no data, no cells are read from either array type
+        for atype in [ArrayType::Sparse, ArrayType::Dense] {
+            let array_uri = if atype == ArrayType::Sparse {
+                array_uri.clone()
+            } else {
+                create_array(&ctx, atype, &test_uri)?
+            };
+            let array = Array::open(&ctx, &array_uri, Mode::Read)?;
+            let mut query = ReadBuilder::new(array)?
+                .with_full_domain()?
+                .register_constructor::<_, Vec<i32>>("id", Default::default())?
+                .register_constructor::<_, Vec<i32>>(
+                    "attr",
+                    Default::default(),
+                )?
+                .build();
+            let (atts, (ids, _)) = query.execute()?;
+            assert!(ids.is_empty());
+            assert!(atts.is_empty());
+        }
+
+        {
+            let ids = vec![3i32, 7];
+            let atts = vec![30i32, 70];
+
+            let array = Array::open(&ctx, &array_uri, Mode::Write)?;
+            let q = WriteBuilder::new(array)?
+                .data("id", &ids)?
+                .data("attr", &atts)?
+                .build();
+            q.submit()?;
+            q.finalize()?;
+        }
+
+        let array = Array::open(&ctx, &array_uri, Mode::Read)?;
+        let domain = array.nonempty_domain()?.unwrap();
+        let query = ReadBuilder::new(array)?.with_full_domain()?.build();
+
+        let ranges = query.subarray()?.ranges()?;
+        assert_eq!(vec![vec![Range::from(&[3i32, 7])]], ranges);
+        assert_eq!(
+            SubarrayData::from_nonempty_domain(&domain).dimension_ranges,
+            ranges
+        );
+
+        Ok(())
+    }
+
     /// Create a simple dense test array with a couple fragments to inspect.
     fn create_array(
         ctx: &Context,
@@ -6,13 +6,14 @@ use anyhow::anyhow;
 use arrow::array::{
     Array as ArrowArray, AsArray, FixedSizeBinaryArray, FixedSizeListArray,
     GenericListArray, LargeBinaryArray, LargeStringArray, PrimitiveArray,
-    RecordBatch,
+    RecordBatch, StringArray,
 };
 use arrow::buffer::OffsetBuffer;
 use arrow::datatypes::{ArrowPrimitiveType, Field};
 use tiledb_common::array::CellValNum;
+use tiledb_common::datatype::Datatype;
 
-use crate::array::Schema;
+use crate::array::{Array, Schema};
 use crate::error::{DatatypeError, Error};
 use crate::query::buffer::{
     Buffer, CellStructure, QueryBuffers, TypedQueryBuffers,
@@ -20,7 +21,7 @@ use crate::query::buffer::{
 use crate::query::write::input::{
     DataProvider, RecordProvider, TypedDataProvider,
 };
-use crate::query::CellValue;
+use crate::query::{CellValue, QueryBuilder, WriteBuilder, WriteQuery};
 use crate::Result as TileDBResult;
 
 fn cell_structure_var(
@@ -46,6 +47,35 @@ fn cell_structure_var(
     }
 }
 
+/// Like `cell_structure_var`, but for arrays with 32-bit offsets.
+/// The offsets must be widened to 64 bits, so they cannot be borrowed.
+fn cell_structure_var_i32(
+    offsets: &OffsetBuffer<i32>,
+    cell_val_num: CellValNum,
+) -> TileDBResult<CellStructure<'static>> {
+    match cell_val_num {
+        CellValNum::Fixed(nz) => {
+            let expect_len = nz.get() as i32;
+            for window in offsets.windows(2) {
+                if window[1] - window[0] != expect_len {
+                    return Err(Error::UnexpectedCellStructure {
+                        expected: cell_val_num,
+                        found: CellValNum::Var,
+                    });
+                }
+            }
+            Ok(CellStructure::Fixed(nz))
+        }
+        CellValNum::Var => Ok(CellStructure::Var(Buffer::Owned(
+            offsets
+                .iter()
+                .map(|o| *o as u64)
+                .collect::<Vec<u64>>()
+                .into_boxed_slice(),
+        ))),
+    }
+}
+
 fn cell_structure_fixed(
     fixed_len: i32,
     ncells: usize,
@@ -357,6 +387,27 @@ impl DataProvider for LargeStringArray {
     }
 }
 
+impl DataProvider for StringArray {
+    type Unit = u8;
+
+    fn query_buffers(
+        &self,
+        cell_val_num: CellValNum,
+        is_nullable: bool,
+    ) -> TileDBResult<QueryBuffers<Self::Unit>> {
+        let cell_structure =
+            cell_structure_var_i32(self.offsets(), cell_val_num)?;
+        let data = Buffer::Borrowed(self.value_data());
+        let validity = validity_buffer(self, is_nullable)?;
+
+        Ok(QueryBuffers {
+            data,
+            cell_structure,
+            validity,
+        })
+    }
+}
+
 impl TypedDataProvider for FixedSizeListArray {
     fn typed_query_buffers(
         &self,
@@ -414,7 +465,6 @@ impl TypedDataProvider for dyn ArrowArray {
             | ADT::Duration(_)
             | ADT::Interval(_)
             | ADT::Binary
-            | ADT::Utf8
             | ADT::BinaryView
             | ADT::Utf8View
             | ADT::List(_)
@@ -500,6 +550,7 @@ impl TypedDataProvider for dyn ArrowArray {
             ADT::LargeBinary => {
                 self.as_binary::<i64>().typed_query_buffers(c, n)
             }
+            ADT::Utf8 => self.as_string::<i32>().typed_query_buffers(c, n),
             ADT::LargeUtf8 => self.as_string::<i64>().typed_query_buffers(c, n),
             ADT::FixedSizeList(_, _) => {
                 self.as_fixed_size_list().typed_query_buffers(c, n)
@@ -544,10 +595,18 @@ impl<'data> Iterator for RecordBatchTileDBInputs<'data> {
         match (self.fields.next(), self.columns.next()) {
             (None, None) => None,
             (Some(f), Some(c)) => {
-                let Some((datatype, cell_val_num)) =
-                    tiledb_common::datatype::arrow::from_arrow(f.data_type())
-                        .ok()
-                else {
+                let arrow_datatype = match f.data_type() {
+                    /*
+                     * `from_arrow` rejects 32-bit offsets since they cannot
+                     * be used for read results, but they can be widened
+                     * for query inputs
+                     */
+                    arrow::datatypes::DataType::Utf8 => {
+                        Some((Datatype::StringUtf8, CellValNum::Var))
+                    }
+                    adt => tiledb_common::datatype::arrow::from_arrow(adt).ok(),
+                };
+                let Some((datatype, cell_val_num)) = arrow_datatype else {
                     return Some(Err(Error::InvalidArgument(anyhow!(
                         format!(
                             "Unsupported Arrow datatype as query input: {}",
@@ -564,7 +623,10 @@ impl<'data> Iterator for RecordBatchTileDBInputs<'data> {
                     Ok(datatype) => datatype,
                     Err(e) => return Some(Err(e)),
                 };
-                if datatype != field_datatype {
+                let is_string_compatible = datatype.is_string_type()
+                    && field_datatype.is_string_type()
+                    && datatype.same_physical_type(&field_datatype);
+                if datatype != field_datatype && !is_string_compatible {
                     return Some(Err(Error::Datatype(
                         DatatypeError::LogicalTypeMismatch {
                             source_type: datatype,
@@ -604,12 +666,62 @@ impl<'data> Iterator for RecordBatchTileDBInputs<'data> {
     }
 }
 
+impl<'data> WriteBuilder<'data> {
+    /// Returns a query which writes each column of `batch`
+    /// to the field of `array` which has the same name.
+    ///
+    /// Each column must have a datatype and cell structure which
+    /// match its field. Columns which contain nulls may only be
+    /// written to nullable fields. `Utf8` and `LargeUtf8` columns
+    /// can be written to any variable-length string attribute.
+    ///
+    /// If any column cannot be written, the returned error names it.
+    pub fn from_record_batch(
+        array: Array,
+        batch: &'data RecordBatch,
+    ) -> TileDBResult<WriteQuery<'data>> {
+        let schema = Rc::new(array.schema()?);
+
+        let unmatched = batch
+            .schema_ref()
+            .fields()
+            .iter()
+            .filter(|f| schema.field(f.name().as_str()).is_err())
+            .map(|f| f.name().as_str())
+            .collect::<Vec<&str>>();
+        if !unmatched.is_empty() {
+            return Err(Error::InvalidArgument(anyhow!(
+                "Columns not found in array schema: {}",
+                unmatched.join(", ")
+            )));
+        }
+
+        let mut b = WriteBuilder::new(array)?;
+        let inputs = batch.tiledb_inputs(Rc::clone(&schema));
+        for (f, try_input) in batch.schema_ref().fields().iter().zip(inputs) {
+            let (field, input_data) = try_input.map_err(|e| {
+                Error::InvalidArgument(anyhow!("Column '{}': {}", f.name(), e))
+            })?;
+            b = b.buffers(field, input_data)?;
+        }
+
+        Ok(b.build())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use arrow::array::{Int32Array, Int64Array};
+    use arrow::datatypes::{Int32Type, UInt8Type};
     use proptest::prelude::*;
+    use tiledb_common::array::ArrayType;
+    use tiledb_pod::array::attribute::AttributeData;
+    use tiledb_pod::array::schema::SchemaData;
 
     use crate::query::read::output::TypedRawReadOutput;
+    use crate::query::Query;
+    use crate::tests::examples::{quickstart, TestArray};
     use crate::typed_query_buffers_go;
 
     fn do_raw_read_arrow_invertible(rr_in: TypedRawReadOutput) {
@@ -698,4 +810,156 @@ mod tests {
             do_raw_read_arrow_invertible(rr)
         }
     }
+
+    /// Returns the quickstart schema with an additional nullable
+    /// variable-length string attribute "s".
+    fn record_batch_schema() -> Rc<SchemaData> {
+        let mut b = quickstart::Builder::new(ArrayType::Sparse);
+        b.schema.attributes.push(AttributeData {
+            name: "s".to_owned(),
+            datatype: Datatype::StringUtf8,
+            cell_val_num: Some(CellValNum::Var),
+            nullability: Some(true),
+            fill: None,
+            filters: Default::default(),
+            enumeration: None,
+        });
+        Rc::new(b.build())
+    }
+
+    #[test]
+    fn from_record_batch_roundtrip() -> anyhow::Result<()> {
+        let mut array = TestArray::new(
+            "from_record_batch_roundtrip",
+            record_batch_schema(),
+        )?;
+
+        let batch = RecordBatch::try_from_iter(vec![
+            (
+                "rows",
+                Arc::new(Int32Array::from(vec![1, 2, 3, 4]))
+                    as Arc<dyn ArrowArray>,
+            ),
+            ("cols", Arc::new(Int32Array::from(vec![4, 3, 2, 1]))),
+            ("a", Arc::new(Int32Array::from(vec![10, 20, 30, 40]))),
+            (
+                "s",
+                Arc::new(StringArray::from(vec![
+                    Some("foo"),
+                    None,
+                    Some(""),
+                    Some("quux"),
+                ])),
+            ),
+        ])?;
+
+        {
+            let q =
+                WriteBuilder::from_record_batch(array.for_write()?, &batch)?;
+            q.submit()?;
+            q.finalize()?;
+        }
+
+        let columns = array
+            .for_read()?
+            .read_all(&["rows", "cols", "a", "s"])?
+            .into_iter()
+            .map(|(_, rr)| Arc::<dyn ArrowArray>::try_from(rr))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let (rows, cols, a) = (
+            columns[0].as_primitive::<Int32Type>(),
+            columns[1].as_primitive::<Int32Type>(),
+            columns[2].as_primitive::<Int32Type>(),
+        );
+        let s = columns[3].as_list::<i64>();
+
+        let mut cells = (0..rows.len())
+            .map(|i| {
+                let s = s.is_valid(i).then(|| {
+                    String::from_utf8(
+                        s.value(i)
+                            .as_primitive::<UInt8Type>()
+                            .values()
+                            .to_vec(),
+                    )
+                    .unwrap()
+                });
+                (rows.value(i), cols.value(i), a.value(i), s)
+            })
+            .collect::<Vec<_>>();
+        cells.sort();
+
+        assert_eq!(
+            vec![
+                (1, 4, 10, Some("foo".to_owned())),
+                (2, 3, 20, None),
+                (3, 2, 30, Some("".to_owned())),
+                (4, 1, 40, Some("quux".to_owned())),
+            ],
+            cells
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn from_record_batch_invalid() -> anyhow::Result<()> {
+        let mut array =
+            TestArray::new("from_record_batch_invalid", record_batch_schema())?;
+
+        let rows = Arc::new(Int32Array::from(vec![1])) as Arc<dyn ArrowArray>;
+        let cols = Arc::new(Int32Array::from(vec![1])) as Arc<dyn ArrowArray>;
+
+        // column which is not in the schema
+        let batch = RecordBatch::try_from_iter(vec![
+            ("rows", Arc::clone(&rows)),
+            ("cols", Arc::clone(&cols)),
+            (
+                "b",
+                Arc::new(Int32Array::from(vec![1])) as Arc<dyn ArrowArray>,
+            ),
+        ])?;
+        let Err(e) =
+            WriteBuilder::from_record_batch(array.for_write()?, &batch)
+        else {
+            unreachable!()
+        };
+        assert!(e.to_string().ends_with(": b"), "{}", e);
+
+        // column whose datatype does not match its field
+        let batch = RecordBatch::try_from_iter(vec![
+            ("rows", Arc::clone(&rows)),
+            ("cols", Arc::clone(&cols)),
+            (
+                "a",
+                Arc::new(Int64Array::from(vec![1])) as Arc<dyn ArrowArray>,
+            ),
+        ])?;
+        let Err(e) =
+            WriteBuilder::from_record_batch(array.for_write()?, &batch)
+        else {
+            unreachable!()
+        };
+        assert!(e.to_string().contains("Column 'a'"), "{}", e);
+
+        // nulls in a non-nullable field
+        let batch = RecordBatch::try_from_iter(vec![
+            ("rows", Arc::clone(&rows)),
+            ("cols", Arc::clone(&cols)),
+            (
+                "a",
+                Arc::new(Int32Array::from(vec![None::<i32>]))
+                    as Arc<dyn ArrowArray>,
+            ),
+        ])?;
+        let Err(e) =
+            WriteBuilder::from_record_batch(array.for_write()?, &batch)
+        else {
+            unreachable!()
+        };
+        assert!(e.to_string().contains("Column 'a'"), "{}", e);
+
+        Ok(())
+    }
 }
@@ -4,8 +4,14 @@ use std::collections::HashMap;
 use std::pin::Pin;
 use std::rc::Rc;
 
-use crate::config::Config;
-use crate::query::buffer::{CellStructure, QueryBuffers, TypedQueryBuffers};
+use anyhow::anyhow;
+
+use crate::array::{ArrayType, CellValNum};
+use crate::datatype::PhysicalType;
+use crate::query::buffer::{
+    Buffer, CellStructure, QueryBuffers, TypedQueryBuffers,
+};
+use crate::query::read::output::{RawReadOutput, TypedRawReadOutput};
 use crate::query::write::input::{
     DataProvider, RecordProvider, TypedDataProvider,
 };
@@ -20,6 +26,86 @@ struct RawWriteInput<'data> {
     _input: TypedQueryBuffers<'data>,
 }
 
+impl<'data> RawWriteInput<'data> {
+    /// Sets `input` as the buffers for `field_name` on the query `c_query`.
+    fn attach(
+        context: &Context,
+        c_query: *mut ffi::tiledb_query_t,
+        field_name: &str,
+        input: TypedQueryBuffers<'data>,
+    ) -> TileDBResult<Self> {
+        let c_name = cstring!(field_name);
+
+        let (c_bufptr, mut data_size) =
+            typed_query_buffers_go!(input, _DT, ref qb, {
+                let c_bufptr =
+                    qb.data.as_ref().as_ptr() as *mut std::ffi::c_void;
+                let data_size = Box::pin(qb.data.size() as u64);
+                (c_bufptr, data_size)
+            });
+
+        let c_sizeptr = data_size.as_mut().get_mut() as *mut u64;
+
+        context.capi_call(|ctx| unsafe {
+            ffi::tiledb_query_set_data_buffer(
+                ctx,
+                c_query,
+                c_name.as_ptr(),
+                c_bufptr,
+                c_sizeptr,
+            )
+        })?;
+
+        let offsets_size = if let CellStructure::Var(offsets) =
+            input.cell_structure().borrow()
+        {
+            let mut offsets_size = Box::pin(offsets.size() as u64);
+
+            let c_offptr = offsets.as_ref().as_ptr() as *mut u64;
+            let c_sizeptr = offsets_size.as_mut().get_mut() as *mut u64;
+
+            context.capi_call(|ctx| unsafe {
+                ffi::tiledb_query_set_offsets_buffer(
+                    ctx,
+                    c_query,
+                    c_name.as_ptr(),
+                    c_offptr,
+                    c_sizeptr,
+                )
+            })?;
+            Some(offsets_size)
+        } else {
+            None
+        };
+
+        let mut validity_size =
+            input.validity().map(|b| Box::pin(b.size() as u64));
+
+        if let Some(ref mut validity_size) = validity_size.as_mut() {
+            let c_validityptr =
+                input.validity().unwrap().as_ref().as_ptr() as *mut u8;
+            let c_sizeptr = validity_size.as_mut().get_mut() as *mut u64;
+
+            context.capi_call(|ctx| unsafe {
+                ffi::tiledb_query_set_validity_buffer(
+                    ctx,
+                    c_query,
+                    c_name.as_ptr(),
+                    c_validityptr,
+                    c_sizeptr,
+                )
+            })?;
+        }
+
+        Ok(RawWriteInput {
+            _data_size: data_size,
+            _offsets_size: offsets_size,
+            _validity_size: validity_size,
+            _input: input,
+        })
+    }
+}
+
 type InputMap<'data> = HashMap<String, RawWriteInput<'data>>;
 
 pub struct WriteQuery<'data> {
@@ -45,8 +131,92 @@ impl Query for WriteQuery<'_> {
     }
 }
 
-impl WriteQuery<'_> {
+impl<'data> WriteQuery<'data> {
+    /// Submits the query.
+    ///
+    /// Returns an error without submitting the query if the array is sparse
+    /// and the query does not have input for each of its dimensions.
     pub fn submit(&self) -> TileDBResult<()> {
+        self.check_coordinates()?;
+        self.base.do_submit()
+    }
+
+    /// Returns an error naming the dimensions of a sparse array
+    /// which this query has no coordinates for.
+    fn check_coordinates(&self) -> TileDBResult<()> {
+        let schema = self.base.array().schema()?;
+        if schema.array_type()? != ArrayType::Sparse {
+            return Ok(());
+        }
+        let domain = schema.domain()?;
+        let mut missing = vec![];
+        for d in 0..domain.num_dimensions()? {
+            let name = domain.dimension(d)?.name()?;
+            if !self._inputs.contains_key(&name) {
+                missing.push(name);
+            }
+        }
+        if missing.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::InvalidArgument(anyhow!(
+                "Sparse write is missing coordinates for dimension(s): {}",
+                missing.join(", ")
+            )))
+        }
+    }
+
+    /// Replaces the input buffers of this query with `chunk` and submits
+    /// the query without finalizing it.
+    ///
+    /// This allows a write using `QueryLayout::Global` to produce a single
+    /// fragment from several chunks of input: call `write_chunk` once for
+    /// each chunk following the initial `submit`, and then `finalize`
+    /// after the last chunk.
+    ///
+    /// `chunk` must contain buffers for exactly the same fields as the
+    /// original input, and each buffer must have the same physical type,
+    /// cell structure, and nullability as the buffer it replaces.
+    pub fn write_chunk<I, S>(&mut self, chunk: I) -> TileDBResult<()>
+    where
+        I: IntoIterator<Item = (S, TypedQueryBuffers<'data>)>,
+        S: AsRef<str>,
+    {
+        let chunk = chunk
+            .into_iter()
+            .map(|(field, input)| (field.as_ref().to_string(), input))
+            .collect::<HashMap<String, TypedQueryBuffers<'data>>>();
+
+        if chunk.len() != self._inputs.len() {
+            return Err(Error::InvalidArgument(anyhow!(
+                "Expected input for {} fields but found {}",
+                self._inputs.len(),
+                chunk.len()
+            )));
+        }
+        for (field, input) in chunk.iter() {
+            let Some(prev) = self._inputs.get(field) else {
+                return Err(Error::InvalidArgument(anyhow!(
+                    "Unexpected input for field '{}'",
+                    field
+                )));
+            };
+            if !prev._input.is_compatible(input) {
+                return Err(Error::InvalidArgument(anyhow!(
+                    "Input for field '{}' is not compatible with the \
+                     original input",
+                    field
+                )));
+            }
+        }
+
+        let c_query = **self.base.cquery();
+        for (field, input) in chunk {
+            let raw_write_input =
+                RawWriteInput::attach(&self.context(), c_query, &field, input)?;
+            self._inputs.insert(field, raw_write_input);
+        }
+
         self.base.do_submit()
     }
 }
@@ -81,22 +251,8 @@ impl<'data> WriteBuilder<'data> {
     pub fn new(array: Array) -> TileDBResult<Self> {
         let base = BuilderBase::new(array, QueryType::Write)?;
 
-        {
-            let mut config = Config::new()?;
-            config.set("sm.var_offsets.bitsize", "64")?;
-            config.set("sm.var_offsets.mode", "elements")?;
-            config.set("sm.var_offsets.extra_element", "true")?;
-
-            /*
-             * TODO: make sure that users can't override this somehow,
-             * else we will be very very sad
-             */
-            let c_query = **base.cquery();
-
-            base.capi_call(|c_context| unsafe {
-                ffi::tiledb_query_set_config(c_context, c_query, config.capi())
-            })?;
-        }
+        /* configure the query to always use arrow-like output */
+        base.set_config(None)?;
 
         Ok(WriteBuilder {
             base,
@@ -115,75 +271,12 @@ impl<'data> WriteBuilder<'data> {
         let field_name = field.as_ref().to_string();
 
         let c_query = **self.base().cquery();
-        let c_name = cstring!(field_name.clone());
-
-        let (c_bufptr, mut data_size) =
-            typed_query_buffers_go!(input, _DT, ref qb, {
-                let c_bufptr =
-                    qb.data.as_ref().as_ptr() as *mut std::ffi::c_void;
-                let data_size = Box::pin(qb.data.size() as u64);
-                (c_bufptr, data_size)
-            });
-
-        let c_sizeptr = data_size.as_mut().get_mut() as *mut u64;
-
-        self.capi_call(|ctx| unsafe {
-            ffi::tiledb_query_set_data_buffer(
-                ctx,
-                c_query,
-                c_name.as_ptr(),
-                c_bufptr,
-                c_sizeptr,
-            )
-        })?;
-
-        let offsets_size = if let CellStructure::Var(offsets) =
-            input.cell_structure().borrow()
-        {
-            let mut offsets_size = Box::pin(offsets.size() as u64);
-
-            let c_offptr = offsets.as_ref().as_ptr() as *mut u64;
-            let c_sizeptr = offsets_size.as_mut().get_mut() as *mut u64;
-
-            self.capi_call(|ctx| unsafe {
-                ffi::tiledb_query_set_offsets_buffer(
-                    ctx,
-                    c_query,
-                    c_name.as_ptr(),
-                    c_offptr,
-                    c_sizeptr,
-                )
-            })?;
-            Some(offsets_size)
-        } else {
-            None
-        };
-
-        let mut validity_size =
-            input.validity().map(|b| Box::pin(b.size() as u64));
-
-        if let Some(ref mut validity_size) = validity_size.as_mut() {
-            let c_validityptr =
-                input.validity().unwrap().as_ref().as_ptr() as *mut u8;
-            let c_sizeptr = validity_size.as_mut().get_mut() as *mut u64;
-
-            self.capi_call(|ctx| unsafe {
-                ffi::tiledb_query_set_validity_buffer(
-                    ctx,
-                    c_query,
-                    c_name.as_ptr(),
-                    c_validityptr,
-                    c_sizeptr,
-                )
-            })?;
-        }
-
-        let raw_write_input = RawWriteInput {
-            _data_size: data_size,
-            _offsets_size: offsets_size,
-            _validity_size: validity_size,
-            _input: input,
-        };
+        let raw_write_input = RawWriteInput::attach(
+            &self.context(),
+            c_query,
+            &field_name,
+            input,
+        )?;
 
         self.inputs.insert(field_name, raw_write_input);
 
@@ -234,6 +327,227 @@ impl<'data> WriteBuilder<'data> {
         self.buffers(field, input)
     }
 
+    /// Returns the [CellValNum] of `field` if values of type `T`
+    /// can be written to it without a validity buffer.
+    fn iter_input_cell_val_num<T>(
+        &self,
+        field: &str,
+    ) -> TileDBResult<CellValNum>
+    where
+        T: PhysicalType,
+    {
+        let schema = self.base().array().schema()?;
+        let schema_field = schema.field(field)?;
+        let datatype = schema_field.datatype()?;
+        if !datatype.is_compatible_type::<T>() {
+            return Err(Error::InvalidArgument(anyhow!(
+                "Cannot write values of type {} to field '{}' of type {}",
+                std::any::type_name::<T>(),
+                field,
+                datatype
+            )));
+        }
+        if schema_field.nullability()? {
+            return Err(Error::InvalidArgument(anyhow!(
+                "Cannot write field '{}' from an iterator: it is nullable",
+                field
+            )));
+        }
+        schema_field.cell_val_num()
+    }
+
+    /// Sets the input for a fixed-length `field` by collecting the values of `iter`
+    /// directly into an owned query buffer.
+    ///
+    /// Returns `Error::InvalidArgument` if `T` is not compatible with
+    /// the datatype of `field` or if `field` is nullable.
+    pub fn data_from_iter<S, T, I>(
+        self,
+        field: S,
+        iter: I,
+    ) -> TileDBResult<Self>
+    where
+        S: AsRef<str>,
+        T: PhysicalType,
+        I: ExactSizeIterator<Item = T>,
+        QueryBuffers<'data, T>: Into<TypedQueryBuffers<'data>>,
+    {
+        let cell_val_num = self.iter_input_cell_val_num::<T>(field.as_ref())?;
+        let cell_structure = match cell_val_num {
+            CellValNum::Fixed(nz) => CellStructure::Fixed(nz),
+            CellValNum::Var => {
+                return Err(Error::UnexpectedCellStructure {
+                    expected: CellValNum::Var,
+                    found: CellValNum::single(),
+                })
+            }
+        };
+
+        let mut data = Vec::with_capacity(iter.len());
+        data.extend(iter);
+
+        let input = QueryBuffers {
+            data: Buffer::from(data),
+            cell_structure,
+            validity: None,
+        };
+        self.buffers(field, input.into())
+    }
+
+    /// Sets the input for a variable-length `field` by concatenating the cells
+    /// of `iter` into an owned query buffer and computing their offsets.
+    ///
+    /// Returns `Error::InvalidArgument` if the datatype of `field` is not
+    /// compatible with `u8` or if `field` is nullable.
+    pub fn data_from_var_iter<S, I>(
+        self,
+        field: S,
+        iter: I,
+    ) -> TileDBResult<Self>
+    where
+        S: AsRef<str>,
+        I: IntoIterator,
+        I::Item: AsRef<[u8]>,
+    {
+        let cell_val_num =
+            self.iter_input_cell_val_num::<u8>(field.as_ref())?;
+        if cell_val_num != CellValNum::Var {
+            return Err(Error::UnexpectedCellStructure {
+                expected: cell_val_num,
+                found: CellValNum::Var,
+            });
+        }
+
+        let iter = iter.into_iter();
+        let mut data = Vec::<u8>::new();
+        let mut offsets = Vec::<u64>::with_capacity(iter.size_hint().0 + 1);
+        offsets.push(0);
+        for cell in iter {
+            data.extend_from_slice(cell.as_ref());
+            offsets.push(data.len() as u64);
+        }
+
+        let input = QueryBuffers {
+            data: Buffer::from(data),
+            cell_structure: CellStructure::Var(Buffer::from(offsets)),
+            validity: None,
+        };
+        self.buffers(field, input.into())
+    }
+
+    /// Removes cells from the input of this query which have the same values
+    /// of the fields `keys` as a later cell, so that only the last of each
+    /// group of duplicate cells is written.
+    ///
+    /// This is a convenience for writing to sparse arrays which do not allow
+    /// duplicates, where `keys` are the dimensions of the array.
+    /// Only the cells of this write are deduplicated; cells in other
+    /// fragments of the array with the same coordinates are not affected.
+    /// The remaining cells keep their relative order.
+    ///
+    /// This applies to the input which has been set so far, so it should be
+    /// called after the input of every field has been set. The deduplicated
+    /// input is copied into buffers owned by the query.
+    ///
+    /// Returns `Error::InvalidArgument` if there is no input for a field of
+    /// `keys`, or if the inputs do not all have the same number of cells.
+    pub fn dedup_coordinates(
+        mut self,
+        keys: Vec<String>,
+    ) -> TileDBResult<Self> {
+        if let Some(key) = keys.iter().find(|k| !self.inputs.contains_key(*k)) {
+            return Err(Error::InvalidArgument(anyhow!(
+                "Cannot deduplicate by field '{}': it has no input",
+                key
+            )));
+        }
+
+        let schema = self.base().array().schema()?;
+        let outputs = self
+            .inputs
+            .iter()
+            .map(|(field, input)| {
+                let datatype = schema.field(field.as_str())?.datatype()?;
+                let output =
+                    typed_query_buffers_go!(input._input, _DT, ref qb, {
+                        let ncells = match qb.cell_structure {
+                            CellStructure::Fixed(nz) => {
+                                qb.data.len() / nz.get() as usize
+                            }
+                            CellStructure::Var(ref offsets) => {
+                                offsets.len().saturating_sub(1)
+                            }
+                        };
+                        TypedRawReadOutput::new(
+                            datatype,
+                            RawReadOutput {
+                                ncells,
+                                input: qb.borrow(),
+                            },
+                        )
+                    });
+                Ok((field.clone(), output))
+            })
+            .collect::<TileDBResult<HashMap<String, TypedRawReadOutput>>>()?;
+
+        let Some(ncells) = outputs.values().next().map(|o| o.ncells) else {
+            return Ok(self);
+        };
+        if let Some((field, output)) =
+            outputs.iter().find(|(_, o)| o.ncells != ncells)
+        {
+            return Err(Error::InvalidArgument(anyhow!(
+                "Cannot deduplicate input: field '{}' has {} cells \
+                 but other fields have {}",
+                field,
+                output.ncells,
+                ncells
+            )));
+        }
+
+        // sort by the keys, keeping the original order of equal cells,
+        // and then keep the last cell of each run of equal cells
+        let key_outputs = keys.iter().map(|k| &outputs[k]).collect::<Vec<_>>();
+        let cmp_keys = |l: &usize, r: &usize| {
+            key_outputs
+                .iter()
+                .map(|key| key.cmp_cells(*l, *r))
+                .find(|o| o.is_ne())
+                .unwrap_or(std::cmp::Ordering::Equal)
+        };
+        let mut idx = (0..ncells).collect::<Vec<usize>>();
+        idx.sort_by(cmp_keys);
+
+        let mut keep = idx
+            .iter()
+            .enumerate()
+            .filter(|(i, c)| {
+                idx.get(i + 1)
+                    .map(|next| cmp_keys(c, next).is_ne())
+                    .unwrap_or(true)
+            })
+            .map(|(_, c)| *c)
+            .collect::<Vec<usize>>();
+        if keep.len() == ncells {
+            return Ok(self);
+        }
+        keep.sort();
+
+        let deduped = outputs
+            .iter()
+            .map(|(field, output)| (field.clone(), output.take(&keep).buffers))
+            .collect::<Vec<_>>();
+
+        let c_query = **self.base().cquery();
+        for (field, input) in deduped {
+            let raw_write_input =
+                RawWriteInput::attach(&self.context(), c_query, &field, input)?;
+            self.inputs.insert(field, raw_write_input);
+        }
+
+        Ok(self)
+    }
+
     pub fn records<R>(self, data: &'data R) -> TileDBResult<Self>
     where
         R: RecordProvider<'data>,
@@ -249,3 +563,318 @@ impl<'data> WriteBuilder<'data> {
         Ok(b)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::examples::quickstart;
+    use crate::tests::prelude::*;
+
+    fn int32_input(values: Vec<i32>) -> TypedQueryBuffers<'static> {
+        QueryBuffers {
+            data: Buffer::from(values),
+            cell_structure: CellStructure::single(),
+            validity: None,
+        }
+        .into()
+    }
+
+    fn quickstart_input(
+        rows: Vec<i32>,
+        cols: Vec<i32>,
+        a: Vec<i32>,
+    ) -> Vec<(&'static str, TypedQueryBuffers<'static>)> {
+        vec![
+            ("rows", int32_input(rows)),
+            ("cols", int32_input(cols)),
+            ("a", int32_input(a)),
+        ]
+    }
+
+    /// Test that a global order write of several chunks produces one fragment
+    #[test]
+    fn write_chunk_global_order() -> TileDBResult<()> {
+        let mut array = TestArray::new(
+            "write_chunk_global_order",
+            Rc::new(quickstart::Builder::new(ArrayType::Sparse).build()),
+        )?;
+
+        let mut query = {
+            let mut b = WriteBuilder::new(array.for_write()?)?
+                .layout(QueryLayout::Global)?;
+            for (field, input) in
+                quickstart_input(vec![1, 1], vec![1, 2], vec![10, 20])
+            {
+                b = b.buffers(field, input)?;
+            }
+            b.build()
+        };
+        query.submit()?;
+        query.write_chunk(quickstart_input(
+            vec![2, 3],
+            vec![3, 4],
+            vec![30, 40],
+        ))?;
+        query.finalize()?;
+
+        let array = array.for_read()?;
+        assert_eq!(1, array.fragment_info()?.num_fragments()?);
+
+        let mut read = ReadBuilder::new(array)?
+            .layout(QueryLayout::RowMajor)?
+            .register_constructor::<_, Vec<i32>>("a", Default::default())?
+            .build();
+        let (a, ()) = read.execute()?;
+        assert_eq!(vec![10, 20, 30, 40], a);
+
+        Ok(())
+    }
+
+    /// Test that a sparse write without coordinates for every dimension
+    /// is rejected before it is submitted
+    #[test]
+    fn write_missing_dimension() -> TileDBResult<()> {
+        let mut array = TestArray::new(
+            "write_missing_dimension",
+            Rc::new(quickstart::Builder::new(ArrayType::Sparse).build()),
+        )?;
+
+        let rows = vec![1, 2];
+        let a = vec![10, 20];
+        let query = WriteBuilder::new(array.for_write()?)?
+            .data("rows", &rows)?
+            .data("a", &a)?
+            .build();
+
+        let r = query.submit();
+        let Err(Error::InvalidArgument(e)) = r else {
+            unreachable!("Expected InvalidArgument error")
+        };
+        assert!(e.to_string().contains("cols"));
+        assert!(!e.to_string().contains("rows"));
+
+        Ok(())
+    }
+
+    /// Test that `write_chunk` rejects input which does not match the
+    /// fields of the original input
+    #[test]
+    fn write_chunk_invalid_input() -> TileDBResult<()> {
+        let mut array = TestArray::new(
+            "write_chunk_invalid_input",
+            Rc::new(quickstart::Builder::new(ArrayType::Sparse).build()),
+        )?;
+
+        let mut query = {
+            let mut b = WriteBuilder::new(array.for_write()?)?
+                .layout(QueryLayout::Global)?;
+            for (field, input) in quickstart_input(vec![1], vec![1], vec![10]) {
+                b = b.buffers(field, input)?;
+            }
+            b.build()
+        };
+        query.submit()?;
+
+        // missing field
+        let r = query.write_chunk(vec![
+            ("rows", int32_input(vec![2])),
+            ("cols", int32_input(vec![2])),
+        ]);
+        assert!(matches!(r, Err(Error::InvalidArgument(_))));
+
+        // unexpected field
+        let r = query.write_chunk(vec![
+            ("rows", int32_input(vec![2])),
+            ("cols", int32_input(vec![2])),
+            ("b", int32_input(vec![20])),
+        ]);
+        assert!(matches!(r, Err(Error::InvalidArgument(_))));
+
+        // wrong physical type
+        let r = query.write_chunk(vec![
+            ("rows", int32_input(vec![2])),
+            ("cols", int32_input(vec![2])),
+            (
+                "a",
+                QueryBuffers {
+                    data: Buffer::from(vec![20u64]),
+                    cell_structure: CellStructure::single(),
+                    validity: None,
+                }
+                .into(),
+            ),
+        ]);
+        assert!(matches!(r, Err(Error::InvalidArgument(_))));
+
+        // the original input is still usable
+        query.write_chunk(quickstart_input(vec![2], vec![2], vec![20]))?;
+        query.finalize()?;
+
+        Ok(())
+    }
+
+    /// Test that the iterator-based inputs write the same cells as
+    /// the slice-based inputs
+    #[test]
+    fn write_from_iter() -> TileDBResult<()> {
+        let schema = {
+            let mut b = quickstart::Builder::new(ArrayType::Sparse).build();
+            b.attributes.push(AttributeData {
+                name: "s".to_owned(),
+                datatype: Datatype::StringAscii,
+                cell_val_num: Some(CellValNum::Var),
+                ..Default::default()
+            });
+            Rc::new(b)
+        };
+
+        let rows = vec![1, 2, 2, 4];
+        let cols = vec![1, 1, 3, 4];
+        let a = vec![10, 20, 30, 40];
+        let s = ["foo", "", "bar", "bazzle"];
+
+        let read = |array: Array| -> TileDBResult<(Vec<i32>, Vec<String>)> {
+            let mut q = ReadBuilder::new(array)?
+                .layout(QueryLayout::RowMajor)?
+                .register_constructor::<_, Vec<i32>>("a", Default::default())?
+                .register_constructor::<_, Vec<String>>(
+                    "s",
+                    Default::default(),
+                )?
+                .build();
+            let (s, (a, ())) = q.execute()?;
+            Ok((a, s))
+        };
+
+        let mut from_slice =
+            TestArray::new("write_from_slice", Rc::clone(&schema))?;
+        {
+            let s = s.iter().map(|s| s.to_string()).collect::<Vec<String>>();
+            let q = WriteBuilder::new(from_slice.for_write()?)?
+                .data_typed("rows", &rows)?
+                .data_typed("cols", &cols)?
+                .data_typed("a", &a)?
+                .data("s", &s)?
+                .build();
+            q.submit()?;
+            q.finalize()?;
+        }
+
+        let mut from_iter = TestArray::new("write_from_iter", schema)?;
+        {
+            let q = WriteBuilder::new(from_iter.for_write()?)?
+                .data_from_iter("rows", rows.iter().copied())?
+                .data_from_iter("cols", cols.iter().copied())?
+                .data_from_iter("a", (1..5).map(|i| i * 10))?
+                .data_from_var_iter("s", s.iter().map(|s| s.as_bytes()))?
+                .build();
+            q.submit()?;
+            q.finalize()?;
+        }
+
+        let expect = read(from_slice.for_read()?)?;
+        assert_eq!(a, expect.0);
+        assert_eq!(expect, read(from_iter.for_read()?)?);
+
+        // cell structure must match the schema
+        let r = WriteBuilder::new(from_iter.for_write()?)?.data_from_iter(
+            "s",
+            s.iter()
+                .flat_map(|s| s.bytes())
+                .collect::<Vec<u8>>()
+                .into_iter(),
+        );
+        assert!(matches!(r, Err(Error::UnexpectedCellStructure { .. })));
+
+        Ok(())
+    }
+
+    /// Test that the iterator-based inputs reject fields whose datatype,
+    /// nullability, or cell structure do not match the input
+    #[test]
+    fn write_from_iter_invalid() -> TileDBResult<()> {
+        let schema = {
+            let mut b = quickstart::Builder::new(ArrayType::Sparse).build();
+            b.attributes[0].nullability = Some(true);
+            b.attributes.push(AttributeData {
+                name: "u".to_owned(),
+                datatype: Datatype::UInt8,
+                ..Default::default()
+            });
+            Rc::new(b)
+        };
+        let mut array = TestArray::new("write_from_iter_invalid", schema)?;
+
+        // physical type must match the datatype
+        let r = WriteBuilder::new(array.for_write()?)?
+            .data_from_iter("rows", vec![1u64, 2].into_iter());
+        assert!(matches!(r, Err(Error::InvalidArgument(_))));
+        let r = WriteBuilder::new(array.for_write()?)?
+            .data_from_var_iter("rows", vec![vec![1u8, 2, 3, 4]]);
+        assert!(matches!(r, Err(Error::InvalidArgument(_))));
+
+        // nullable fields cannot be written from an iterator
+        let r = WriteBuilder::new(array.for_write()?)?
+            .data_from_iter("a", vec![10i32, 20].into_iter());
+        assert!(matches!(r, Err(Error::InvalidArgument(_))));
+
+        // cell structure must match the schema
+        let r = WriteBuilder::new(array.for_write()?)?
+            .data_from_var_iter("u", vec![vec![1u8, 2, 3, 4]]);
+        assert!(matches!(r, Err(Error::UnexpectedCellStructure { .. })));
+
+        Ok(())
+    }
+
+    /// Test that `dedup_coordinates` writes only the last of each set of
+    /// cells with duplicate coordinates
+    #[test]
+    fn write_dedup_coordinates() -> TileDBResult<()> {
+        let mut array = TestArray::new(
+            "write_dedup_coordinates",
+            Rc::new(quickstart::Builder::new(ArrayType::Sparse).build()),
+        )?;
+
+        let rows = vec![1, 2, 1, 3, 2];
+        let cols = vec![1, 2, 1, 3, 2];
+        let a = vec![10, 20, 30, 40, 50];
+        let keys = vec!["rows".to_owned(), "cols".to_owned()];
+
+        // duplicates are not allowed
+        {
+            let q = WriteBuilder::new(array.for_write()?)?
+                .data_typed("rows", &rows)?
+                .data_typed("cols", &cols)?
+                .data_typed("a", &a)?
+                .build();
+            assert!(q.submit().is_err());
+        }
+
+        // every key must have input
+        {
+            let r = WriteBuilder::new(array.for_write()?)?
+                .data_typed("rows", &rows)?
+                .data_typed("a", &a)?
+                .dedup_coordinates(keys.clone());
+            assert!(matches!(r, Err(Error::InvalidArgument(_))));
+        }
+
+        let q = WriteBuilder::new(array.for_write()?)?
+            .data_typed("rows", &rows)?
+            .data_typed("cols", &cols)?
+            .data_typed("a", &a)?
+            .dedup_coordinates(keys)?
+            .build();
+        q.submit()?;
+        q.finalize()?;
+
+        let mut read = ReadBuilder::new(array.for_read()?)?
+            .layout(QueryLayout::RowMajor)?
+            .register_constructor::<_, Vec<i32>>("a", Default::default())?
+            .build();
+        let (a, ()) = read.execute()?;
+        assert_eq!(vec![30, 50, 40], a);
+
+        Ok(())
+    }
+}
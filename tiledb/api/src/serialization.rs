@@ -0,0 +1,140 @@
+use std::ops::Deref;
+
+use crate::context::Context;
+use crate::Result as TileDBResult;
+
+/// Format of the serialized representation of a TileDB object,
+/// as used by the TileDB REST protocol.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SerializationType {
+    Json,
+    Capnp,
+}
+
+impl From<SerializationType> for ffi::tiledb_serialization_type_t {
+    fn from(value: SerializationType) -> Self {
+        match value {
+            SerializationType::Json => {
+                ffi::tiledb_serialization_type_t_TILEDB_JSON
+            }
+            SerializationType::Capnp => {
+                ffi::tiledb_serialization_type_t_TILEDB_CAPNP
+            }
+        }
+    }
+}
+
+impl TryFrom<ffi::tiledb_serialization_type_t> for SerializationType {
+    type Error = ffi::tiledb_serialization_type_t;
+
+    fn try_from(
+        value: ffi::tiledb_serialization_type_t,
+    ) -> Result<Self, Self::Error> {
+        match value {
+            ffi::tiledb_serialization_type_t_TILEDB_JSON => Ok(Self::Json),
+            ffi::tiledb_serialization_type_t_TILEDB_CAPNP => Ok(Self::Capnp),
+            _ => Err(value),
+        }
+    }
+}
+
+/// Wrapper for the CAPI handle.
+/// Ensures that the CAPI structure is freed.
+pub(crate) enum RawBuffer {
+    Owned(*mut ffi::tiledb_buffer_t),
+}
+
+impl Deref for RawBuffer {
+    type Target = *mut ffi::tiledb_buffer_t;
+
+    fn deref(&self) -> &Self::Target {
+        let RawBuffer::Owned(ref ffi) = *self;
+        ffi
+    }
+}
+
+impl Drop for RawBuffer {
+    fn drop(&mut self) {
+        let RawBuffer::Owned(ref mut ffi) = *self;
+        unsafe { ffi::tiledb_buffer_free(ffi) }
+    }
+}
+
+impl RawBuffer {
+    /// Returns a buffer which refers to, but does not own, `data`.
+    /// The buffer must not outlive `data`.
+    pub(crate) fn borrowed(
+        context: &Context,
+        data: &[u8],
+    ) -> TileDBResult<Self> {
+        let mut c_buffer: *mut ffi::tiledb_buffer_t = out_ptr!();
+        context.capi_call(|ctx| unsafe {
+            ffi::tiledb_buffer_alloc(ctx, &mut c_buffer)
+        })?;
+        let buffer = RawBuffer::Owned(c_buffer);
+
+        context.capi_call(|ctx| unsafe {
+            ffi::tiledb_buffer_set_data(
+                ctx,
+                c_buffer,
+                data.as_ptr() as *mut std::ffi::c_void,
+                data.len() as u64,
+            )
+        })?;
+        Ok(buffer)
+    }
+
+    /// Returns a copy of the contents of the buffer.
+    pub(crate) fn to_vec(&self, context: &Context) -> TileDBResult<Vec<u8>> {
+        let c_buffer = **self;
+        let mut c_data: *mut std::ffi::c_void = out_ptr!();
+        let mut c_size: u64 = 0;
+        context.capi_call(|ctx| unsafe {
+            ffi::tiledb_buffer_get_data(ctx, c_buffer, &mut c_data, &mut c_size)
+        })?;
+        if c_data.is_null() || c_size == 0 {
+            Ok(vec![])
+        } else {
+            Ok(unsafe {
+                std::slice::from_raw_parts(c_data as *const u8, c_size as usize)
+            }
+            .to_vec())
+        }
+    }
+}
+
+/// Wrapper for the CAPI handle.
+/// Ensures that the CAPI structure is freed.
+pub(crate) enum RawBufferList {
+    Owned(*mut ffi::tiledb_buffer_list_t),
+}
+
+impl Deref for RawBufferList {
+    type Target = *mut ffi::tiledb_buffer_list_t;
+
+    fn deref(&self) -> &Self::Target {
+        let RawBufferList::Owned(ref ffi) = *self;
+        ffi
+    }
+}
+
+impl Drop for RawBufferList {
+    fn drop(&mut self) {
+        let RawBufferList::Owned(ref mut ffi) = *self;
+        unsafe { ffi::tiledb_buffer_list_free(ffi) }
+    }
+}
+
+impl RawBufferList {
+    /// Returns a copy of the contents of each buffer of the list,
+    /// concatenated into a single buffer.
+    pub(crate) fn to_vec(&self, context: &Context) -> TileDBResult<Vec<u8>> {
+        let c_buffer_list = **self;
+        let mut c_buffer: *mut ffi::tiledb_buffer_t = out_ptr!();
+        context.capi_call(|ctx| unsafe {
+            ffi::tiledb_buffer_list_flatten(ctx, c_buffer_list, &mut c_buffer)
+        })?;
+
+        RawBuffer::Owned(c_buffer).to_vec(context)
+    }
+}
@@ -128,8 +128,13 @@ pub fn dump_json() -> Result<Option<Vec<Metrics>>, Error> {
 
 #[cfg(test)]
 mod tests {
+    use std::sync::Mutex;
+
     use super::*;
 
+    /// Serializes tests which enable stats, since stats are process-global.
+    static STATS_LOCK: Mutex<()> = Mutex::new(());
+
     struct ScopedStats {
         was_enabled: bool,
     }
@@ -152,6 +157,7 @@ mod tests {
 
     #[test]
     fn scoped_stats() -> anyhow::Result<()> {
+        let _lock = STATS_LOCK.lock().unwrap();
         assert!(!is_enabled()?);
 
         {
@@ -163,4 +169,46 @@ mod tests {
 
         Ok(())
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn context_stats_json() -> anyhow::Result<()> {
+        use proptest::test_runner::TestRunner;
+        use tiledb_common::array::ArrayType;
+
+        use crate::tests::examples::{quickstart, TestArray};
+
+        let _lock = STATS_LOCK.lock().unwrap();
+
+        let mut array = TestArray::new(
+            "context_stats_json",
+            quickstart::Builder::new(ArrayType::Sparse).build().into(),
+        )?;
+        let input = array.arbitrary_input(&mut TestRunner::deterministic());
+        array.try_insert(&input)?;
+
+        let ctx = array.context.clone();
+
+        // there may be nothing to report, which is not an error
+        ctx.reset_stats()?;
+        let json = ctx.stats_json()?;
+        assert!(json.is_null() || json.is_array(), "{:?}", json);
+
+        let _stats = ScopedStats::new()?;
+        ctx.reset_stats()?;
+
+        let _ = array.for_read()?.read_all(&[])?;
+
+        let json = ctx.stats_json()?;
+        let metrics = json.as_array().expect("Expected array of metrics");
+        assert!(metrics.iter().any(|m| m
+            .get("timers")
+            .and_then(|t| t.as_object())
+            .is_some_and(|t| !t.is_empty())));
+
+        // the same stats are available in the structured form
+        assert!(dump_json()?.is_some());
+
+        Ok(())
+    }
 }
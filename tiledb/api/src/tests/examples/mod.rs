@@ -55,6 +55,10 @@ impl TestArray {
         self.open(Mode::Write)
     }
 
+    pub fn for_delete(&mut self) -> TileDBResult<Array> {
+        self.open(Mode::Delete)
+    }
+
     pub fn arbitrary_input(&self, runner: &mut TestRunner) -> WriteInput {
         match self.schema.array_type {
             ArrayType::Sparse => {
@@ -1,3 +1,4 @@
+use std::io::{self, Read, Seek, SeekFrom, Write};
 use std::ops::Deref;
 
 use crate::config::{Config, RawConfig};
@@ -12,6 +13,15 @@ pub enum VFSLsStatus {
     Error,
 }
 
+/// The order in which [VFS::walk] lists a directory relative to its contents.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum WalkOrder {
+    /// Each directory is listed before its contents.
+    Preorder,
+    /// Each directory is listed after its contents.
+    Postorder,
+}
+
 pub(crate) enum RawVFS {
     Owned(*mut ffi::tiledb_vfs_t),
 }
@@ -291,6 +301,35 @@ impl VFS {
         })
     }
 
+    /// Opens the file at `uri` for use with the [std::io] traits.
+    /// See [VFSFile].
+    pub fn open_file(&self, uri: &str, mode: VFSMode) -> TileDBResult<VFSFile> {
+        // a reader and a writer start at the beginning of the file, since
+        // opening for write truncates it, and an appender starts at the end
+        let (size, position) = match mode {
+            VFSMode::Read => (self.file_size(uri)?, 0),
+            VFSMode::Write => (0, 0),
+            VFSMode::Append => {
+                let size = if self.is_file(uri)? {
+                    self.file_size(uri)?
+                } else {
+                    0
+                };
+                (size, size)
+            }
+        };
+        let handle = self.open(uri, mode)?;
+        Ok(VFSFile {
+            handle,
+            mode,
+            size,
+            position,
+            buffer: vec![],
+            buffer_offset: 0,
+            closed: false,
+        })
+    }
+
     pub fn remove_file(&self, uri: &str) -> TileDBResult<()> {
         let c_vfs = *self.raw;
         let c_uri = cstring!(uri);
@@ -384,6 +423,49 @@ impl VFS {
 
         Ok(())
     }
+
+    /// Returns the URIs of all files and directories beneath `uri`.
+    ///
+    /// Unlike [VFS::ls_recursive], this descends into each directory
+    /// using [VFS::ls] and is supported by every backend.
+    /// The URIs are returned as reported by the backend, and `uri`
+    /// itself is not included.
+    pub fn walk(
+        &self,
+        uri: &str,
+        order: WalkOrder,
+    ) -> TileDBResult<Vec<String>> {
+        let mut uris = vec![];
+        self.walk_into(uri, order, &mut uris)?;
+        Ok(uris)
+    }
+
+    fn walk_into(
+        &self,
+        uri: &str,
+        order: WalkOrder,
+        uris: &mut Vec<String>,
+    ) -> TileDBResult<()> {
+        let mut children = vec![];
+        self.ls(uri, |child| {
+            children.push(child.to_owned());
+            VFSLsStatus::Continue
+        })?;
+
+        for child in children {
+            if order == WalkOrder::Preorder {
+                uris.push(child.clone());
+            }
+            if self.is_dir(&child)? {
+                self.walk_into(&child, order, uris)?;
+            }
+            if order == WalkOrder::Postorder {
+                uris.push(child);
+            }
+        }
+
+        Ok(())
+    }
 }
 
 // This bit of complexity is based on the StackOverflow answer here:
@@ -508,6 +590,188 @@ impl VFSHandle {
     }
 }
 
+/// A file opened using [VFS::open_file], which implements the
+/// [std::io] traits.
+///
+/// A file opened with [VFSMode::Read] implements [Read] and [Seek].
+/// Reads are buffered internally, so small reads do not each
+/// require a request to the backend.
+///
+/// A file opened with [VFSMode::Write] or [VFSMode::Append] implements [Write].
+/// Writes are buffered internally and are written to the backend when the
+/// buffer fills, when the file is flushed, and when the file is closed.
+/// The file is closed when it is dropped, but any error which occurs then
+/// is ignored. Use [VFSFile::close] to observe such errors.
+pub struct VFSFile {
+    handle: VFSHandle,
+    mode: VFSMode,
+    /// Size of the file when it was opened.
+    size: u64,
+    /// Offset of the next byte to read or write.
+    position: u64,
+    /// Bytes which have been read ahead or which are waiting to be written.
+    buffer: Vec<u8>,
+    /// Offset of the first byte of `buffer` in the file, in read mode.
+    buffer_offset: u64,
+    closed: bool,
+}
+
+impl VFSFile {
+    /// Size of the internal buffer.
+    pub const BUFFER_CAPACITY: usize = 1024 * 1024;
+
+    pub fn mode(&self) -> VFSMode {
+        self.mode
+    }
+
+    /// Writes any buffered data and closes the file.
+    pub fn close(mut self) -> TileDBResult<()> {
+        self.closed = true;
+        self.write_buffer()?;
+        self.handle.close()
+    }
+
+    fn write_buffer(&mut self) -> TileDBResult<()> {
+        if self.mode != VFSMode::Read && !self.buffer.is_empty() {
+            self.handle.write(&self.buffer)?;
+            self.buffer.clear();
+        }
+        Ok(())
+    }
+
+    fn require_read(&self) -> io::Result<()> {
+        if self.mode == VFSMode::Read {
+            Ok(())
+        } else {
+            Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                format!("VFS file opened in {:?} mode cannot read", self.mode),
+            ))
+        }
+    }
+
+    fn require_write(&self) -> io::Result<()> {
+        if self.mode == VFSMode::Read {
+            Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                "VFS file opened in Read mode cannot write",
+            ))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl ContextBound for VFSFile {
+    fn context(&self) -> Context {
+        self.handle.context()
+    }
+}
+
+impl Read for VFSFile {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.require_read()?;
+        if buf.is_empty() || self.position >= self.size {
+            return Ok(0);
+        }
+
+        let buffer_end = self.buffer_offset + self.buffer.len() as u64;
+        if !(self.buffer_offset..buffer_end).contains(&self.position) {
+            let remaining = self.size - self.position;
+            if buf.len() >= Self::BUFFER_CAPACITY {
+                // skip the buffer for large reads
+                let n = std::cmp::min(buf.len() as u64, remaining) as usize;
+                self.handle
+                    .read(self.position, &mut buf[..n])
+                    .map_err(io::Error::other)?;
+                self.position += n as u64;
+                return Ok(n);
+            }
+
+            let n =
+                std::cmp::min(Self::BUFFER_CAPACITY as u64, remaining) as usize;
+            self.buffer.resize(n, 0);
+            self.handle
+                .read(self.position, &mut self.buffer)
+                .map_err(io::Error::other)?;
+            self.buffer_offset = self.position;
+        }
+
+        let start = (self.position - self.buffer_offset) as usize;
+        let n = std::cmp::min(buf.len(), self.buffer.len() - start);
+        buf[..n].copy_from_slice(&self.buffer[start..start + n]);
+        self.position += n as u64;
+        Ok(n)
+    }
+}
+
+impl Write for VFSFile {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.require_write()?;
+        if self.buffer.len() + buf.len() > Self::BUFFER_CAPACITY {
+            self.write_buffer().map_err(io::Error::other)?;
+        }
+        if buf.len() >= Self::BUFFER_CAPACITY {
+            self.handle.write(buf).map_err(io::Error::other)?;
+        } else {
+            self.buffer.extend_from_slice(buf);
+        }
+        self.position += buf.len() as u64;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.require_write()?;
+        self.write_buffer().map_err(io::Error::other)?;
+        self.handle.sync().map_err(io::Error::other)
+    }
+}
+
+/// A file opened for reading can seek anywhere. A file opened for writing
+/// can only "seek" to its current position, which reports the number
+/// of bytes in the file.
+impl Seek for VFSFile {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        let (base, delta) = match pos {
+            SeekFrom::Start(offset) => (offset, 0),
+            SeekFrom::Current(delta) => (self.position, delta),
+            SeekFrom::End(delta) => {
+                let end = if self.mode == VFSMode::Read {
+                    self.size
+                } else {
+                    self.position
+                };
+                (end, delta)
+            }
+        };
+        let Some(position) = base.checked_add_signed(delta) else {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "invalid seek to a negative or overflowing position",
+            ));
+        };
+
+        if self.mode != VFSMode::Read && position != self.position {
+            return Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                format!("VFS file opened in {:?} mode cannot seek", self.mode),
+            ));
+        }
+
+        self.position = position;
+        Ok(position)
+    }
+}
+
+impl Drop for VFSFile {
+    fn drop(&mut self) {
+        if !self.closed {
+            let _ = self.write_buffer();
+            let _ = self.handle.close();
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -647,6 +911,88 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn vfs_file_io() -> TileDBResult<()> {
+        let ctx = Context::new()?;
+        let cfg = Config::new()?;
+        let vfs = VFS::new(&ctx, &cfg)?;
+
+        let test_uri =
+            TestDirectory::new().map_err(|e| Error::Other(e.to_string()))?;
+        let file_uri = test_uri
+            .with_path("vfs_test_file_io")
+            .map_err(|e| Error::Other(e.to_string()))?;
+
+        // large enough to span several buffers
+        let data = (0..(VFSFile::BUFFER_CAPACITY * 5 / 2))
+            .map(|i| (i % 251) as u8)
+            .collect::<Vec<u8>>();
+
+        // write in pieces smaller and larger than the buffer
+        {
+            let mut f = vfs.open_file(&file_uri, VFSMode::Write)?;
+            let (small, large) = data.split_at(1000);
+            for chunk in small.chunks(7) {
+                f.write_all(chunk)
+                    .map_err(|e| Error::Other(e.to_string()))?;
+            }
+            f.write_all(large)
+                .map_err(|e| Error::Other(e.to_string()))?;
+            assert_eq!(
+                data.len() as u64,
+                f.stream_position()
+                    .map_err(|e| Error::Other(e.to_string()))?
+            );
+
+            let mut buf = [0u8; 1];
+            assert!(f.read(&mut buf).is_err());
+            assert!(f.seek(SeekFrom::Start(0)).is_err());
+            // dropping the file writes the rest
+        }
+        assert_eq!(data.len() as u64, vfs.file_size(&file_uri)?);
+
+        let mut f = vfs.open_file(&file_uri, VFSMode::Read)?;
+        let mut contents = vec![];
+        f.read_to_end(&mut contents)
+            .map_err(|e| Error::Other(e.to_string()))?;
+        assert_eq!(data, contents);
+        assert!(f.write(&[0u8]).is_err());
+
+        let mut buf = [0u8; 100];
+        let offset = VFSFile::BUFFER_CAPACITY as u64 - 50;
+        f.seek(SeekFrom::Start(offset))
+            .map_err(|e| Error::Other(e.to_string()))?;
+        f.read_exact(&mut buf)
+            .map_err(|e| Error::Other(e.to_string()))?;
+        assert_eq!(data[offset as usize..][..100], buf);
+
+        f.seek(SeekFrom::End(-10))
+            .map_err(|e| Error::Other(e.to_string()))?;
+        let mut tail = vec![];
+        f.read_to_end(&mut tail)
+            .map_err(|e| Error::Other(e.to_string()))?;
+        assert_eq!(data[data.len() - 10..], tail);
+
+        assert!(f.seek(SeekFrom::Current(-(data.len() as i64) - 1)).is_err());
+        f.close()?;
+
+        // appending continues from the end of the file
+        {
+            let mut f = vfs.open_file(&file_uri, VFSMode::Append)?;
+            f.write_all(b"tail")
+                .map_err(|e| Error::Other(e.to_string()))?;
+            assert_eq!(
+                data.len() as u64 + 4,
+                f.stream_position()
+                    .map_err(|e| Error::Other(e.to_string()))?
+            );
+            f.close()?;
+        }
+        assert_eq!(data.len() as u64 + 4, vfs.file_size(&file_uri)?);
+
+        Ok(())
+    }
+
     fn create_test_dir_structure(
         vfs: &VFS,
         test_uri: &TestDirectory,
@@ -708,6 +1054,43 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn vfs_walk() -> TileDBResult<()> {
+        let ctx = Context::new()?;
+        let cfg = Config::new()?;
+        let vfs = VFS::new(&ctx, &cfg)?;
+
+        let test_uri =
+            TestDirectory::new().map_err(|e| Error::Other(e.to_string()))?;
+
+        create_test_dir_structure(&vfs, &test_uri)?;
+
+        let tmp_uri = test_uri
+            .base_dir()
+            .map_err(|e| Error::Other(e.to_string()))?;
+
+        let position = |uris: &[String], suffix: &str| {
+            uris.iter().position(|u| u.ends_with(suffix)).unwrap()
+        };
+
+        // walk sees three directories and one file.
+        let preorder = vfs.walk(&tmp_uri, WalkOrder::Preorder)?;
+        assert_eq!(preorder.len(), 4);
+        assert!(
+            position(&preorder, "vfs_test_dir_1")
+                < position(&preorder, "vfs_test_dir_1/foo")
+        );
+
+        let postorder = vfs.walk(&tmp_uri, WalkOrder::Postorder)?;
+        assert_eq!(postorder.len(), 4);
+        assert!(
+            position(&postorder, "vfs_test_dir_1/foo")
+                < position(&postorder, "vfs_test_dir_1")
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn vfs_ls_recursive_old() -> TileDBResult<()> {
         // Recursive ls over the Posix backend doesn't exist before 2.21
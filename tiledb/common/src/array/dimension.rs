@@ -1,3 +1,5 @@
+use std::fmt::Debug;
+
 use thiserror::Error;
 
 #[cfg(feature = "option-subset")]
@@ -252,4 +254,263 @@ impl DimensionConstraints {
             None
         )
     }
+
+    /// Splits the domain of this constraint into at most `n` contiguous,
+    /// non-overlapping ranges which together cover the whole domain.
+    ///
+    /// The ranges begin on tile boundaries and contain roughly equal
+    /// numbers of tiles. Fewer than `n` ranges are returned if the
+    /// domain has fewer than `n` tiles. An integral domain without
+    /// a tile extent is split between cells, and a floating-point domain
+    /// without a tile extent is split into `n` ranges of equal width.
+    ///
+    /// Returns `None` for `StringAscii`, which has no domain, or if `n` is zero.
+    /// ```
+    /// use tiledb_common::array::dimension::DimensionConstraints;
+    /// use tiledb_common::range::SingleValueRange;
+    ///
+    /// let c = DimensionConstraints::from(([1i32, 100], 10));
+    /// assert_eq!(
+    ///     Some(vec![
+    ///         SingleValueRange::Int32(1, 20),
+    ///         SingleValueRange::Int32(21, 50),
+    ///         SingleValueRange::Int32(51, 70),
+    ///         SingleValueRange::Int32(71, 100),
+    ///     ]),
+    ///     c.tile_partition(4)
+    /// );
+    /// ```
+    pub fn tile_partition(&self, n: usize) -> Option<Vec<SingleValueRange>> {
+        if n == 0 {
+            return None;
+        }
+        crate::dimension_constraints_go!(
+            self,
+            DT,
+            [low, high],
+            extent,
+            {
+                let (low, high) = (i128::from(*low), i128::from(*high));
+                let extent = extent.map(i128::from).unwrap_or(1);
+                let ntiles = (high - low) / extent + 1;
+                let nranges = std::cmp::min(ntiles, n as i128);
+
+                // the first tile of each range
+                let first_tile = |r: i128| r * ntiles / nranges;
+                let ranges = (0..nranges)
+                    .map(|r| {
+                        let start = low + first_tile(r) * extent;
+                        let end = std::cmp::min(
+                            high,
+                            low + first_tile(r + 1) * extent - 1,
+                        );
+                        SingleValueRange::from(&[
+                            DT::try_from(start).unwrap(),
+                            DT::try_from(end).unwrap(),
+                        ])
+                    })
+                    .collect::<Vec<_>>();
+                Some(ranges)
+            },
+            {
+                let (low, high) = (*low, *high);
+                let (extent, ntiles) = if let Some(extent) = extent {
+                    (*extent, ((high - low) / *extent).ceil().max(1.0) as u128)
+                } else {
+                    ((high - low) / n as DT, n as u128)
+                };
+                let nranges = std::cmp::min(ntiles, n as u128);
+
+                // each range starts at a tile boundary, and ends just before
+                // the start of the next range
+                let mut starts = (0..nranges)
+                    .map(|r| {
+                        let tile = r * ntiles / nranges;
+                        let start = low + (tile as DT) * extent;
+                        if start > high {
+                            high
+                        } else {
+                            start
+                        }
+                    })
+                    .collect::<Vec<DT>>();
+                starts.dedup();
+
+                let ranges = starts
+                    .iter()
+                    .enumerate()
+                    .map(|(r, start)| {
+                        let end = starts
+                            .get(r + 1)
+                            .map(|next| next.predecessor())
+                            .unwrap_or(high);
+                        SingleValueRange::from(&[*start, end])
+                    })
+                    .collect::<Vec<_>>();
+                Some(ranges)
+            },
+            None
+        )
+    }
+}
+
+/// Returns the greatest value less than `self`,
+/// for splitting domains into disjoint ranges.
+trait Predecessor {
+    fn predecessor(self) -> Self;
+}
+
+macro_rules! predecessor_impl {
+    (integral: $($ty:ty),+) => {
+        $(
+            impl Predecessor for $ty {
+                fn predecessor(self) -> Self {
+                    self.saturating_sub(1)
+                }
+            }
+        )+
+    };
+    (float: $($ty:ty),+) => {
+        $(
+            impl Predecessor for $ty {
+                fn predecessor(self) -> Self {
+                    if self.is_nan() || self == <$ty>::NEG_INFINITY {
+                        self
+                    } else if self == 0.0 {
+                        -<$ty>::from_bits(1)
+                    } else if self > 0.0 {
+                        <$ty>::from_bits(self.to_bits() - 1)
+                    } else {
+                        <$ty>::from_bits(self.to_bits() + 1)
+                    }
+                }
+            }
+        )+
+    };
+}
+
+predecessor_impl!(integral: i8, i16, i32, i64, u8, u16, u32, u64);
+predecessor_impl!(float: f32, f64);
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::datatype::PhysicalType;
+    use crate::range::Range;
+
+    /// Asserts that `ranges` are in order, do not overlap,
+    /// and together cover exactly `[low, high]`.
+    fn assert_partition<T>(ranges: &[SingleValueRange], low: T, high: T)
+    where
+        T: PhysicalType + PartialOrd + Predecessor,
+    {
+        let bounds = ranges
+            .iter()
+            .map(|r| {
+                let (start, end) =
+                    Range::Single(r.clone()).as_typed::<T>().unwrap();
+                assert!(start <= end, "{:?}", ranges);
+                (start, end)
+            })
+            .collect::<Vec<(T, T)>>();
+
+        assert_eq!(low, bounds.first().unwrap().0);
+        assert_eq!(high, bounds.last().unwrap().1);
+        for w in bounds.windows(2) {
+            assert_eq!(w[0].1, w[1].0.predecessor(), "{:?}", ranges);
+        }
+    }
+
+    #[test]
+    fn tile_partition_int32() {
+        let c = DimensionConstraints::from(([1i32, 100], 10));
+        for n in 1..=10 {
+            let ranges = c.tile_partition(n).unwrap();
+            assert_eq!(n, ranges.len());
+            assert_partition(&ranges, 1i32, 100i32);
+            // each range starts on a tile boundary
+            for r in ranges.iter() {
+                let (start, _) =
+                    Range::Single(r.clone()).as_typed::<i32>().unwrap();
+                assert_eq!(1, start % 10);
+            }
+        }
+
+        // no more ranges than tiles
+        let ranges = c.tile_partition(20).unwrap();
+        assert_eq!(10, ranges.len());
+        assert_partition(&ranges, 1i32, 100i32);
+
+        // partial last tile
+        let c = DimensionConstraints::from(([-5i32, 7], 4));
+        let ranges = c.tile_partition(2).unwrap();
+        assert_eq!(
+            vec![
+                SingleValueRange::Int32(-5, 2),
+                SingleValueRange::Int32(3, 7)
+            ],
+            ranges
+        );
+
+        // whole domain of the type
+        let c = DimensionConstraints::from(([i32::MIN, i32::MAX], 1 << 20));
+        assert_partition(&c.tile_partition(7).unwrap(), i32::MIN, i32::MAX);
+
+        assert_eq!(None, c.tile_partition(0));
+        assert_eq!(None, DimensionConstraints::StringAscii.tile_partition(4));
+    }
+
+    #[test]
+    fn tile_partition_float64() {
+        let c = DimensionConstraints::from(([0.0f64, 100.0], 10.0));
+        let ranges = c.tile_partition(4).unwrap();
+        assert_eq!(4, ranges.len());
+        assert_partition(&ranges, 0.0f64, 100.0f64);
+        let starts = ranges
+            .iter()
+            .map(|r| Range::Single(r.clone()).as_typed::<f64>().unwrap().0)
+            .collect::<Vec<f64>>();
+        assert_eq!(vec![0.0, 20.0, 50.0, 70.0], starts);
+
+        // no extent
+        let c = DimensionConstraints::from([-1.0f64, 1.0]);
+        let ranges = c.tile_partition(4).unwrap();
+        assert_eq!(4, ranges.len());
+        assert_partition(&ranges, -1.0f64, 1.0f64);
+
+        // single point
+        let c = DimensionConstraints::from(([3.0f64, 3.0], 1.0));
+        assert_eq!(
+            vec![SingleValueRange::Float64(3.0, 3.0)],
+            c.tile_partition(4).unwrap()
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn tile_partition_int64(
+            (low, high) in (any::<i64>(), any::<i64>()).prop_map(|(a, b)| (a.min(b), a.max(b))),
+            extent in 1..=i64::MAX,
+            n in 1..=64usize
+        ) {
+            let c = DimensionConstraints::from(([low, high], extent));
+            let ranges = c.tile_partition(n).unwrap();
+            assert!(ranges.len() <= n);
+            assert_partition(&ranges, low, high);
+        }
+
+        #[test]
+        fn tile_partition_float64_arbitrary(
+            (low, high) in (-1e12f64..1e12, -1e12f64..1e12).prop_map(|(a, b)| (a.min(b), a.max(b))),
+            extent in proptest::option::of(1e-3f64..1e6),
+            n in 1..=64usize
+        ) {
+            let c = DimensionConstraints::from(([low, high], extent));
+            let ranges = c.tile_partition(n).unwrap();
+            assert!(ranges.len() <= n);
+            assert_partition(&ranges, low, high);
+        }
+    }
 }
@@ -345,17 +345,12 @@ pub fn from_arrow(value: &arrow_schema::DataType) -> DatatypeFromArrowResult {
              */
             Res::None
         }
-        ADT::Timestamp(TimeUnit::Second, _) => {
-            Res::Exact(Datatype::DateTimeSecond, CellValNum::single())
+        ADT::Timestamp(unit, None) => {
+            Res::Exact(from_arrow_timestamp(unit), CellValNum::single())
         }
-        ADT::Timestamp(TimeUnit::Millisecond, _) => {
-            Res::Exact(Datatype::DateTimeMillisecond, CellValNum::single())
-        }
-        ADT::Timestamp(TimeUnit::Microsecond, _) => {
-            Res::Exact(Datatype::DateTimeMicrosecond, CellValNum::single())
-        }
-        ADT::Timestamp(TimeUnit::Nanosecond, _) => {
-            Res::Exact(Datatype::DateTimeNanosecond, CellValNum::single())
+        ADT::Timestamp(unit, Some(_)) => {
+            /* tiledb datetimes have no time zone, so it cannot be recovered */
+            Res::Inexact(from_arrow_timestamp(unit), CellValNum::single())
         }
         ADT::Date32 | ADT::Time32(_) => {
             Res::Inexact(Datatype::Int32, CellValNum::single())
@@ -509,6 +504,48 @@ pub fn from_arrow(value: &arrow_schema::DataType) -> DatatypeFromArrowResult {
     }
 }
 
+/// Returns the tiledb DateTime `Datatype` with the same unit as an arrow timestamp.
+fn from_arrow_timestamp(unit: &TimeUnit) -> Datatype {
+    match unit {
+        TimeUnit::Second => Datatype::DateTimeSecond,
+        TimeUnit::Millisecond => Datatype::DateTimeMillisecond,
+        TimeUnit::Microsecond => Datatype::DateTimeMicrosecond,
+        TimeUnit::Nanosecond => Datatype::DateTimeNanosecond,
+    }
+}
+
+/// Returns the arrow `TimeUnit` corresponding to a tiledb `TimeUnit`,
+/// if there is one.
+///
+/// ```
+/// use tiledb_common::datatype::arrow::to_arrow_time_unit;
+/// use tiledb_common::datatype::Datatype;
+///
+/// let unit = Datatype::DateTimeMillisecond.time_unit().unwrap();
+/// assert_eq!(Some(arrow_schema::TimeUnit::Millisecond), to_arrow_time_unit(unit));
+///
+/// let unit = Datatype::DateTimeDay.time_unit().unwrap();
+/// assert_eq!(None, to_arrow_time_unit(unit));
+/// ```
+pub fn to_arrow_time_unit(unit: super::TimeUnit) -> Option<TimeUnit> {
+    use super::TimeUnit as TileDBTimeUnit;
+    match unit {
+        TileDBTimeUnit::Second => Some(TimeUnit::Second),
+        TileDBTimeUnit::Millisecond => Some(TimeUnit::Millisecond),
+        TileDBTimeUnit::Microsecond => Some(TimeUnit::Microsecond),
+        TileDBTimeUnit::Nanosecond => Some(TimeUnit::Nanosecond),
+        TileDBTimeUnit::Year
+        | TileDBTimeUnit::Month
+        | TileDBTimeUnit::Week
+        | TileDBTimeUnit::Day
+        | TileDBTimeUnit::Hour
+        | TileDBTimeUnit::Minute
+        | TileDBTimeUnit::Picosecond
+        | TileDBTimeUnit::Femtosecond
+        | TileDBTimeUnit::Attosecond => None,
+    }
+}
+
 #[cfg(any(test, feature = "proptest-strategies"))]
 pub mod strategy {
     use std::collections::HashMap;
@@ -1050,7 +1087,94 @@ pub mod tests {
         }
     }
 
+    fn prop_temporal_datatype() -> impl Strategy<Value = Datatype> {
+        proptest::sample::select(
+            Datatype::iter()
+                .filter(|dt| dt.is_datetime_type() || dt.is_time_type())
+                .collect::<Vec<_>>(),
+        )
+    }
+
+    fn prop_arrow_temporal() -> impl Strategy<Value = arrow_schema::DataType> {
+        use arrow_schema::DataType as ADT;
+
+        let unit = prop_oneof![
+            Just(TimeUnit::Second),
+            Just(TimeUnit::Millisecond),
+            Just(TimeUnit::Microsecond),
+            Just(TimeUnit::Nanosecond),
+        ];
+        let tz = proptest::option::of(prop_oneof![
+            Just(Arc::<str>::from("+00:00")),
+            Just(Arc::<str>::from("America/New_York")),
+        ]);
+        prop_oneof![
+            (unit, tz).prop_map(|(unit, tz)| ADT::Timestamp(unit, tz)),
+            Just(ADT::Time64(TimeUnit::Microsecond)),
+            Just(ADT::Time64(TimeUnit::Nanosecond)),
+        ]
+    }
+
+    /// Temporal types with an arrow equivalent must convert exactly
+    /// and round trip without loss.
+    fn do_to_arrow_temporal(tdb_dt: Datatype) {
+        use arrow_schema::DataType as ADT;
+
+        let arrow_unit = tdb_dt.time_unit().and_then(to_arrow_time_unit);
+        let expect = match arrow_unit {
+            Some(unit) if tdb_dt.is_datetime_type() => {
+                DatatypeToArrowResult::Exact(ADT::Timestamp(unit, None))
+            }
+            Some(unit @ (TimeUnit::Microsecond | TimeUnit::Nanosecond)) => {
+                DatatypeToArrowResult::Exact(ADT::Time64(unit))
+            }
+            _ => DatatypeToArrowResult::Inexact(ADT::Int64),
+        };
+
+        let arrow = to_arrow(&tdb_dt, CellValNum::single());
+        assert_eq!(expect, arrow);
+
+        if let DatatypeToArrowResult::Exact(arrow) = arrow {
+            assert_eq!(
+                DatatypeFromArrowResult::Exact(tdb_dt, CellValNum::single()),
+                from_arrow(&arrow)
+            );
+        }
+    }
+
+    fn do_from_arrow_temporal(arrow_in: arrow_schema::DataType) {
+        use arrow_schema::DataType as ADT;
+
+        let tdb = from_arrow(&arrow_in);
+        let Some((tdb_dt, cell_val_num)) = tdb.ok() else {
+            unreachable!("Expected temporal datatype for {}", arrow_in)
+        };
+        assert!(tdb_dt.is_datetime_type() || tdb_dt.is_time_type());
+
+        let arrow_out = to_arrow(&tdb_dt, cell_val_num);
+        assert!(arrow_out.is_exact());
+
+        if let ADT::Timestamp(unit, Some(_)) = &arrow_in {
+            /* the time zone is lost */
+            assert!(tdb.is_inexact());
+            assert_eq!(ADT::Timestamp(*unit, None), arrow_out.into_inner());
+        } else {
+            assert!(tdb.is_exact());
+            assert_eq!(arrow_in, arrow_out.into_inner());
+        }
+    }
+
     proptest! {
+        #[test]
+        fn test_to_arrow_temporal(tdb_dt in prop_temporal_datatype()) {
+            do_to_arrow_temporal(tdb_dt)
+        }
+
+        #[test]
+        fn test_from_arrow_temporal(arrow in prop_arrow_temporal()) {
+            do_from_arrow_temporal(arrow)
+        }
+
         #[test]
         fn test_to_arrow_single(tdb_dt in any::<Datatype>()) {
             do_to_arrow_single(tdb_dt)
@@ -34,6 +34,8 @@ pub enum Error {
         source_type: Datatype,
         target_type: Datatype,
     },
+    #[error("Invalid UTF-8 value: {0}")]
+    InvalidUtf8(#[from] std::string::FromUtf8Error),
 }
 
 impl Error {
@@ -52,6 +54,25 @@ impl Error {
     }
 }
 
+/// Unit of measure of the values of a DateTime or Time [Datatype].
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub enum TimeUnit {
+    Year,
+    Month,
+    Week,
+    Day,
+    Hour,
+    Minute,
+    Second,
+    Millisecond,
+    Microsecond,
+    Nanosecond,
+    Picosecond,
+    Femtosecond,
+    Attosecond,
+}
+
 #[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 #[repr(u64)]
@@ -414,6 +435,39 @@ impl Datatype {
         )
     }
 
+    /// Returns the unit of measure of a DateTime or Time type,
+    /// or `None` if this is not a DateTime or Time type.
+    pub fn time_unit(&self) -> Option<TimeUnit> {
+        Some(match *self {
+            Datatype::DateTimeYear => TimeUnit::Year,
+            Datatype::DateTimeMonth => TimeUnit::Month,
+            Datatype::DateTimeWeek => TimeUnit::Week,
+            Datatype::DateTimeDay => TimeUnit::Day,
+            Datatype::DateTimeHour | Datatype::TimeHour => TimeUnit::Hour,
+            Datatype::DateTimeMinute | Datatype::TimeMinute => TimeUnit::Minute,
+            Datatype::DateTimeSecond | Datatype::TimeSecond => TimeUnit::Second,
+            Datatype::DateTimeMillisecond | Datatype::TimeMillisecond => {
+                TimeUnit::Millisecond
+            }
+            Datatype::DateTimeMicrosecond | Datatype::TimeMicrosecond => {
+                TimeUnit::Microsecond
+            }
+            Datatype::DateTimeNanosecond | Datatype::TimeNanosecond => {
+                TimeUnit::Nanosecond
+            }
+            Datatype::DateTimePicosecond | Datatype::TimePicosecond => {
+                TimeUnit::Picosecond
+            }
+            Datatype::DateTimeFemtosecond | Datatype::TimeFemtosecond => {
+                TimeUnit::Femtosecond
+            }
+            Datatype::DateTimeAttosecond | Datatype::TimeAttosecond => {
+                TimeUnit::Attosecond
+            }
+            _ => return None,
+        })
+    }
+
     /// Returns whether this type is a byte
     // Keep in sync with sm/enums/datatype.h:datatype_is_byte
     pub fn is_byte_type(&self) -> bool {
@@ -987,6 +1041,60 @@ mod tests {
         }
     }
 
+    #[test]
+    fn time_unit() {
+        let datetimes = [
+            (Datatype::DateTimeYear, TimeUnit::Year),
+            (Datatype::DateTimeMonth, TimeUnit::Month),
+            (Datatype::DateTimeWeek, TimeUnit::Week),
+            (Datatype::DateTimeDay, TimeUnit::Day),
+            (Datatype::DateTimeHour, TimeUnit::Hour),
+            (Datatype::DateTimeMinute, TimeUnit::Minute),
+            (Datatype::DateTimeSecond, TimeUnit::Second),
+            (Datatype::DateTimeMillisecond, TimeUnit::Millisecond),
+            (Datatype::DateTimeMicrosecond, TimeUnit::Microsecond),
+            (Datatype::DateTimeNanosecond, TimeUnit::Nanosecond),
+            (Datatype::DateTimePicosecond, TimeUnit::Picosecond),
+            (Datatype::DateTimeFemtosecond, TimeUnit::Femtosecond),
+            (Datatype::DateTimeAttosecond, TimeUnit::Attosecond),
+        ];
+        let times = [
+            (Datatype::TimeHour, TimeUnit::Hour),
+            (Datatype::TimeMinute, TimeUnit::Minute),
+            (Datatype::TimeSecond, TimeUnit::Second),
+            (Datatype::TimeMillisecond, TimeUnit::Millisecond),
+            (Datatype::TimeMicrosecond, TimeUnit::Microsecond),
+            (Datatype::TimeNanosecond, TimeUnit::Nanosecond),
+            (Datatype::TimePicosecond, TimeUnit::Picosecond),
+            (Datatype::TimeFemtosecond, TimeUnit::Femtosecond),
+            (Datatype::TimeAttosecond, TimeUnit::Attosecond),
+        ];
+
+        for (dt, unit) in datetimes.iter() {
+            assert!(dt.is_datetime_type());
+            assert!(!dt.is_time_type());
+            assert_eq!(Some(*unit), dt.time_unit());
+        }
+        for (dt, unit) in times.iter() {
+            assert!(!dt.is_datetime_type());
+            assert!(dt.is_time_type());
+            assert_eq!(Some(*unit), dt.time_unit());
+        }
+
+        let temporal = datetimes
+            .iter()
+            .chain(times.iter())
+            .map(|(dt, _)| *dt)
+            .collect::<HashSet<Datatype>>();
+        for dt in Datatype::iter() {
+            if !temporal.contains(&dt) {
+                assert!(!dt.is_datetime_type());
+                assert!(!dt.is_time_type());
+                assert_eq!(None, dt.time_unit());
+            }
+        }
+    }
+
     #[test]
     fn iter() {
         let mut yielded = HashSet::<Datatype>::new();
@@ -356,6 +356,7 @@ pub enum PhysicalValue {
     Float64(f64),
 }
 
+#[macro_export]
 macro_rules! physical_value_go {
     ($physical_value:expr, $DT:ident, $value:pat, $then:expr) => {{
         use $crate::datatype::physical::PhysicalValue;
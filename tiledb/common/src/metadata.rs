@@ -23,10 +23,15 @@ pub enum Value {
     Int64Value(Vec<i64>),
     Float32Value(Vec<f32>),
     Float64Value(Vec<f64>),
+    /// The value of a [Datatype::StringUtf8] metadata item.
+    StringUtf8(String),
 }
 
 /// Applies a generic expression to the interior of a `Value`.
 ///
+/// The interior of a [Value::StringUtf8] is its `String`, whose
+/// elements are the `u8` bytes of its UTF-8 encoding.
+///
 /// # Examples
 /// ```
 /// use tiledb_common::metadata::Value;
@@ -85,6 +90,10 @@ macro_rules! metadata_value_go {
                 type $typename = f64;
                 $then
             }
+            Value::StringUtf8($vec) => {
+                type $typename = u8;
+                $then
+            }
         }
     }};
 }
@@ -138,6 +147,10 @@ macro_rules! value_cmp {
                 type $typename = f64;
                 $same_type
             }
+            (Value::StringUtf8($lpat), Value::StringUtf8($rpat)) => {
+                type $typename = u8;
+                $same_type
+            }
             _ => $else,
         }
     }};
@@ -174,6 +187,12 @@ metadata_value_impl!(u64, Value::UInt64Value);
 metadata_value_impl!(f32, Value::Float32Value);
 metadata_value_impl!(f64, Value::Float64Value);
 
+impl From<String> for Value {
+    fn from(s: String) -> Self {
+        Value::StringUtf8(s)
+    }
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct Metadata {
     pub key: String,
@@ -196,12 +215,27 @@ impl Metadata {
                 datatype,
             ));
         }
+        let value = match Value::from(vec) {
+            Value::UInt8Value(bytes) if datatype == Datatype::StringUtf8 => {
+                Value::StringUtf8(String::from_utf8(bytes)?)
+            }
+            value => value,
+        };
         Ok(Metadata {
             key,
             datatype,
-            value: Value::from(vec),
+            value,
         })
     }
+
+    /// Returns a [Datatype::StringUtf8] metadata item with value `value`.
+    pub fn new_string(key: String, value: String) -> Self {
+        Metadata {
+            key,
+            datatype: Datatype::StringUtf8,
+            value: Value::StringUtf8(value),
+        }
+    }
 }
 
 #[cfg(any(test, feature = "proptest-strategies"))]
@@ -244,11 +278,19 @@ pub mod strategy {
             params
                 .datatype
                 .prop_flat_map(move |dt| {
-                    let value_strat = physical_type_go!(dt, DT, {
-                        vec(any::<DT>(), params.value_length.clone())
-                            .prop_map(Value::from)
+                    let value_strat = if dt == Datatype::StringUtf8 {
+                        vec(any::<char>(), params.value_length.clone())
+                            .prop_map(|chars| {
+                                Value::StringUtf8(chars.into_iter().collect())
+                            })
                             .boxed()
-                    });
+                    } else {
+                        physical_type_go!(dt, DT, {
+                            vec(any::<DT>(), params.value_length.clone())
+                                .prop_map(Value::from)
+                                .boxed()
+                        })
+                    };
                     (params.key.clone(), Just(dt), value_strat)
                 })
                 .prop_map(|(key, datatype, value)| Metadata {
@@ -267,7 +309,10 @@ mod tests {
     use proptest::prelude::*;
 
     fn do_value_cmp(m1: Metadata, m2: Metadata) {
-        if m1.datatype.same_physical_type(&m2.datatype) {
+        let is_utf8 = |m: &Metadata| m.datatype == Datatype::StringUtf8;
+        if m1.datatype.same_physical_type(&m2.datatype)
+            && is_utf8(&m1) == is_utf8(&m2)
+        {
             value_cmp!(&m1.value, &m2.value, _DT, _, _,
                 (),
                 unreachable!("Non-matching `Value` variants for same physical type: {:?} and {:?}",
@@ -280,6 +325,37 @@ mod tests {
         }
     }
 
+    #[test]
+    fn new_string_utf8() {
+        let m = Metadata::new(
+            "key".to_owned(),
+            Datatype::StringUtf8,
+            "héllo".as_bytes().to_vec(),
+        )
+        .unwrap();
+        assert_eq!(Value::StringUtf8("héllo".to_owned()), m.value);
+        assert_eq!(
+            Metadata::new_string("key".to_owned(), "héllo".to_owned()),
+            m
+        );
+
+        // other string types remain bytes
+        let m = Metadata::new(
+            "key".to_owned(),
+            Datatype::StringAscii,
+            "hello".as_bytes().to_vec(),
+        )
+        .unwrap();
+        assert_eq!(Value::UInt8Value("hello".as_bytes().to_vec()), m.value);
+
+        let invalid = Metadata::new(
+            "key".to_owned(),
+            Datatype::StringUtf8,
+            vec![0x66u8, 0xff, 0x6f],
+        );
+        assert!(matches!(invalid, Err(DatatypeError::InvalidUtf8(_))));
+    }
+
     proptest! {
         #[test]
         fn value_cmp((m1, m2) in (any::<Metadata>(), any::<Metadata>())) {
@@ -1,3 +1,4 @@
+use std::any::Any;
 use std::hash::{Hash, Hasher};
 use std::num::NonZeroU32;
 use std::ops::{Deref, RangeInclusive};
@@ -10,7 +11,8 @@ use serde::{Deserialize, Serialize};
 
 use crate::array::CellValNum;
 use crate::datatype::physical::{BitsEq, BitsHash, BitsOrd};
-use crate::datatype::{Datatype, Error as DatatypeError};
+use crate::datatype::PhysicalType;
+use crate::datatype::{Datatype, Error as DatatypeError, PhysicalValue};
 use crate::physical_type_go;
 
 pub type MinimumBoundingRectangle = Vec<TypedRange>;
@@ -29,8 +31,23 @@ pub enum DimensionCompatibilityError {
     FixedRangeForStringDimension,
     #[error("Dimension of type {0} cannot have {:?}", CellValNum::Var)]
     CellValNumVar(Datatype),
+    #[error("Range start is outside of the dimension domain: range = {range:?}, domain = {domain:?}")]
+    StartOutOfDomain {
+        range: SingleValueRange,
+        domain: SingleValueRange,
+    },
+    #[error("Range end is outside of the dimension domain: range = {range:?}, domain = {domain:?}")]
+    EndOutOfDomain {
+        range: SingleValueRange,
+        domain: SingleValueRange,
+    },
+    #[error("Range start is greater than range end: range = {range:?}, domain = {domain:?}")]
+    StartExceedsEnd {
+        range: SingleValueRange,
+        domain: SingleValueRange,
+    },
     #[error("Datatype error: {0}")]
-    Datatype(#[from] DatatypeError),
+    DatatypeMismatch(#[from] DatatypeError),
 }
 
 #[derive(Clone, Debug, Error)]
@@ -233,6 +250,37 @@ impl SingleValueRange {
             }
         )
     }
+
+    /// Returns whether `point` lies within this range.
+    ///
+    /// Returns `false` if `point` does not have the same physical datatype as `self`.
+    /// ```
+    /// use tiledb_common::datatype::PhysicalValue;
+    /// use tiledb_common::range::SingleValueRange;
+    /// let r = SingleValueRange::Int64(1, 100);
+    /// assert!(r.contains(&PhysicalValue::Int64(50)));
+    /// assert!(!r.contains(&PhysicalValue::Int64(101)));
+    /// assert!(!r.contains(&PhysicalValue::UInt64(50)));
+    /// ```
+    pub fn contains(&self, point: &PhysicalValue) -> bool {
+        let point = crate::physical_value_go!(
+            point,
+            _DT,
+            p,
+            SingleValueRange::from(&[*p, *p])
+        );
+        crate::single_value_range_cmp!(
+            self,
+            &point,
+            _DT,
+            start,
+            end,
+            p,
+            _,
+            start.bits_le(p) && p.bits_le(end),
+            false
+        )
+    }
 }
 
 impl PartialEq for SingleValueRange {
@@ -1119,6 +1167,38 @@ impl VarValueRange {
             },
             panic!("`VarValueRange::union` on non-matching datatypes: `self` = {:?}, `other` = {:?}", self, other))
     }
+
+    /// Returns whether `point` lies within this range.
+    ///
+    /// Returns `false` if `point` does not have the same physical datatype as `self`.
+    /// ```
+    /// use tiledb_common::range::VarValueRange;
+    /// let r = VarValueRange::from(("bar", "foo"));
+    /// assert!(r.contains("baz".as_bytes()));
+    /// assert!(!r.contains("qux".as_bytes()));
+    /// assert!(!r.contains(&[1i32, 2, 3]));
+    /// ```
+    pub fn contains<T>(&self, point: &[T]) -> bool
+    where
+        T: Clone,
+        VarValueRange: From<(Box<[T]>, Box<[T]>)>,
+    {
+        let point = VarValueRange::from((
+            point.to_vec().into_boxed_slice(),
+            point.to_vec().into_boxed_slice(),
+        ));
+        crate::var_value_range_cmp!(
+            self,
+            &point,
+            _DT,
+            ref start,
+            ref end,
+            ref p,
+            _,
+            start.bits_le(p) && p.bits_le(end),
+            false
+        )
+    }
 }
 
 impl PartialEq for VarValueRange {
@@ -1466,17 +1546,17 @@ impl Range {
                         Err(DimensionCompatibilityError::FixedRangeForStringDimension),
                     Range::Var(VarValueRange::UInt8(_, _)) => Ok(()),
                     Range::Single(s) => single_value_range_go!(s, DT, _, _,
-                        Err(DimensionCompatibilityError::Datatype(
+                        Err(DimensionCompatibilityError::DatatypeMismatch(
                                 DatatypeError::physical_type_incompatible::<DT>(datatype)))),
                     Range::Multi(m) => {
                         // NB: this is actually unreachable but this is what it would be if it were
                         multi_value_range_go!(m, DT, _, _,
-                            Err(DimensionCompatibilityError::Datatype(
+                            Err(DimensionCompatibilityError::DatatypeMismatch(
                                     DatatypeError::physical_type_incompatible::<DT>(datatype))))
                     },
                     Range::Var(v) => var_value_range_go!(v, DT, _, _,
                         Err(
-                            DimensionCompatibilityError::Datatype(
+                            DimensionCompatibilityError::DatatypeMismatch(
                                 DatatypeError::physical_type_incompatible::<DT>(
                                     datatype,
                                 ),
@@ -1490,6 +1570,71 @@ impl Range {
         Ok(())
     }
 
+    /// Checks that this range can be used as a range on a dimension
+    /// as if by [Self::check_dimension_compatibility], and additionally
+    /// that a fixed-size range lies within the dimension domain `domain`
+    /// and has a start which does not exceed its end.
+    ///
+    /// Var-sized dimensions have no domain, and so `domain` is `None`
+    /// and only the compatibility check applies.
+    pub fn check_dimension_domain(
+        &self,
+        datatype: Datatype,
+        cell_val_num: CellValNum,
+        domain: Option<&SingleValueRange>,
+    ) -> Result<(), DimensionCompatibilityError> {
+        self.check_dimension_compatibility(datatype, cell_val_num)?;
+
+        let (Self::Single(range), Some(domain)) = (self, domain) else {
+            return Ok(());
+        };
+
+        let error = crate::single_value_range_cmp!(
+            range,
+            domain,
+            _DT,
+            start,
+            end,
+            domain_start,
+            domain_end,
+            {
+                if start.bits_lt(domain_start) || start.bits_gt(domain_end) {
+                    Some(DimensionCompatibilityError::StartOutOfDomain {
+                        range: range.clone(),
+                        domain: domain.clone(),
+                    })
+                } else if end.bits_lt(domain_start) || end.bits_gt(domain_end) {
+                    Some(DimensionCompatibilityError::EndOutOfDomain {
+                        range: range.clone(),
+                        domain: domain.clone(),
+                    })
+                } else if start.bits_gt(end) {
+                    Some(DimensionCompatibilityError::StartExceedsEnd {
+                        range: range.clone(),
+                        domain: domain.clone(),
+                    })
+                } else {
+                    None
+                }
+            },
+            {
+                // `range` is compatible with `datatype` so the domain is not
+                crate::single_value_range_go!(
+                    domain,
+                    DT,
+                    _,
+                    _,
+                    Some(DimensionCompatibilityError::DatatypeMismatch(
+                        DatatypeError::physical_type_incompatible::<DT>(
+                            datatype
+                        )
+                    ))
+                )
+            }
+        );
+        error.map_or(Ok(()), Err)
+    }
+
     /// Returns the range covered by the union of `self` and `other`.
     ///
     /// # Panics
@@ -1520,6 +1665,113 @@ impl Range {
             _ => panic!("`Range::intersection` on non-matching range variants: `self` = {:?}, `other` = {:?}", self, other)
         }
     }
+
+    /// Returns the range covered by the intersection of `self` and `other`,
+    /// or `None` if `self` and `other` do not overlap.
+    ///
+    /// Unlike [Self::intersection], this also returns `None` if `self`
+    /// and `other` are not the same variant, do not have the same physical
+    /// datatype, or do not have the same `cell_val_num`.
+    /// ```
+    /// use tiledb_common::range::Range;
+    /// let domain = Range::from(&[0i32, 100]);
+    /// assert_eq!(
+    ///     Some(Range::from(&[50i32, 100])),
+    ///     domain.intersect(&Range::from(&[50i32, 200]))
+    /// );
+    /// assert_eq!(None, domain.intersect(&Range::from(&[200i32, 300])));
+    /// assert_eq!(None, domain.intersect(&Range::from(&[0u32, 100])));
+    /// ```
+    pub fn intersect(&self, other: &Self) -> Option<Self> {
+        let compatible = match (self, other) {
+            (Self::Single(ref l), Self::Single(ref r)) => {
+                crate::single_value_range_cmp!(
+                    l, r, _DT, _, _, _, _, true, false
+                )
+            }
+            (Self::Multi(ref l), Self::Multi(ref r)) => {
+                l.cell_val_num() == r.cell_val_num()
+                    && crate::multi_value_range_cmp!(
+                        l, r, _DT, _, _, _, _, true, false
+                    )
+            }
+            (Self::Var(ref l), Self::Var(ref r)) => {
+                crate::var_value_range_cmp!(l, r, _DT, _, _, _, _, true, false)
+            }
+            _ => false,
+        };
+        if compatible {
+            self.intersection(other)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the bounds of this range if it is a `Range::Single`
+    /// whose values have physical type `T`, and `None` otherwise.
+    ///
+    /// ```
+    /// use tiledb_common::range::{Range, SingleValueRange};
+    ///
+    /// let r = Range::Single(SingleValueRange::Int32(1, 4));
+    /// assert_eq!(Some((1, 4)), r.as_typed::<i32>());
+    /// assert_eq!(None, r.as_typed::<u32>());
+    /// ```
+    pub fn as_typed<T>(&self) -> Option<(T, T)>
+    where
+        T: PhysicalType,
+    {
+        let Self::Single(ref r) = self else {
+            return None;
+        };
+        crate::single_value_range_go!(r, _DT, start, end, {
+            let start = (start as &dyn Any).downcast_ref::<T>()?;
+            let end = (end as &dyn Any).downcast_ref::<T>()?;
+            Some((*start, *end))
+        })
+    }
+
+    /// Returns the bounds of this range if it is a `Range::Var`
+    /// whose values have physical type `T`, and `None` otherwise.
+    ///
+    /// ```
+    /// use tiledb_common::range::Range;
+    ///
+    /// let r = Range::from(("bar", "foo"));
+    /// let (start, end) = r.as_var_typed::<u8>().unwrap();
+    /// assert_eq!(b"bar", start.as_ref());
+    /// assert_eq!(b"foo", end.as_ref());
+    /// assert_eq!(None, r.as_var_typed::<i8>());
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn as_var_typed<T>(&self) -> Option<(Box<[T]>, Box<[T]>)>
+    where
+        T: PhysicalType,
+    {
+        let Self::Var(ref r) = self else {
+            return None;
+        };
+        crate::var_value_range_go!(r, _DT, start, end, {
+            let start = (start as &dyn Any).downcast_ref::<Box<[T]>>()?;
+            let end = (end as &dyn Any).downcast_ref::<Box<[T]>>()?;
+            Some((start.clone(), end.clone()))
+        })
+    }
+
+    /// Returns whether `point` lies within this range.
+    ///
+    /// For `Range::Var`, `point` is treated as a variable-length value with one element.
+    /// Returns `false` if `point` does not have the same physical datatype as `self`,
+    /// or if `self` is a `Range::Multi`.
+    pub fn contains(&self, point: &PhysicalValue) -> bool {
+        match self {
+            Self::Single(ref r) => r.contains(point),
+            Self::Multi(_) => false,
+            Self::Var(ref r) => {
+                crate::physical_value_go!(point, _DT, p, r.contains(&[*p]))
+            }
+        }
+    }
 }
 
 macro_rules! range_from_impl {
@@ -1957,6 +2209,58 @@ mod tests {
         do_test_range_inclusive_try_from!(Float64, f64);
     }
 
+    #[test]
+    fn range_as_typed() {
+        macro_rules! do_range_as_typed {
+            ($Variant:ident, $DT:ty, $Other:ty) => {{
+                let lower = 0 as $DT;
+                let upper = 1 as $DT;
+
+                let single =
+                    Range::Single(SingleValueRange::$Variant(lower, upper));
+                assert_eq!(Some((lower, upper)), single.as_typed::<$DT>());
+                assert_eq!(None, single.as_typed::<$Other>());
+                assert_eq!(None, single.as_var_typed::<$DT>());
+
+                let var = Range::Var(VarValueRange::$Variant(
+                    vec![lower].into_boxed_slice(),
+                    vec![upper, upper].into_boxed_slice(),
+                ));
+                assert_eq!(
+                    Some((
+                        vec![lower].into_boxed_slice(),
+                        vec![upper, upper].into_boxed_slice()
+                    )),
+                    var.as_var_typed::<$DT>()
+                );
+                assert_eq!(None, var.as_var_typed::<$Other>());
+                assert_eq!(None, var.as_typed::<$DT>());
+
+                let multi = Range::Multi(
+                    MultiValueRange::try_from((
+                        CellValNum::try_from(2).unwrap(),
+                        vec![lower, lower].into_boxed_slice(),
+                        vec![upper, upper].into_boxed_slice(),
+                    ))
+                    .unwrap(),
+                );
+                assert_eq!(None, multi.as_typed::<$DT>());
+                assert_eq!(None, multi.as_var_typed::<$DT>());
+            }};
+        }
+
+        do_range_as_typed!(UInt8, u8, i8);
+        do_range_as_typed!(UInt16, u16, i16);
+        do_range_as_typed!(UInt32, u32, f32);
+        do_range_as_typed!(UInt64, u64, i64);
+        do_range_as_typed!(Int8, i8, u8);
+        do_range_as_typed!(Int16, i16, u16);
+        do_range_as_typed!(Int32, i32, u32);
+        do_range_as_typed!(Int64, i64, f64);
+        do_range_as_typed!(Float32, f32, u32);
+        do_range_as_typed!(Float64, f64, u64);
+    }
+
     fn do_inclusive_range_conversion<T>(range_in: RangeInclusive<T>)
     where
         T: Clone + Debug + FromPrimitive + PartialEq,
@@ -2280,11 +2584,66 @@ mod tests {
         let _ = format!("{:?}", range);
     }
 
+    #[test]
+    fn dimension_domain() {
+        let domain = SingleValueRange::Int32(1, 10);
+        let check = |range: SingleValueRange| {
+            Range::Single(range).check_dimension_domain(
+                Datatype::Int32,
+                CellValNum::single(),
+                Some(&domain),
+            )
+        };
+
+        assert_eq!(Ok(()), check(SingleValueRange::Int32(1, 10)));
+        assert_eq!(Ok(()), check(SingleValueRange::Int32(5, 5)));
+        assert_eq!(
+            Err(DimensionCompatibilityError::StartOutOfDomain {
+                range: SingleValueRange::Int32(0, 5),
+                domain: domain.clone()
+            }),
+            check(SingleValueRange::Int32(0, 5))
+        );
+        assert_eq!(
+            Err(DimensionCompatibilityError::EndOutOfDomain {
+                range: SingleValueRange::Int32(5, 11),
+                domain: domain.clone()
+            }),
+            check(SingleValueRange::Int32(5, 11))
+        );
+        assert_eq!(
+            Err(DimensionCompatibilityError::StartExceedsEnd {
+                range: SingleValueRange::Int32(6, 5),
+                domain: domain.clone()
+            }),
+            check(SingleValueRange::Int32(6, 5))
+        );
+        assert_eq!(
+            Err(DimensionCompatibilityError::DatatypeMismatch(
+                DatatypeError::PhysicalTypeIncompatible {
+                    physical_type: "i64",
+                    logical_type: Datatype::Int32
+                }
+            )),
+            check(SingleValueRange::Int64(1, 10))
+        );
+
+        // var-sized dimensions have no domain
+        assert_eq!(
+            Ok(()),
+            Range::from(("b", "a")).check_dimension_domain(
+                Datatype::StringAscii,
+                CellValNum::Var,
+                None
+            )
+        );
+    }
+
     #[test]
     fn dimension_compatibility_string_ascii_var() {
         // single
         assert_eq!(
-            Err(DimensionCompatibilityError::Datatype(
+            Err(DimensionCompatibilityError::DatatypeMismatch(
                 DatatypeError::PhysicalTypeIncompatible {
                     physical_type: "u16",
                     logical_type: Datatype::StringAscii
@@ -2337,7 +2696,7 @@ mod tests {
 
         // var but not u8
         assert_eq!(
-            Err(DimensionCompatibilityError::Datatype(
+            Err(DimensionCompatibilityError::DatatypeMismatch(
                 DatatypeError::PhysicalTypeIncompatible {
                     physical_type: "u16",
                     logical_type: Datatype::StringAscii
@@ -2657,4 +3016,78 @@ mod tests {
             do_intersection_var(left, right)
         }
     }
+
+    fn do_contains_single(range: SingleValueRange) {
+        let (start, end) = single_value_range_go!(
+            range,
+            _DT,
+            start,
+            end,
+            (PhysicalValue::from(start), PhysicalValue::from(end))
+        );
+        assert!(range.contains(&start));
+        assert!(range.contains(&end));
+
+        let r = Range::Single(range.clone());
+        assert!(r.contains(&start));
+        assert!(r.contains(&end));
+
+        // a point of a different physical type is never contained
+        let other = if matches!(start, PhysicalValue::UInt8(_)) {
+            PhysicalValue::Int8(0)
+        } else {
+            PhysicalValue::UInt8(0)
+        };
+        assert!(!range.contains(&other));
+    }
+
+    fn do_contains_var(range: VarValueRange) {
+        var_value_range_go!(range, _DT, ref start, ref end, {
+            assert!(range.contains(start));
+            assert!(range.contains(end));
+        });
+    }
+
+    proptest! {
+        #[test]
+        fn contains_single(range in any::<SingleValueRange>()) {
+            do_contains_single(range)
+        }
+
+        #[test]
+        fn contains_var(range in any::<VarValueRange>()) {
+            do_contains_var(range)
+        }
+
+        #[test]
+        fn intersect_single((left, right) in strat_intersection_single()) {
+            let (left, right) = (Range::Single(left), Range::Single(right));
+            assert_eq!(left.intersection(&right), left.intersect(&right));
+        }
+
+        #[test]
+        fn intersect_var((left, right) in strat_intersection_var()) {
+            let (left, right) = (Range::Var(left), Range::Var(right));
+            assert_eq!(left.intersection(&right), left.intersect(&right));
+        }
+
+        #[test]
+        fn intersect_mismatch(left in any::<SingleValueRange>(), right in any::<SingleValueRange>()) {
+            let same_type = single_value_range_cmp!(
+                &left, &right, _DT, _, _, _, _, true, false
+            );
+            prop_assume!(!same_type);
+
+            let (left, right) = (Range::Single(left), Range::Single(right));
+            assert_eq!(None, left.intersect(&right));
+            assert_eq!(None, right.intersect(&left));
+        }
+
+        #[test]
+        fn intersect_variant_mismatch(left in any::<SingleValueRange>(), right in any::<VarValueRange>()) {
+            let (left, right) = (Range::Single(left), Range::Var(right));
+            assert_eq!(None, left.intersect(&right));
+            assert_eq!(None, right.intersect(&left));
+        }
+    }
 }
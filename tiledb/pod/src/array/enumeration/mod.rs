@@ -7,8 +7,29 @@ use tiledb_utils::option::OptionSubset;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use thiserror::Error;
 use tiledb_common::array::CellValNum;
-use tiledb_common::datatype::Datatype;
+use tiledb_common::datatype::{Datatype, Error as DatatypeError, PhysicalType};
+
+#[derive(Debug, Error)]
+pub enum ExtendError {
+    #[error("Invalid variant datatype: {0}")]
+    Datatype(#[from] DatatypeError),
+    #[error("Expected offsets for variants of variable-length enumeration")]
+    ExpectedOffsets,
+    #[error("Unexpected offsets for variants of fixed-length enumeration")]
+    UnexpectedOffsets,
+    #[error(
+        "Expected variants of {expected} bytes each but found {found} bytes"
+    )]
+    FixedLengthTruncation { expected: usize, found: usize },
+    #[error("Invalid offset {offset} for variants of {nbytes} bytes")]
+    InvalidOffset { offset: u64, nbytes: usize },
+    #[error("Expected first offset 0 but found {0}")]
+    NonZeroFirstOffset(u64),
+    #[error("Expected offsets for variants of {nbytes} bytes")]
+    EmptyOffsets { nbytes: usize },
+}
 
 /// Encapsulation of data needed to construct an Enumeration
 #[derive(Clone, Debug, PartialEq)]
@@ -64,6 +85,131 @@ impl EnumerationData {
                 .collect::<Vec<Vec<u8>>>()
         }
     }
+
+    /// Returns the raw bytes of the variant at `index`,
+    /// or `None` if `index` is out of bounds.
+    ///
+    /// `tiledb_api::array::Enumeration::variant_at` returns the variant
+    /// as a query condition literal instead.
+    pub fn variant_at(&self, index: u64) -> Option<&[u8]> {
+        let index = usize::try_from(index).ok()?;
+        if let Some(offsets) = self.offsets.as_ref() {
+            let start = *offsets.get(index)? as usize;
+            let end = offsets
+                .get(index + 1)
+                .map(|o| *o as usize)
+                .unwrap_or(self.data.len());
+            self.data.get(start..end)
+        } else {
+            let fixed = self.datatype.size()
+                * u32::from(self.cell_val_num.unwrap_or(CellValNum::single()))
+                    as usize;
+            let start = index.checked_mul(fixed)?;
+            self.data.get(start..start.checked_add(fixed)?)
+        }
+    }
+
+    /// Returns the index of the variant whose raw bytes are `value`,
+    /// or `None` if no variant matches.
+    ///
+    /// This is the key which a cell of an attribute using this enumeration
+    /// would contain to represent `value`.
+    pub fn index_of(&self, value: &[u8]) -> Option<u64> {
+        (0..self.num_variants())
+            .find(|i| self.variant_at(*i as u64) == Some(value))
+            .map(|i| i as u64)
+    }
+
+    /// Appends new variants to this enumeration.
+    ///
+    /// For a variable-length enumeration, `offsets` contains the byte offset of each new
+    /// variant into `data`, and is rebased to follow the existing variants.
+    /// For a fixed-length enumeration, `offsets` must be `None`.
+    ///
+    /// Returns an error if `T` is not compatible with [Self::datatype] or if the
+    /// new variants do not match the structure of the existing variants.
+    /// The `offsets` of variable-length variants must start at zero and be
+    /// non-decreasing and within `data`, and must not be empty unless `data` is.
+    /// In this case `self` is unchanged.
+    pub fn extend<T>(
+        &mut self,
+        data: &[T],
+        offsets: Option<&[u64]>,
+    ) -> Result<(), ExtendError>
+    where
+        T: PhysicalType,
+    {
+        if !self.datatype.is_compatible_type::<T>() {
+            return Err(DatatypeError::physical_type_incompatible::<T>(
+                self.datatype,
+            )
+            .into());
+        }
+
+        let nbytes = std::mem::size_of_val(data);
+        let bytes = unsafe {
+            std::slice::from_raw_parts(data.as_ptr() as *const u8, nbytes)
+        };
+
+        let offsets = match (self.offsets.as_ref(), offsets) {
+            (Some(_), None) => return Err(ExtendError::ExpectedOffsets),
+            (None, Some(_)) => return Err(ExtendError::UnexpectedOffsets),
+            (None, None) => {
+                let fixed = self.datatype.size()
+                    * u32::from(
+                        self.cell_val_num.unwrap_or(CellValNum::single()),
+                    ) as usize;
+                if nbytes % fixed != 0 {
+                    return Err(ExtendError::FixedLengthTruncation {
+                        expected: fixed,
+                        found: nbytes,
+                    });
+                }
+                None
+            }
+            (Some(existing), Some(offsets)) => {
+                match offsets.first() {
+                    None if nbytes > 0 => {
+                        return Err(ExtendError::EmptyOffsets { nbytes })
+                    }
+                    Some(first) if *first != 0 => {
+                        return Err(ExtendError::NonZeroFirstOffset(*first))
+                    }
+                    _ => (),
+                }
+                let bounds = offsets
+                    .iter()
+                    .copied()
+                    .chain(std::iter::once(nbytes as u64))
+                    .collect::<Vec<u64>>();
+                if let Some(w) = bounds.windows(2).find(|w| w[0] > w[1]) {
+                    return Err(ExtendError::InvalidOffset {
+                        offset: w[0],
+                        nbytes,
+                    });
+                }
+                let base = self.data.len() as u64;
+                Some(
+                    existing
+                        .iter()
+                        .copied()
+                        .chain(offsets.iter().map(|o| base + *o))
+                        .collect::<Vec<u64>>()
+                        .into_boxed_slice(),
+                )
+            }
+        };
+
+        self.data = self
+            .data
+            .iter()
+            .chain(bytes.iter())
+            .copied()
+            .collect::<Vec<u8>>()
+            .into_boxed_slice();
+        self.offsets = offsets;
+        Ok(())
+    }
 }
 
 /// Returns a (raw bytes, offsets) pair representing the input set of records
@@ -143,6 +289,149 @@ mod tests {
         }
     }
 
+    #[test]
+    fn extend_fixed() {
+        let mut e = EnumerationData {
+            name: "e".to_owned(),
+            datatype: Datatype::Int32,
+            cell_val_num: None,
+            ordered: None,
+            data: [1i32, 2, 3]
+                .iter()
+                .flat_map(|v| v.to_le_bytes())
+                .collect::<Vec<u8>>()
+                .into_boxed_slice(),
+            offsets: None,
+        };
+
+        e.extend(&[4i32, 5], None).unwrap();
+        assert_eq!(5, e.num_variants());
+        assert_eq!(5i32.to_le_bytes().to_vec(), e.records()[4]);
+
+        assert!(matches!(
+            e.extend(&[6u32], None),
+            Err(ExtendError::Datatype(_))
+        ));
+        assert!(matches!(
+            e.extend(&[6i32], Some(&[0])),
+            Err(ExtendError::UnexpectedOffsets)
+        ));
+        assert_eq!(5, e.num_variants());
+
+        let mut pairs = EnumerationData {
+            cell_val_num: Some(CellValNum::try_from(2).unwrap()),
+            ..e.clone()
+        };
+        assert!(matches!(
+            pairs.extend(&[6i32], None),
+            Err(ExtendError::FixedLengthTruncation {
+                expected: 8,
+                found: 4
+            })
+        ));
+    }
+
+    #[test]
+    fn extend_var() {
+        let mut e = EnumerationData {
+            name: "flintstones".to_owned(),
+            datatype: Datatype::StringUtf8,
+            cell_val_num: Some(CellValNum::Var),
+            ordered: None,
+            data: "fredwilmageorgebetty".as_bytes().into(),
+            offsets: Some(vec![0u64, 4, 9, 15].into_boxed_slice()),
+        };
+
+        e.extend("barneypebbles".as_bytes(), Some(&[0, 6])).unwrap();
+        assert_eq!(6, e.num_variants());
+        assert_eq!(Some(&[0u64, 4, 9, 15, 20, 26][..]), e.offsets.as_deref());
+        assert_eq!(
+            vec!["fred", "wilma", "george", "betty", "barney", "pebbles"],
+            e.records()
+                .into_iter()
+                .map(|r| String::from_utf8(r).unwrap())
+                .collect::<Vec<_>>()
+        );
+
+        assert!(matches!(
+            e.extend("dino".as_bytes(), None),
+            Err(ExtendError::ExpectedOffsets)
+        ));
+        assert!(matches!(
+            e.extend("dino".as_bytes(), Some(&[0, 5])),
+            Err(ExtendError::InvalidOffset {
+                offset: 5,
+                nbytes: 4
+            })
+        ));
+        assert!(matches!(
+            e.extend("dinohoppy".as_bytes(), Some(&[0, 4, 2])),
+            Err(ExtendError::InvalidOffset {
+                offset: 4,
+                nbytes: 9
+            })
+        ));
+        assert!(matches!(
+            e.extend("dinohoppy".as_bytes(), Some(&[1, 4])),
+            Err(ExtendError::NonZeroFirstOffset(1))
+        ));
+        assert!(matches!(
+            e.extend("dino".as_bytes(), Some(&[])),
+            Err(ExtendError::EmptyOffsets { nbytes: 4 })
+        ));
+        assert_eq!(6, e.num_variants());
+
+        e.extend("".as_bytes(), Some(&[])).unwrap();
+        assert_eq!(6, e.num_variants());
+    }
+
+    fn do_variant_lookup(enumeration: EnumerationData) {
+        let records = enumeration.records();
+        for (i, record) in records.iter().enumerate() {
+            assert_eq!(
+                Some(record.as_slice()),
+                enumeration.variant_at(i as u64)
+            );
+            assert_eq!(Some(i as u64), enumeration.index_of(record));
+        }
+        assert_eq!(None, enumeration.variant_at(records.len() as u64));
+    }
+
+    #[test]
+    fn variant_lookup() {
+        let e = EnumerationData {
+            name: "e".to_owned(),
+            datatype: Datatype::Int32,
+            cell_val_num: None,
+            ordered: None,
+            data: [1i32, 2, 3]
+                .iter()
+                .flat_map(|v| v.to_le_bytes())
+                .collect::<Vec<u8>>()
+                .into_boxed_slice(),
+            offsets: None,
+        };
+        assert_eq!(Some(&2i32.to_le_bytes()[..]), e.variant_at(1));
+        assert_eq!(None, e.variant_at(3));
+        assert_eq!(None, e.variant_at(u64::MAX));
+        assert_eq!(Some(2), e.index_of(&3i32.to_le_bytes()));
+        assert_eq!(None, e.index_of(&4i32.to_le_bytes()));
+
+        let e = EnumerationData {
+            name: "flintstones".to_owned(),
+            datatype: Datatype::StringUtf8,
+            cell_val_num: Some(CellValNum::Var),
+            ordered: None,
+            data: "fredwilmageorgebetty".as_bytes().into(),
+            offsets: Some(vec![0u64, 4, 9, 15].into_boxed_slice()),
+        };
+        assert_eq!(Some("wilma".as_bytes()), e.variant_at(1));
+        assert_eq!(Some("betty".as_bytes()), e.variant_at(3));
+        assert_eq!(None, e.variant_at(4));
+        assert_eq!(Some(2), e.index_of("george".as_bytes()));
+        assert_eq!(None, e.index_of("geo".as_bytes()));
+    }
+
     // NB: do not use Arbitrary because that *depends* on the roundtrip test
     fn strat_enumeration() -> impl Strategy<Value = EnumerationData> {
         (any::<Datatype>(), any::<CellValNum>()).prop_flat_map(
@@ -166,5 +455,10 @@ mod tests {
         fn variants_records_integrity(enumeration in strat_enumeration()) {
             do_variants_records_integrity(enumeration)
         }
+
+        #[test]
+        fn variant_lookup_records(enumeration in strat_enumeration()) {
+            do_variant_lookup(enumeration)
+        }
     }
 }
@@ -0,0 +1,425 @@
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+use tiledb_common::datatype::Datatype;
+
+use crate::array::schema::SchemaData;
+use crate::array::{AttributeData, EnumerationData};
+
+/// Identifies one of the filter pipelines of a schema.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum FilterPipeline {
+    Coordinates,
+    Offsets,
+    Nullity,
+    Attribute(String),
+}
+
+impl Display for FilterPipeline {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        match self {
+            Self::Coordinates => write!(f, "coordinate filters"),
+            Self::Offsets => write!(f, "offsets filters"),
+            Self::Nullity => write!(f, "nullity filters"),
+            Self::Attribute(name) => {
+                write!(f, "filters of attribute '{}'", name)
+            }
+        }
+    }
+}
+
+/// The differences between two [SchemaData], as computed by [SchemaData::diff].
+///
+/// The domain, array type, tile capacity, and cell and tile orders
+/// are not compared.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SchemaDiff {
+    /// Attributes which are only present in the new schema.
+    pub added_attributes: Vec<AttributeData>,
+    /// Names of attributes which are only present in the old schema.
+    pub dropped_attributes: Vec<String>,
+    /// Attributes which are present in both schemas with different
+    /// definitions, not including their filters, as `(old, new)`.
+    pub changed_attributes: Vec<(AttributeData, AttributeData)>,
+    /// Enumerations which are only present in the new schema.
+    pub added_enumerations: Vec<EnumerationData>,
+    /// Names of enumerations which are only present in the old schema.
+    pub dropped_enumerations: Vec<String>,
+    /// Enumerations which are present in both schemas
+    /// with different definitions, as `(old, new)`.
+    pub changed_enumerations: Vec<(EnumerationData, EnumerationData)>,
+    /// Filter pipelines which differ between the schemas,
+    /// including those of attributes present in both schemas.
+    pub changed_filters: Vec<FilterPipeline>,
+}
+
+/// A single step of evolving a schema.
+#[derive(Clone, Debug, PartialEq)]
+pub enum SchemaEvolutionOp {
+    AddAttribute(AttributeData),
+    DropAttribute(String),
+    AddEnumeration(EnumerationData),
+    DropEnumeration(String),
+}
+
+/// A schema change which cannot be made using schema evolution.
+#[derive(Clone, Debug, PartialEq, thiserror::Error)]
+pub enum UnsupportedSchemaChange {
+    #[error(
+        "Cannot change datatype of attribute '{name}' from {old} to {new}"
+    )]
+    AttributeDatatype {
+        name: String,
+        old: Datatype,
+        new: Datatype,
+    },
+    #[error("Cannot change definition of attribute '{0}'")]
+    Attribute(String),
+    #[error("Cannot change definition of enumeration '{0}'")]
+    Enumeration(String),
+    #[error("Cannot change {0}")]
+    Filters(FilterPipeline),
+}
+
+impl SchemaDiff {
+    /// Returns whether there are no differences.
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Returns the sequence of schema evolution operations which
+    /// applies this diff, or the first change which cannot be applied.
+    ///
+    /// Attributes and enumerations are dropped before new ones are added,
+    /// so that a name which is dropped and added can be re-used,
+    /// and new enumerations are added before the attributes which use them.
+    pub fn evolution(
+        &self,
+    ) -> Result<Vec<SchemaEvolutionOp>, UnsupportedSchemaChange> {
+        if let Some((old, new)) = self.changed_attributes.first() {
+            return Err(if old.datatype != new.datatype {
+                UnsupportedSchemaChange::AttributeDatatype {
+                    name: old.name.clone(),
+                    old: old.datatype,
+                    new: new.datatype,
+                }
+            } else {
+                UnsupportedSchemaChange::Attribute(old.name.clone())
+            });
+        }
+        if let Some((old, _)) = self.changed_enumerations.first() {
+            return Err(UnsupportedSchemaChange::Enumeration(old.name.clone()));
+        }
+        if let Some(pipeline) = self.changed_filters.first() {
+            return Err(UnsupportedSchemaChange::Filters(pipeline.clone()));
+        }
+
+        let drop_attributes = self
+            .dropped_attributes
+            .iter()
+            .cloned()
+            .map(SchemaEvolutionOp::DropAttribute);
+        let drop_enumerations = self
+            .dropped_enumerations
+            .iter()
+            .cloned()
+            .map(SchemaEvolutionOp::DropEnumeration);
+        let add_enumerations = self
+            .added_enumerations
+            .iter()
+            .cloned()
+            .map(SchemaEvolutionOp::AddEnumeration);
+        let add_attributes = self
+            .added_attributes
+            .iter()
+            .cloned()
+            .map(SchemaEvolutionOp::AddAttribute);
+
+        Ok(drop_attributes
+            .chain(drop_enumerations)
+            .chain(add_enumerations)
+            .chain(add_attributes)
+            .collect())
+    }
+}
+
+/// Returns whether `new` specifies a value which is not `old`.
+fn option_differs<T: PartialEq>(old: &Option<T>, new: &Option<T>) -> bool {
+    new.is_some() && old != new
+}
+
+fn attribute_differs(old: &AttributeData, new: &AttributeData) -> bool {
+    old.datatype != new.datatype
+        || option_differs(&old.nullability, &new.nullability)
+        || option_differs(&old.cell_val_num, &new.cell_val_num)
+        || option_differs(&old.fill, &new.fill)
+        || old.enumeration != new.enumeration
+}
+
+fn enumeration_differs(old: &EnumerationData, new: &EnumerationData) -> bool {
+    old.datatype != new.datatype
+        || option_differs(&old.cell_val_num, &new.cell_val_num)
+        || option_differs(&old.ordered, &new.ordered)
+        || old.data != new.data
+        || old.offsets != new.offsets
+}
+
+impl SchemaData {
+    /// Returns the changes needed to turn `self` into `other`.
+    ///
+    /// Optional properties of attributes and enumerations which are
+    /// `None` in `other` are treated as unspecified and are not compared.
+    pub fn diff(&self, other: &SchemaData) -> SchemaDiff {
+        let mut diff = SchemaDiff::default();
+
+        for (pipeline, old, new) in [
+            (
+                FilterPipeline::Coordinates,
+                &self.coordinate_filters,
+                &other.coordinate_filters,
+            ),
+            (
+                FilterPipeline::Offsets,
+                &self.offsets_filters,
+                &other.offsets_filters,
+            ),
+            (
+                FilterPipeline::Nullity,
+                &self.nullity_filters,
+                &other.nullity_filters,
+            ),
+        ] {
+            if old != new {
+                diff.changed_filters.push(pipeline);
+            }
+        }
+
+        for old in self.attributes.iter() {
+            if let Some(new) =
+                other.attributes.iter().find(|a| a.name == old.name)
+            {
+                if attribute_differs(old, new) {
+                    diff.changed_attributes.push((old.clone(), new.clone()));
+                }
+                if old.filters != new.filters {
+                    diff.changed_filters
+                        .push(FilterPipeline::Attribute(old.name.clone()));
+                }
+            } else {
+                diff.dropped_attributes.push(old.name.clone());
+            }
+        }
+        diff.added_attributes = other
+            .attributes
+            .iter()
+            .filter(|new| !self.attributes.iter().any(|a| a.name == new.name))
+            .cloned()
+            .collect();
+
+        for old in self.enumerations.iter() {
+            if let Some(new) =
+                other.enumerations.iter().find(|e| e.name == old.name)
+            {
+                if enumeration_differs(old, new) {
+                    diff.changed_enumerations.push((old.clone(), new.clone()));
+                }
+            } else {
+                diff.dropped_enumerations.push(old.name.clone());
+            }
+        }
+        diff.added_enumerations = other
+            .enumerations
+            .iter()
+            .filter(|new| !self.enumerations.iter().any(|e| e.name == new.name))
+            .cloned()
+            .collect();
+
+        diff
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tiledb_common::array::{ArrayType, CellValNum};
+    use tiledb_common::filter::{CompressionData, CompressionType, FilterData};
+
+    use super::*;
+    use crate::array::DomainData;
+
+    fn attribute(name: &str, datatype: Datatype) -> AttributeData {
+        AttributeData {
+            name: name.to_owned(),
+            datatype,
+            nullability: None,
+            cell_val_num: None,
+            fill: None,
+            filters: vec![],
+            enumeration: None,
+        }
+    }
+
+    fn enumeration(name: &str, variants: &[u8]) -> EnumerationData {
+        EnumerationData {
+            name: name.to_owned(),
+            datatype: Datatype::UInt8,
+            cell_val_num: Some(CellValNum::single()),
+            ordered: None,
+            data: variants.to_vec().into_boxed_slice(),
+            offsets: None,
+        }
+    }
+
+    fn schema(
+        attributes: Vec<AttributeData>,
+        enumerations: Vec<EnumerationData>,
+    ) -> SchemaData {
+        SchemaData {
+            array_type: ArrayType::Sparse,
+            domain: DomainData { dimension: vec![] },
+            attributes,
+            enumerations,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn diff_identical() {
+        let s = schema(
+            vec![attribute("a", Datatype::Int32)],
+            vec![enumeration("e", &[1, 2, 3])],
+        );
+        let diff = s.diff(&s);
+        assert!(diff.is_empty());
+        assert_eq!(Ok(vec![]), diff.evolution());
+    }
+
+    #[test]
+    fn diff_add_drop() {
+        let old = schema(
+            vec![
+                attribute("a", Datatype::Int32),
+                attribute("b", Datatype::Float64),
+            ],
+            vec![enumeration("e1", &[1, 2, 3])],
+        );
+        let new = schema(
+            vec![
+                attribute("a", Datatype::Int32),
+                AttributeData {
+                    enumeration: Some("e2".to_owned()),
+                    ..attribute("c", Datatype::UInt8)
+                },
+            ],
+            vec![enumeration("e2", &[4, 5])],
+        );
+
+        let diff = old.diff(&new);
+        assert_eq!(vec!["b".to_owned()], diff.dropped_attributes);
+        assert_eq!(vec![new.attributes[1].clone()], diff.added_attributes);
+        assert_eq!(vec!["e1".to_owned()], diff.dropped_enumerations);
+        assert_eq!(vec![new.enumerations[0].clone()], diff.added_enumerations);
+        assert!(diff.changed_attributes.is_empty());
+        assert!(diff.changed_enumerations.is_empty());
+        assert!(diff.changed_filters.is_empty());
+
+        assert_eq!(
+            Ok(vec![
+                SchemaEvolutionOp::DropAttribute("b".to_owned()),
+                SchemaEvolutionOp::DropEnumeration("e1".to_owned()),
+                SchemaEvolutionOp::AddEnumeration(new.enumerations[0].clone()),
+                SchemaEvolutionOp::AddAttribute(new.attributes[1].clone()),
+            ]),
+            diff.evolution()
+        );
+    }
+
+    #[test]
+    fn diff_unspecified_properties() {
+        let old = schema(
+            vec![AttributeData {
+                nullability: Some(true),
+                cell_val_num: Some(CellValNum::single()),
+                ..attribute("a", Datatype::Int32)
+            }],
+            vec![],
+        );
+        let new = schema(vec![attribute("a", Datatype::Int32)], vec![]);
+        assert!(old.diff(&new).is_empty());
+
+        // but the reverse specifies properties which were unspecified
+        assert_eq!(
+            vec![(new.attributes[0].clone(), old.attributes[0].clone())],
+            new.diff(&old).changed_attributes
+        );
+    }
+
+    #[test]
+    fn diff_changed_datatype() {
+        let old = schema(vec![attribute("a", Datatype::Int32)], vec![]);
+        let new = schema(vec![attribute("a", Datatype::Int64)], vec![]);
+
+        let diff = old.diff(&new);
+        assert!(diff.added_attributes.is_empty());
+        assert!(diff.dropped_attributes.is_empty());
+        assert_eq!(
+            vec![(old.attributes[0].clone(), new.attributes[0].clone())],
+            diff.changed_attributes
+        );
+        assert_eq!(
+            Err(UnsupportedSchemaChange::AttributeDatatype {
+                name: "a".to_owned(),
+                old: Datatype::Int32,
+                new: Datatype::Int64
+            }),
+            diff.evolution()
+        );
+    }
+
+    #[test]
+    fn diff_changed_filters() {
+        let zstd = FilterData::Compression(CompressionData::new(
+            CompressionType::Zstd,
+        ));
+
+        let old = schema(vec![attribute("a", Datatype::Int32)], vec![]);
+        let new = SchemaData {
+            offsets_filters: vec![zstd.clone()],
+            ..schema(
+                vec![AttributeData {
+                    filters: vec![zstd],
+                    ..attribute("a", Datatype::Int32)
+                }],
+                vec![],
+            )
+        };
+
+        let diff = old.diff(&new);
+        assert!(diff.changed_attributes.is_empty());
+        assert_eq!(
+            vec![
+                FilterPipeline::Offsets,
+                FilterPipeline::Attribute("a".to_owned())
+            ],
+            diff.changed_filters
+        );
+        assert_eq!(
+            Err(UnsupportedSchemaChange::Filters(FilterPipeline::Offsets)),
+            diff.evolution()
+        );
+    }
+
+    #[test]
+    fn diff_changed_enumeration() {
+        let old = schema(vec![], vec![enumeration("e", &[1, 2, 3])]);
+        let new = schema(vec![], vec![enumeration("e", &[1, 2, 3, 4])]);
+
+        let diff = old.diff(&new);
+        assert_eq!(
+            vec![(old.enumerations[0].clone(), new.enumerations[0].clone())],
+            diff.changed_enumerations
+        );
+        assert_eq!(
+            Err(UnsupportedSchemaChange::Enumeration("e".to_owned())),
+            diff.evolution()
+        );
+    }
+}
@@ -4,6 +4,12 @@ use tiledb_utils::option::OptionSubset;
 #[cfg(any(test, feature = "proptest-strategies"))]
 pub mod strategy;
 
+mod diff;
+
+pub use self::diff::{
+    FilterPipeline, SchemaDiff, SchemaEvolutionOp, UnsupportedSchemaChange,
+};
+
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -316,6 +316,33 @@ fn prop_schema(
     .value_tree_map(|vt| SchemaValueTree::new(vt.current()))
 }
 
+/// Returns a strategy which produces schemas of arrays which support
+/// delete queries.
+///
+/// Delete queries (`TILEDB_DELETE`) require a sparse array; tiledb
+/// rejects them for dense arrays. Every schema has at least one attribute,
+/// so a delete condition always has an attribute to refer to.
+///
+/// Sparse arrays which allow duplicates do support delete queries, which
+/// remove each duplicate cell which satisfies the condition. Such schemas
+/// are not produced, so that the cells of the array are identified by
+/// their coordinates when modeling the effect of a delete.
+pub fn prop_deletable_schema() -> impl Strategy<Value = SchemaData> {
+    let requirements = Requirements {
+        domain: Some(Rc::new(DomainRequirements {
+            array_type: Some(ArrayType::Sparse),
+            ..Default::default()
+        })),
+        num_attributes: std::cmp::max(1, Requirements::min_attributes_default())
+            ..=std::cmp::max(1, Requirements::max_attributes_default()),
+        ..Default::default()
+    };
+    prop_schema(Rc::new(requirements)).prop_map(|schema| SchemaData {
+        allow_duplicates: Some(false),
+        ..schema
+    })
+}
+
 impl Arbitrary for SchemaData {
     type Parameters = Rc<Requirements>;
     type Strategy = BoxedStrategy<SchemaData>;
@@ -523,5 +550,12 @@ mod tests {
             let vt = SchemaValueTree::new(schema);
             test_schema_value_tree(vt)
         }
+
+        #[test]
+        fn deletable_schema(schema in prop_deletable_schema()) {
+            assert_eq!(ArrayType::Sparse, schema.array_type);
+            assert_eq!(Some(false), schema.allow_duplicates);
+            assert!(!schema.attributes.is_empty());
+        }
     }
 }
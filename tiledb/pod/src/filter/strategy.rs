@@ -551,6 +551,46 @@ impl Strategy for FilterPipelineStrategy {
     }
 }
 
+/// Returns a strategy which generates filter pipelines
+/// whose input is `datatype`.
+///
+/// Each filter accepts the output of the filter before it, beginning
+/// with `datatype`. A filter is only generated if it is compatible with
+/// its input datatype and position in the pipeline:
+///
+/// | Filter                         | Input datatype                       | Position |
+/// | ------------------------------ | ------------------------------------ | -------- |
+/// | Checksum, BitShuffle, ByteShuffle | any                               | any      |
+/// | Bzip2, Gzip, Lz4, Zstd         | any                                  | any      |
+/// | Rle, Dictionary                | any                                  | any      |
+/// | Delta, DoubleDelta             | any                                  | first    |
+/// | BitWidthReduction, PositiveDelta | integral, datetime, time, or byte  | any      |
+/// | ScaleFloat                     | 4 or 8 bytes wide                    | first    |
+/// | Xor                            | 1, 2, 4, or 8 bytes wide             | any      |
+/// | WebP                           | never, see below                     |          |
+///
+/// Option values are also constrained to valid ranges. Compression levels
+/// are in `1..=9`. Bit width reduction and positive delta windows are in
+/// `8..=1024`. Delta reinterpret datatypes are never floating-point and
+/// evenly divide the width of the input datatype.
+///
+/// In the filter pipeline of a var-sized string field, Rle and Dictionary
+/// are only valid as the first filter. The pipelines generated here are not
+/// for a particular field, so that restriction does not apply.
+///
+/// WebP is only valid for an attribute of a dense array with a particular
+/// domain, so it is never generated without the schema context.
+/// Use [FilterPipelineStrategy] with [StrategyContext::SchemaAttribute]
+/// to generate pipelines which may include WebP.
+pub fn prop_filter_list_for_datatype(
+    datatype: Datatype,
+) -> impl Strategy<Value = Vec<FilterData>> {
+    FilterPipelineStrategy::new(Rc::new(Requirements {
+        input_datatype: Some(datatype),
+        ..Default::default()
+    }))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -569,6 +609,24 @@ mod tests {
         });
     }
 
+    /// Ensure that each filter in a pipeline accepts the output of the previous
+    #[test]
+    fn filter_list_for_datatype() {
+        let strat = any::<Datatype>()
+            .prop_flat_map(|dt| (Just(dt), prop_filter_list_for_datatype(dt)));
+        proptest!(|((dt, pipeline) in strat)| {
+            let mut current_dt = dt;
+            for f in pipeline.iter() {
+                assert!(!matches!(f, FilterData::WebP { .. }));
+                current_dt = f.transform_datatype(&current_dt)
+                    .unwrap_or_else(|| panic!(
+                        "Invalid filter pipeline for datatype {}: {:?}",
+                        dt, pipeline
+                    ));
+            }
+        });
+    }
+
     /// Ensure that filter pipelines can shrink
     #[test]
     fn pipeline_shrinking() {
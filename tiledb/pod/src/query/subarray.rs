@@ -1,8 +1,26 @@
 use itertools::Itertools;
-use tiledb_common::range::Range;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tiledb_common::range::{Range, TypedNonEmptyDomain};
+
+#[derive(Debug, Error)]
+pub enum ClipError {
+    #[error(
+        "Expected non-empty domain of {expected} dimensions but found {found}"
+    )]
+    DimensionCount { expected: usize, found: usize },
+    #[error("Range {range:?} of dimension {dimension} is not compatible with non-empty domain {domain:?}")]
+    IncompatibleRange {
+        dimension: usize,
+        range: Range,
+        domain: Range,
+    },
+}
 
 /// Encapsulates data for a subarray.
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 pub struct SubarrayData {
     /// List of requested ranges on each dimension.
     /// The outer `Vec` is the list of dimensions and the inner `Vec`
@@ -13,6 +31,37 @@ pub struct SubarrayData {
 }
 
 impl SubarrayData {
+    /// Returns a `SubarrayData` which selects exactly the non-empty domain `domain`,
+    /// i.e. one range per dimension.
+    ///
+    /// ```
+    /// use tiledb_common::datatype::Datatype;
+    /// use tiledb_common::range::{Range, TypedNonEmptyDomain, TypedRange};
+    /// use tiledb_pod::query::subarray::SubarrayData;
+    ///
+    /// let domain = TypedNonEmptyDomain::from(vec![
+    ///     TypedRange::new(Datatype::Int32, Range::from(&[1, 4])),
+    ///     TypedRange::new(Datatype::StringAscii, Range::from(("bar", "foo"))),
+    /// ]);
+    /// assert_eq!(
+    ///     SubarrayData::from_nonempty_domain(&domain),
+    ///     SubarrayData {
+    ///         dimension_ranges: vec![
+    ///             vec![Range::from(&[1, 4])],
+    ///             vec![Range::from(("bar", "foo"))],
+    ///         ]
+    ///     }
+    /// );
+    /// ```
+    pub fn from_nonempty_domain(domain: &TypedNonEmptyDomain) -> Self {
+        SubarrayData {
+            dimension_ranges: domain
+                .iter()
+                .map(|typed_range| vec![typed_range.range.clone()])
+                .collect::<Vec<Vec<Range>>>(),
+        }
+    }
+
     /// Returns a new `SubarrayData` which represents the intersection
     /// of all the ranges of `self` with a new set of `ranges` on each dimension.
     ///
@@ -46,6 +95,94 @@ impl SubarrayData {
             })
         }
     }
+
+    /// Returns a new `SubarrayData` which selects the coordinates of `self`
+    /// which are within the non-empty domain `domain`.
+    ///
+    /// The ranges of each dimension are intersected with the non-empty domain
+    /// of that dimension, and ranges which do not overlap it are dropped.
+    /// A dimension with no ranges selects its whole non-empty domain.
+    ///
+    /// If any dimension has no ranges which overlap the non-empty domain, then
+    /// this returns `Ok(None)` as reading the resulting subarray would produce
+    /// no cells.
+    ///
+    /// Returns an error if `domain` does not have one range for each dimension
+    /// of `self`, or if a range of `self` does not have the same datatype as
+    /// the non-empty domain of its dimension.
+    ///
+    /// ```
+    /// use tiledb_common::datatype::Datatype;
+    /// use tiledb_common::range::{Range, TypedNonEmptyDomain, TypedRange};
+    /// use tiledb_pod::query::subarray::SubarrayData;
+    ///
+    /// let domain = TypedNonEmptyDomain::from(vec![
+    ///     TypedRange::new(Datatype::Int32, Range::from(&[10, 20])),
+    ///     TypedRange::new(Datatype::Int32, Range::from(&[1, 4])),
+    /// ]);
+    /// let subarray = SubarrayData {
+    ///     dimension_ranges: vec![
+    ///         vec![Range::from(&[0, 5]), Range::from(&[15, 25])],
+    ///         vec![],
+    ///     ],
+    /// };
+    /// assert_eq!(
+    ///     subarray.clip(&domain).unwrap(),
+    ///     Some(SubarrayData {
+    ///         dimension_ranges: vec![
+    ///             vec![Range::from(&[15, 20])],
+    ///             vec![Range::from(&[1, 4])],
+    ///         ]
+    ///     })
+    /// );
+    /// ```
+    pub fn clip(
+        &self,
+        domain: &TypedNonEmptyDomain,
+    ) -> Result<Option<Self>, ClipError> {
+        if self.dimension_ranges.len() != domain.len() {
+            return Err(ClipError::DimensionCount {
+                expected: self.dimension_ranges.len(),
+                found: domain.len(),
+            });
+        }
+
+        let mut dimension_ranges = vec![];
+        for (dimension, (current_ranges, typed_range)) in
+            self.dimension_ranges.iter().zip(domain.iter()).enumerate()
+        {
+            let domain = &typed_range.range;
+            let (datatype, cell_val_num) =
+                (typed_range.datatype, typed_range.cell_val_num());
+            if current_ranges.is_empty() {
+                // empty means select the whole thing
+                dimension_ranges.push(vec![domain.clone()]);
+                continue;
+            }
+
+            let mut clipped = vec![];
+            for range in current_ranges.iter() {
+                if range
+                    .check_dimension_compatibility(datatype, cell_val_num)
+                    .is_err()
+                {
+                    return Err(ClipError::IncompatibleRange {
+                        dimension,
+                        range: range.clone(),
+                        domain: domain.clone(),
+                    });
+                }
+                clipped.extend(range.intersect(domain));
+            }
+            if clipped.is_empty() {
+                return Ok(None);
+            }
+            dimension_ranges.push(clipped);
+        }
+
+        Ok(Some(SubarrayData { dimension_ranges }))
+    }
+
     /// Returns a new `SubarrayData` which represents the intersection
     /// of all the ranges of `self` with all of the ranges of `other` on each dimension.
     ///
@@ -364,6 +501,94 @@ mod tests {
         })
     }
 
+    #[test]
+    fn subarray_clip() {
+        use tiledb_common::datatype::Datatype;
+        use tiledb_common::range::TypedRange;
+
+        let domain = TypedNonEmptyDomain::from(vec![
+            TypedRange::new(Datatype::Int32, Range::from(&[10, 20])),
+            TypedRange::new(Datatype::StringAscii, Range::from(("bar", "foo"))),
+        ]);
+        let subarray = |rows: Vec<Range>, cols: Vec<Range>| SubarrayData {
+            dimension_ranges: vec![rows, cols],
+        };
+
+        // full overlap
+        let full = subarray(
+            vec![Range::from(&[12, 14]), Range::from(&[16, 18])],
+            vec![Range::from(("baz", "egg"))],
+        );
+        assert_eq!(Some(full.clone()), full.clip(&domain).unwrap());
+
+        // partial overlap, and disjoint ranges are dropped
+        let partial = subarray(
+            vec![
+                Range::from(&[0, 5]),
+                Range::from(&[5, 12]),
+                Range::from(&[18, 25]),
+            ],
+            vec![Range::from(("a", "c")), Range::from(("zoo", "zzz"))],
+        );
+        assert_eq!(
+            Some(subarray(
+                vec![Range::from(&[10, 12]), Range::from(&[18, 20])],
+                vec![Range::from(("bar", "c"))],
+            )),
+            partial.clip(&domain).unwrap()
+        );
+
+        // disjoint
+        let disjoint = subarray(
+            vec![Range::from(&[0, 5]), Range::from(&[21, 25])],
+            vec![],
+        );
+        assert_eq!(None, disjoint.clip(&domain).unwrap());
+
+        // mismatched datatype
+        let mismatch = subarray(
+            vec![Range::from(&[0u64, 5])],
+            vec![Range::from(("a", "c"))],
+        );
+        assert!(matches!(
+            mismatch.clip(&domain),
+            Err(ClipError::IncompatibleRange { dimension: 0, .. })
+        ));
+
+        // mismatched number of dimensions
+        let mismatch = SubarrayData {
+            dimension_ranges: vec![vec![]],
+        };
+        assert!(matches!(
+            mismatch.clip(&domain),
+            Err(ClipError::DimensionCount {
+                expected: 1,
+                found: 2
+            })
+        ));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn subarray_serde() {
+        use tiledb_common::range::{SingleValueRange, VarValueRange};
+
+        let subarray = SubarrayData {
+            dimension_ranges: vec![
+                vec![
+                    Range::Single(SingleValueRange::from(&[1i32, 4])),
+                    Range::Single(SingleValueRange::from(&[7i32, 9])),
+                ],
+                vec![Range::Var(VarValueRange::from(&["bar", "foo"]))],
+                vec![],
+            ],
+        };
+
+        let json = serde_json::to_string(&subarray).unwrap();
+        let subarray_out = serde_json::from_str::<SubarrayData>(&json).unwrap();
+        assert_eq!(subarray, subarray_out);
+    }
+
     proptest! {
         #[test]
         fn subarray_intersect_ranges((subarray, range) in strat_subarray_intersect_ranges()) {
@@ -32,6 +32,12 @@ extern "C" {
         is_open: *mut i32,
     ) -> capi_return_t;
 
+    pub fn tiledb_array_get_query_type(
+        ctx: *mut tiledb_ctx_t,
+        array: *mut tiledb_array_t,
+        query_type: *mut tiledb_query_type_t,
+    ) -> capi_return_t;
+
     pub fn tiledb_array_reopen(
         ctx: *mut tiledb_ctx_t,
         array: *mut tiledb_array_t,
@@ -235,4 +241,9 @@ extern "C" {
         name: *const ::std::os::raw::c_char,
         enumeration: *mut *mut tiledb_enumeration_t,
     ) -> capi_return_t;
+
+    pub fn tiledb_array_load_all_enumerations(
+        ctx: *mut tiledb_ctx_t,
+        array: *const tiledb_array_t,
+    ) -> capi_return_t;
 }
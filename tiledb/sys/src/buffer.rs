@@ -0,0 +1,24 @@
+use crate::types::{capi_return_t, tiledb_buffer_t, tiledb_ctx_t};
+
+extern "C" {
+    pub fn tiledb_buffer_alloc(
+        ctx: *mut tiledb_ctx_t,
+        buffer: *mut *mut tiledb_buffer_t,
+    ) -> capi_return_t;
+
+    pub fn tiledb_buffer_free(buffer: *mut *mut tiledb_buffer_t);
+
+    pub fn tiledb_buffer_get_data(
+        ctx: *mut tiledb_ctx_t,
+        buffer: *const tiledb_buffer_t,
+        data: *mut *mut ::std::os::raw::c_void,
+        num_bytes: *mut u64,
+    ) -> capi_return_t;
+
+    pub fn tiledb_buffer_set_data(
+        ctx: *mut tiledb_ctx_t,
+        buffer: *mut tiledb_buffer_t,
+        data: *mut ::std::os::raw::c_void,
+        size: u64,
+    ) -> capi_return_t;
+}
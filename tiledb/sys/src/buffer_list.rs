@@ -0,0 +1,37 @@
+use crate::types::{
+    capi_return_t, tiledb_buffer_list_t, tiledb_buffer_t, tiledb_ctx_t,
+};
+
+extern "C" {
+    pub fn tiledb_buffer_list_alloc(
+        ctx: *mut tiledb_ctx_t,
+        buffer_list: *mut *mut tiledb_buffer_list_t,
+    ) -> capi_return_t;
+
+    pub fn tiledb_buffer_list_free(buffer_list: *mut *mut tiledb_buffer_list_t);
+
+    pub fn tiledb_buffer_list_get_num_buffers(
+        ctx: *mut tiledb_ctx_t,
+        buffer_list: *const tiledb_buffer_list_t,
+        num_buffers: *mut u64,
+    ) -> capi_return_t;
+
+    pub fn tiledb_buffer_list_get_buffer(
+        ctx: *mut tiledb_ctx_t,
+        buffer_list: *const tiledb_buffer_list_t,
+        buffer_idx: u64,
+        buffer: *mut *mut tiledb_buffer_t,
+    ) -> capi_return_t;
+
+    pub fn tiledb_buffer_list_get_total_size(
+        ctx: *mut tiledb_ctx_t,
+        buffer_list: *const tiledb_buffer_list_t,
+        total_size: *mut u64,
+    ) -> capi_return_t;
+
+    pub fn tiledb_buffer_list_flatten(
+        ctx: *mut tiledb_ctx_t,
+        buffer_list: *const tiledb_buffer_list_t,
+        buffer: *mut *mut tiledb_buffer_t,
+    ) -> capi_return_t;
+}
@@ -6,7 +6,10 @@ mod aggregate;
 mod array;
 mod array_type;
 mod attribute;
+mod buffer;
+mod buffer_list;
 mod config;
+mod consolidation_plan;
 mod constants;
 mod context;
 mod dimension;
@@ -26,6 +29,7 @@ mod query;
 mod query_condition;
 mod schema;
 mod schema_evolution;
+mod serialization;
 mod stats;
 mod string;
 mod subarray;
@@ -39,8 +43,11 @@ pub use aggregate::*;
 pub use array::*;
 pub use array_type::*;
 pub use attribute::*;
+pub use buffer::*;
+pub use buffer_list::*;
 pub use capi_enum::*;
 pub use config::*;
+pub use consolidation_plan::*;
 pub use constants::*;
 pub use context::*;
 pub use dimension::*;
@@ -60,6 +67,7 @@ pub use query::*;
 pub use query_condition::*;
 pub use schema::*;
 pub use schema_evolution::*;
+pub use serialization::*;
 pub use stats::*;
 pub use string::*;
 pub use subarray::*;
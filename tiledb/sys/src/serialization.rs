@@ -0,0 +1,39 @@
+use crate::capi_enum::tiledb_serialization_type_t;
+use crate::types::{
+    capi_return_t, tiledb_array_schema_t, tiledb_buffer_list_t,
+    tiledb_buffer_t, tiledb_ctx_t, tiledb_query_t,
+};
+
+extern "C" {
+    pub fn tiledb_serialize_array_schema(
+        ctx: *mut tiledb_ctx_t,
+        array_schema: *const tiledb_array_schema_t,
+        serialization_type: tiledb_serialization_type_t,
+        client_side: i32,
+        buffer: *mut *mut tiledb_buffer_t,
+    ) -> capi_return_t;
+
+    pub fn tiledb_deserialize_array_schema(
+        ctx: *mut tiledb_ctx_t,
+        buffer: *const tiledb_buffer_t,
+        serialization_type: tiledb_serialization_type_t,
+        client_side: i32,
+        array_schema: *mut *mut tiledb_array_schema_t,
+    ) -> capi_return_t;
+
+    pub fn tiledb_serialize_query(
+        ctx: *mut tiledb_ctx_t,
+        query: *const tiledb_query_t,
+        serialization_type: tiledb_serialization_type_t,
+        client_side: i32,
+        buffer_list: *mut *mut tiledb_buffer_list_t,
+    ) -> capi_return_t;
+
+    pub fn tiledb_deserialize_query(
+        ctx: *mut tiledb_ctx_t,
+        buffer: *const tiledb_buffer_t,
+        serialization_type: tiledb_serialization_type_t,
+        client_side: i32,
+        query: *mut tiledb_query_t,
+    ) -> capi_return_t;
+}
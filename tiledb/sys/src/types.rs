@@ -21,6 +21,18 @@ pub struct tiledb_attribute_t {
     _unused: [u8; 0],
 }
 
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct tiledb_buffer_t {
+    _unused: [u8; 0],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct tiledb_buffer_list_t {
+    _unused: [u8; 0],
+}
+
 #[repr(C)]
 #[derive(Clone, Copy, Debug)]
 pub struct tiledb_config_t {
@@ -33,6 +45,12 @@ pub struct tiledb_config_iter_t {
     _unused: [u8; 0],
 }
 
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct tiledb_consolidation_plan_t {
+    _unused: [u8; 0],
+}
+
 #[repr(C)]
 #[derive(Clone, Copy, Debug)]
 pub struct tiledb_ctx_t {